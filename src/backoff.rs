@@ -0,0 +1,231 @@
+//! Backoff sequences for retry logic.
+//!
+//! All sequences are infinite iterators of `Duration`s which saturate at their configured
+//! maximum, use `Iterator::take` to limit the number of retries. No arithmetic in this module
+//! can panic.
+
+use crate::Duration;
+
+/// Exponential backoff: `base`, `base * factor`, `base * factor^2`, ... capped at `max`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialBackoff<T, const NOM: u64, const DENOM: u64> {
+    base: Duration<T, NOM, DENOM>,
+    max: Duration<T, NOM, DENOM>,
+    current: Duration<T, NOM, DENOM>,
+    factor: u32,
+}
+
+/// Linear backoff: `start`, `start + step`, `start + 2 * step`, ... capped at `max`.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearBackoff<T, const NOM: u64, const DENOM: u64> {
+    start: Duration<T, NOM, DENOM>,
+    step: Duration<T, NOM, DENOM>,
+    max: Duration<T, NOM, DENOM>,
+    current: Duration<T, NOM, DENOM>,
+}
+
+/// Decorrelated jitter backoff: each delay is drawn uniformly from `[base, previous * 3]` and
+/// capped at `max`.
+///
+/// The random source `R` is provided by the caller and must return uniformly distributed values
+/// of the backing integer type.
+#[derive(Clone, Copy, Debug)]
+pub struct JitteredBackoff<T, const NOM: u64, const DENOM: u64, R> {
+    base: Duration<T, NOM, DENOM>,
+    max: Duration<T, NOM, DENOM>,
+    previous: Duration<T, NOM, DENOM>,
+    rng: R,
+}
+
+macro_rules! impl_backoff_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64> ExponentialBackoff<$i, NOM, DENOM> {
+            /// Create an exponential backoff doubling from `base` up to `max`.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let b = ExponentialBackoff::<", stringify!($i), ", 1, 1_000>::new(Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(10), Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(50));")]
+            /// let ticks: Vec<_> = b.take(5).map(|d| d.ticks()).collect();
+            ///
+            /// assert_eq!(ticks, [10, 20, 40, 50, 50]);
+            /// ```
+            #[inline]
+            pub const fn new(base: Duration<$i, NOM, DENOM>, max: Duration<$i, NOM, DENOM>) -> Self {
+                let base = if base.ticks > max.ticks { max } else { base };
+
+                ExponentialBackoff {
+                    base,
+                    max,
+                    current: base,
+                    factor: 2,
+                }
+            }
+
+            /// Use `factor` instead of 2 as the growth factor, a factor of 0 is treated as 1.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let b = ExponentialBackoff::<", stringify!($i), ", 1, 1_000>::new(Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1), Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(100)).with_factor(3);")]
+            /// let ticks: Vec<_> = b.take(5).map(|d| d.ticks()).collect();
+            ///
+            /// assert_eq!(ticks, [1, 3, 9, 27, 81]);
+            /// ```
+            #[inline]
+            pub const fn with_factor(mut self, factor: u32) -> Self {
+                self.factor = if factor == 0 { 1 } else { factor };
+                self
+            }
+
+            /// Restart the sequence from `base`.
+            #[inline]
+            pub fn reset(&mut self) {
+                self.current = self.base;
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> Iterator for ExponentialBackoff<$i, NOM, DENOM> {
+            type Item = Duration<$i, NOM, DENOM>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let current = self.current;
+                let next = current.ticks.saturating_mul(self.factor as $i);
+
+                self.current = Duration::<$i, NOM, DENOM>::from_ticks(if next > self.max.ticks {
+                    self.max.ticks
+                } else {
+                    next
+                });
+
+                Some(current)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> LinearBackoff<$i, NOM, DENOM> {
+            /// Create a linear backoff growing by `step` from `start` up to `max`.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let b = LinearBackoff::<", stringify!($i), ", 1, 1_000>::new(Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(10), Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(15), Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(50));")]
+            /// let ticks: Vec<_> = b.take(5).map(|d| d.ticks()).collect();
+            ///
+            /// assert_eq!(ticks, [10, 25, 40, 50, 50]);
+            /// ```
+            #[inline]
+            pub const fn new(
+                start: Duration<$i, NOM, DENOM>,
+                step: Duration<$i, NOM, DENOM>,
+                max: Duration<$i, NOM, DENOM>,
+            ) -> Self {
+                let start = if start.ticks > max.ticks { max } else { start };
+
+                LinearBackoff {
+                    start,
+                    step,
+                    max,
+                    current: start,
+                }
+            }
+
+            /// Restart the sequence from `start`.
+            #[inline]
+            pub fn reset(&mut self) {
+                self.current = self.start;
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> Iterator for LinearBackoff<$i, NOM, DENOM> {
+            type Item = Duration<$i, NOM, DENOM>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let current = self.current;
+                let next = current.ticks.saturating_add(self.step.ticks);
+
+                self.current = Duration::<$i, NOM, DENOM>::from_ticks(if next > self.max.ticks {
+                    self.max.ticks
+                } else {
+                    next
+                });
+
+                Some(current)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64, R: FnMut() -> $i> JitteredBackoff<$i, NOM, DENOM, R> {
+            /// Create a decorrelated jitter backoff starting at `base` and capped at `max`, drawing
+            /// random numbers from `rng`.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let mut seed: ", stringify!($i), " = 1;")]
+            /// let rng = move || {
+            ///     seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            ///     seed
+            /// };
+            #[doc = concat!("let b = JitteredBackoff::<", stringify!($i), ", 1, 1_000, _>::new(Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(10), Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1_000), rng);")]
+            ///
+            /// for d in b.take(20) {
+            ///     assert!(d.ticks() >= 10 && d.ticks() <= 1_000);
+            /// }
+            /// ```
+            #[inline]
+            pub const fn new(
+                base: Duration<$i, NOM, DENOM>,
+                max: Duration<$i, NOM, DENOM>,
+                rng: R,
+            ) -> Self {
+                let base = if base.ticks > max.ticks { max } else { base };
+
+                JitteredBackoff {
+                    base,
+                    max,
+                    previous: base,
+                    rng,
+                }
+            }
+
+            /// Restart the sequence from `base`.
+            #[inline]
+            pub fn reset(&mut self) {
+                self.previous = self.base;
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64, R: FnMut() -> $i> Iterator
+            for JitteredBackoff<$i, NOM, DENOM, R>
+        {
+            type Item = Duration<$i, NOM, DENOM>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let low = self.base.ticks;
+                let high = self.previous.ticks.saturating_mul(3);
+
+                let ticks = if high <= low {
+                    low
+                } else {
+                    let span = high - low;
+                    let random = (self.rng)();
+
+                    if span == <$i>::MAX {
+                        random
+                    } else {
+                        low + random % (span + 1)
+                    }
+                };
+
+                self.previous = Duration::<$i, NOM, DENOM>::from_ticks(if ticks > self.max.ticks {
+                    self.max.ticks
+                } else {
+                    ticks
+                });
+
+                Some(self.previous)
+            }
+        }
+    };
+}
+
+impl_backoff_for_integer!(u32);
+impl_backoff_for_integer!(u64);
@@ -0,0 +1,205 @@
+use crate::helpers;
+use crate::{HertzU32, Rate};
+
+/// Baud rate divider of a UART peripheral, where
+/// `divisor = clock / (oversampling * baud)` with `fraction_bits` fractional bits.
+///
+/// Common dividers are:
+///
+/// * Integer only, e.g. 16x oversampling (`oversampling: 16, fraction_bits: 0`)
+/// * STM32 USART with 16x oversampling (`oversampling: 16, fraction_bits: 4`), where
+///   `BRR = divisor()`
+/// * STM32 USART with 8x oversampling (`oversampling: 8, fraction_bits: 3`), where the 3 fraction
+///   bits are not stored next to the integer part: `BRR = integer() << 4 | fraction()`, with bit 3
+///   kept at zero
+/// * PL011 (e.g. RP2040) `IBRD`/`FBRD` with 1/64 fractions (`oversampling: 16, fraction_bits: 6`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BaudConstraints {
+    /// Oversampling factor of the receiver.
+    pub oversampling: u32,
+    /// Number of fractional bits of the divisor, at most 16.
+    pub fraction_bits: u32,
+    /// Smallest supported integer part of the divisor.
+    pub min_divisor: u32,
+    /// Largest supported integer part of the divisor.
+    pub max_divisor: u32,
+}
+
+/// Baud rate divisor configuration of a UART, as solved from a peripheral clock and a target
+/// baud rate.
+///
+/// ```
+/// # use fugit::*;
+/// const PL011: BaudConstraints = BaudConstraints {
+///     oversampling: 16,
+///     fraction_bits: 6,
+///     min_divisor: 1,
+///     max_divisor: 65_535,
+/// };
+///
+/// // Solved at compile time, fails compilation if the error is above 1 %
+/// const CFG: BaudConfig = BaudConfig::new(HertzU32::MHz(125), HertzU32::Hz(115_200), PL011, 10_000);
+///
+/// assert_eq!(CFG.integer(), 67);
+/// assert_eq!(CFG.fraction(), 52);
+/// assert_eq!(CFG.baud(), HertzU32::Hz(115_207));
+/// assert_eq!(CFG.error_ppm(), 64);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BaudConfig {
+    divisor: u32,
+    fraction_bits: u32,
+    baud: HertzU32,
+    error_ppm: i32,
+}
+
+impl BaudConfig {
+    /// Solve for the divisor closest to `baud`, `None` if it is outside of the supported divisor
+    /// range, the divisor in fixed point or the achieved baud rate does not fit in 32 bits, or the
+    /// error is larger than `max_error_ppm` (10 000 ppm is 1 %).
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let over16 = BaudConstraints {
+    ///     oversampling: 16,
+    ///     fraction_bits: 0,
+    ///     min_divisor: 1,
+    ///     max_divisor: 65_535,
+    /// };
+    ///
+    /// let cfg = BaudConfig::try_new(HertzU32::MHz(16), HertzU32::Hz(9_600), over16, 10_000).unwrap();
+    /// assert_eq!(cfg.divisor(), 104);
+    /// assert_eq!(cfg.error_ppm(), 1_602);
+    ///
+    /// // 16 MHz can not give 115200 baud within 1 % with an integer divider
+    /// assert_eq!(BaudConfig::try_new(HertzU32::MHz(16), HertzU32::Hz(115_200), over16, 10_000), None);
+    /// ```
+    pub const fn try_new<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const B_NOM: u64,
+        const B_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        baud: Rate<u32, B_NOM, B_DENOM>,
+        constraints: BaudConstraints,
+        max_error_ppm: u32,
+    ) -> Option<Self> {
+        helpers::fits_u32::<C_NOM>();
+        helpers::fits_u32::<C_DENOM>();
+        helpers::fits_u32::<B_NOM>();
+        helpers::fits_u32::<B_DENOM>();
+        if constraints.fraction_bits > 16 || constraints.oversampling == 0 || baud.raw == 0 {
+            return None;
+        }
+
+        let clock_num = (clock.raw as u128 * C_NOM as u128) << constraints.fraction_bits;
+        let clock_den = C_DENOM as u128 * constraints.oversampling as u128;
+
+        // divisor = clock / (oversampling * baud), rounded to nearest
+        let num = clock_num * B_DENOM as u128;
+        let den = clock_den * baud.raw as u128 * B_NOM as u128;
+        let divisor = num / den;
+        let divisor = if 2 * (num % den) >= den {
+            divisor + 1
+        } else {
+            divisor
+        };
+
+        let integer = divisor >> constraints.fraction_bits;
+        if divisor == 0
+            || divisor > u32::MAX as u128
+            || integer < constraints.min_divisor as u128
+            || integer > constraints.max_divisor as u128
+        {
+            return None;
+        }
+
+        // achieved = clock / (oversampling * divisor)
+        let achieved_num = clock_num;
+        let achieved_den = clock_den * divisor;
+
+        let error_ppm = match helpers::error_ppm(
+            achieved_num,
+            achieved_den,
+            baud.raw as u128 * B_NOM as u128,
+            B_DENOM as u128,
+        ) {
+            Some(error_ppm) if error_ppm.unsigned_abs() <= max_error_ppm => error_ppm,
+            _ => return None,
+        };
+
+        let achieved = (achieved_num + achieved_den / 2) / achieved_den;
+        if achieved > u32::MAX as u128 {
+            return None;
+        }
+
+        Some(BaudConfig {
+            divisor: divisor as u32,
+            fraction_bits: constraints.fraction_bits,
+            baud: HertzU32::from_raw(achieved as u32),
+            error_ppm,
+        })
+    }
+
+    /// Solve for the divisor closest to `baud`.
+    ///
+    /// Panics if it is outside of the supported divisor range or the error is larger than
+    /// `max_error_ppm` (10 000 ppm is 1 %), which fails compilation in const contexts.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// const OVER16: BaudConstraints = BaudConstraints {
+    ///     oversampling: 16,
+    ///     fraction_bits: 0,
+    ///     min_divisor: 1,
+    ///     max_divisor: 65_535,
+    /// };
+    ///
+    /// // Fails as the error is 3.5 %
+    /// const CFG: BaudConfig = BaudConfig::new(HertzU32::MHz(16), HertzU32::Hz(115_200), OVER16, 10_000);
+    /// ```
+    pub const fn new<const C_NOM: u64, const C_DENOM: u64, const B_NOM: u64, const B_DENOM: u64>(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        baud: Rate<u32, B_NOM, B_DENOM>,
+        constraints: BaudConstraints,
+        max_error_ppm: u32,
+    ) -> Self {
+        if let Some(v) = Self::try_new(clock, baud, constraints, max_error_ppm) {
+            v
+        } else {
+            panic!("Baud rate configuration failed!");
+        }
+    }
+
+    /// The divisor in fixed point, with the configured number of fractional bits.
+    #[inline]
+    pub const fn divisor(&self) -> u32 {
+        self.divisor
+    }
+
+    /// The integer part of the divisor.
+    #[inline]
+    pub const fn integer(&self) -> u32 {
+        self.divisor >> self.fraction_bits
+    }
+
+    /// The fractional part of the divisor, in units of `1 / 2^fraction_bits`.
+    #[inline]
+    pub const fn fraction(&self) -> u32 {
+        self.divisor & ((1 << self.fraction_bits) - 1)
+    }
+
+    /// The achieved baud rate, rounded to the nearest integer.
+    #[inline]
+    pub const fn baud(&self) -> HertzU32 {
+        self.baud
+    }
+
+    /// The error of the achieved baud rate relative to the requested baud rate, in parts per
+    /// million (10 000 ppm is 1 %).
+    #[inline]
+    pub const fn error_ppm(&self) -> i32 {
+        self.error_ppm
+    }
+}
@@ -0,0 +1,397 @@
+//! UTC wall-clock time in the proleptic Gregorian calendar.
+//!
+//! Time is counted as in Unix time, i.e. every day has exactly 86 400 seconds and leap seconds
+//! are not represented. The date algorithms are the ones by Howard Hinnant, which are exact for
+//! all dates without any tables or loops.
+
+use crate::helpers;
+use crate::Instant;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Days since 1970-01-01 of a date.
+const fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// Date of a number of days since 1970-01-01, `None` if the year does not fit.
+const fn civil_from_days(days: i64) -> Option<(i32, u8, u8)> {
+    let days = match days.checked_add(719_468) {
+        Some(days) => days,
+        None => return None,
+    };
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = era * 400 + yoe + if month <= 2 { 1 } else { 0 };
+
+    if year < i32::MIN as i64 || year > i32::MAX as i64 {
+        None
+    } else {
+        Some((year as i32, month, day))
+    }
+}
+
+/// Date and time of day in UTC.
+///
+/// ```
+/// # use fugit::*;
+/// let dt = DateTime::new(2024, 2, 29, 12, 34, 56, 789_000_000);
+///
+/// assert_eq!(dt.to_unix(), (1_709_210_096, 789_000_000));
+/// assert_eq!(DateTime::from_unix(1_709_210_096, 789_000_000), Some(dt));
+/// assert_eq!(dt.weekday(), 4); // Thursday
+/// assert_eq!(dt.day_of_year(), 60);
+///
+/// assert_eq!(format!("{}", dt), "2024-02-29T12:34:56.789Z");
+///
+/// // Not a leap year
+/// assert_eq!(DateTime::try_new(2023, 2, 29, 0, 0, 0, 0), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl DateTime {
+    /// 1970-01-01 00:00:00 UTC.
+    pub const UNIX_EPOCH: Self = DateTime {
+        year: 1970,
+        month: 1,
+        day: 1,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nanosecond: 0,
+    };
+
+    /// Returns true if `year` is a leap year.
+    #[inline]
+    pub const fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    /// Number of days in a month, 0 if the month is invalid.
+    const fn days_in_month(year: i32, month: u8) -> u8 {
+        match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+
+    /// Create a date and time, `None` if any of the fields are out of range.
+    ///
+    /// Months and days start at 1.
+    pub const fn try_new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        if day == 0
+            || day > Self::days_in_month(year, month)
+            || hour >= 24
+            || minute >= 60
+            || second >= 60
+            || nanosecond >= 1_000_000_000
+        {
+            return None;
+        }
+
+        Some(DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        })
+    }
+
+    /// Create a date and time.
+    ///
+    /// Panics if any of the fields are out of range.
+    pub const fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanosecond: u32,
+    ) -> Self {
+        if let Some(dt) = Self::try_new(year, month, day, hour, minute, second, nanosecond) {
+            dt
+        } else {
+            panic!("Invalid date or time!");
+        }
+    }
+
+    /// Date and time of a Unix timestamp, `None` if `nanosecond` is not below one second or the
+    /// year does not fit.
+    pub const fn from_unix(secs: i64, nanosecond: u32) -> Option<Self> {
+        if nanosecond >= 1_000_000_000 {
+            return None;
+        }
+
+        let tod = secs.rem_euclid(SECS_PER_DAY);
+
+        if let Some((year, month, day)) = civil_from_days(secs.div_euclid(SECS_PER_DAY)) {
+            Some(DateTime {
+                year,
+                month,
+                day,
+                hour: (tod / 3_600) as u8,
+                minute: (tod / 60 % 60) as u8,
+                second: (tod % 60) as u8,
+                nanosecond,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Unix timestamp, as seconds since 1970-01-01 00:00:00 UTC and nanoseconds.
+    pub const fn to_unix(&self) -> (i64, u32) {
+        let secs = days_from_civil(self.year, self.month, self.day) * SECS_PER_DAY
+            + self.hour as i64 * 3_600
+            + self.minute as i64 * 60
+            + self.second as i64;
+
+        (secs, self.nanosecond)
+    }
+
+    /// Nanoseconds since 1970-01-01 00:00:00 UTC.
+    const fn unix_nanos(&self) -> i128 {
+        let (secs, nanos) = self.to_unix();
+
+        secs as i128 * NANOS_PER_SEC + nanos as i128
+    }
+
+    /// Date and time of nanoseconds since 1970-01-01 00:00:00 UTC.
+    const fn from_unix_nanos(nanos: i128) -> Option<Self> {
+        let secs = nanos.div_euclid(NANOS_PER_SEC);
+
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            None
+        } else {
+            Self::from_unix(secs as i64, nanos.rem_euclid(NANOS_PER_SEC) as u32)
+        }
+    }
+
+    /// The year.
+    #[inline]
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month, starting at 1 for January.
+    #[inline]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting at 1.
+    #[inline]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The hour.
+    #[inline]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// The minute.
+    #[inline]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// The second.
+    #[inline]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// The fraction of the second in nanoseconds.
+    #[inline]
+    pub const fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// The ISO 8601 day of the week, 1 for Monday to 7 for Sunday.
+    pub const fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday
+        ((days_from_civil(self.year, self.month, self.day) + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// The day of the year, starting at 1 for January 1st.
+    pub const fn day_of_year(&self) -> u16 {
+        (days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1)
+            as u16
+    }
+}
+
+impl core::fmt::Display for DateTime {
+    /// RFC 3339 format, with as many digits of the fraction of the second as needed for
+    /// milli-, micro- or nanosecond precision.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+
+        if self.nanosecond == 0 {
+            // No fraction
+        } else if self.nanosecond % 1_000_000 == 0 {
+            write!(f, ".{:03}", self.nanosecond / 1_000_000)?;
+        } else if self.nanosecond % 1_000 == 0 {
+            write!(f, ".{:06}", self.nanosecond / 1_000)?;
+        } else {
+            write!(f, ".{:09}", self.nanosecond)?;
+        }
+
+        write!(f, "Z")
+    }
+}
+
+/// Wall-clock interpretation of `Instant`s, e.g. of an RTC-backed monotonic timer.
+///
+/// The epoch is the UTC time of the instant with tick zero. Conversions to wall-clock time round
+/// down to the nanosecond, and conversions to instants round down to the tick.
+///
+/// ```
+/// # use fugit::*;
+/// // 32.768 kHz RTC which was started at 2024-01-01 00:00:00 UTC
+/// let clock = WallClock::<1, 32_768>::new(DateTime::new(2024, 1, 1, 0, 0, 0, 0));
+///
+/// let now = Instant::<u64, 1, 32_768>::from_ticks(86_400 * 32_768 + 16_384);
+/// assert_eq!(clock.to_datetime(now), Some(DateTime::new(2024, 1, 2, 0, 0, 0, 500_000_000)));
+/// assert_eq!(clock.to_unix(now), Some((1_704_153_600, 500_000_000)));
+///
+/// assert_eq!(clock.instant_at_unix(1_704_153_600, 500_000_000), Some(now));
+/// assert_eq!(clock.instant_at(DateTime::new(2023, 12, 31, 0, 0, 0, 0)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WallClock<const NOM: u64, const DENOM: u64> {
+    /// Nanoseconds since 1970-01-01 00:00:00 UTC at tick zero
+    epoch: i128,
+}
+
+impl<const NOM: u64, const DENOM: u64> WallClock<NOM, DENOM> {
+    /// Instants counting from 1970-01-01 00:00:00 UTC.
+    pub const UNIX_EPOCH: Self = WallClock { epoch: 0 };
+
+    /// Create a wall clock where tick zero is at `epoch`.
+    #[inline]
+    pub const fn new(epoch: DateTime) -> Self {
+        WallClock {
+            epoch: epoch.unix_nanos(),
+        }
+    }
+
+    /// The UTC time of tick zero, `None` if the year does not fit.
+    #[inline]
+    pub const fn epoch(&self) -> Option<DateTime> {
+        DateTime::from_unix_nanos(self.epoch)
+    }
+
+    /// Nanoseconds since tick zero of an instant.
+    const fn nanos(instant: Instant<u64, NOM, DENOM>) -> i128 {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        (instant.ticks() as u128 * NOM as u128 * NANOS_PER_SEC as u128 / DENOM as u128) as i128
+    }
+
+    /// Move the epoch so `instant` is at `now`, e.g. when the time has been set.
+    pub const fn sync(&mut self, instant: Instant<u64, NOM, DENOM>, now: DateTime) {
+        self.epoch = now.unix_nanos() - Self::nanos(instant);
+    }
+
+    /// UTC date and time of an instant, `None` if the year does not fit.
+    pub const fn to_datetime(&self, instant: Instant<u64, NOM, DENOM>) -> Option<DateTime> {
+        DateTime::from_unix_nanos(self.epoch + Self::nanos(instant))
+    }
+
+    /// Unix timestamp of an instant, as seconds since 1970-01-01 00:00:00 UTC and nanoseconds.
+    /// `None` if the seconds do not fit.
+    pub const fn to_unix(&self, instant: Instant<u64, NOM, DENOM>) -> Option<(i64, u32)> {
+        let nanos = self.epoch + Self::nanos(instant);
+        let secs = nanos.div_euclid(NANOS_PER_SEC);
+
+        if secs < i64::MIN as i128 || secs > i64::MAX as i128 {
+            None
+        } else {
+            Some((secs as i64, nanos.rem_euclid(NANOS_PER_SEC) as u32))
+        }
+    }
+
+    /// Instant at nanoseconds since 1970-01-01 00:00:00 UTC.
+    const fn instant_at_nanos(&self, nanos: i128) -> Option<Instant<u64, NOM, DENOM>> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        let since_epoch = nanos - self.epoch;
+
+        if since_epoch < 0 {
+            return None;
+        }
+
+        let ticks = match (since_epoch as u128).checked_mul(DENOM as u128) {
+            Some(num) => num / (NOM as u128 * NANOS_PER_SEC as u128),
+            None => return None,
+        };
+
+        if ticks > u64::MAX as u128 {
+            None
+        } else {
+            Some(Instant::<u64, NOM, DENOM>::from_ticks(ticks as u64))
+        }
+    }
+
+    /// Instant at a Unix timestamp, `None` if it is before the epoch, out of range or
+    /// `nanosecond` is not below one second.
+    pub const fn instant_at_unix(
+        &self,
+        secs: i64,
+        nanosecond: u32,
+    ) -> Option<Instant<u64, NOM, DENOM>> {
+        if nanosecond >= 1_000_000_000 {
+            return None;
+        }
+
+        self.instant_at_nanos(secs as i128 * NANOS_PER_SEC + nanosecond as i128)
+    }
+
+    /// Instant at a UTC date and time, `None` if it is before the epoch or out of range.
+    pub const fn instant_at(&self, datetime: DateTime) -> Option<Instant<u64, NOM, DENOM>> {
+        self.instant_at_nanos(datetime.unix_nanos())
+    }
+}
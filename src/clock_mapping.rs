@@ -0,0 +1,327 @@
+//! Mapping of instants between two clock domains.
+
+use crate::helpers;
+use crate::Instant;
+
+/// Fitted line `remote = (c0 + local * slope) / den`, with both sides in ticks relative to the
+/// reference pair.
+#[derive(Clone, Copy, Debug)]
+struct Line {
+    c0: i128,
+    slope: i128,
+    /// Always positive
+    den: i128,
+}
+
+/// Nearest integer of `num / den`, halfway cases away from zero.
+const fn div_round(num: i128, den: i128) -> Option<i128> {
+    if den == 0 {
+        return None;
+    }
+
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+
+    if num >= 0 {
+        Some((num + den / 2) / den)
+    } else {
+        Some((num - den / 2) / den)
+    }
+}
+
+/// Mapping between a local and a remote clock domain, estimated from pairs of correlated
+/// instants.
+///
+/// Offset and frequency skew are estimated with an integer least-squares fit over the last `N`
+/// pairs. With only one pair the clocks are assumed to run at their nominal rates. All instants
+/// are taken relative to the most recent pair, so both clocks may wrap around as long as all pairs
+/// and translated instants are within half the range of the respective tick counters. Arithmetic
+/// which would overflow gives `None`.
+///
+/// ```
+/// # use fugit::*;
+/// // Local 32.768 kHz RTC and a remote 1 MHz clock which runs 50 ppm fast
+/// let mut map = ClockMapping::<u32, 1, 32_768, u64, 1, 1_000_000, 4>::new();
+///
+/// map.push(Instant::<u32, 1, 32_768>::from_ticks(0), Instant::<u64, 1, 1_000_000>::from_ticks(1_000_000));
+/// map.push(Instant::<u32, 1, 32_768>::from_ticks(32_768), Instant::<u64, 1, 1_000_000>::from_ticks(2_000_050));
+/// map.push(Instant::<u32, 1, 32_768>::from_ticks(65_536), Instant::<u64, 1, 1_000_000>::from_ticks(3_000_100));
+///
+/// assert_eq!(map.skew_ppb(), Some(50_000));
+///
+/// let local = Instant::<u32, 1, 32_768>::from_ticks(98_304);
+/// let remote = Instant::<u64, 1, 1_000_000>::from_ticks(4_000_150);
+/// assert_eq!(map.translate(local), Some(remote));
+/// assert_eq!(map.translate_back(remote), Some(local));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ClockMapping<
+    L,
+    const L_NOM: u64,
+    const L_DENOM: u64,
+    R,
+    const R_NOM: u64,
+    const R_DENOM: u64,
+    const N: usize,
+> {
+    pairs: [(Instant<L, L_NOM, L_DENOM>, Instant<R, R_NOM, R_DENOM>); N],
+    /// Index of the next pair to be overwritten
+    head: usize,
+    len: usize,
+    line: Option<Line>,
+}
+
+impl<
+        L,
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        R,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+        const N: usize,
+    > ClockMapping<L, L_NOM, L_DENOM, R, R_NOM, R_DENOM, N>
+{
+    /// Number of pairs used for the estimate.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no pairs.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all pairs, e.g. after a loss of synchronization.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.line = None;
+    }
+
+    /// Nominal remote ticks per local tick, as `(numerator, denominator)`.
+    const NOMINAL: (i128, i128) = {
+        helpers::fits_u32::<L_NOM>();
+        helpers::fits_u32::<L_DENOM>();
+        helpers::fits_u32::<R_NOM>();
+        helpers::fits_u32::<R_DENOM>();
+
+        (
+            L_NOM as i128 * R_DENOM as i128,
+            L_DENOM as i128 * R_NOM as i128,
+        )
+    };
+
+    /// Least-squares fit over `(local, remote)` tick offsets.
+    fn fit(points: impl Iterator<Item = (i128, i128)> + Clone) -> Option<Line> {
+        let mut n = 0i128;
+        let mut sx = 0i128;
+        let mut sy = 0i128;
+
+        for (x, y) in points.clone() {
+            n += 1;
+            sx = sx.checked_add(x)?;
+            sy = sy.checked_add(y)?;
+        }
+
+        // Sums of squares and products of the deviations from the mean, times n^2
+        let mut sxx = 0i128;
+        let mut sxy = 0i128;
+
+        for (x, y) in points {
+            let dx = n.checked_mul(x)?.checked_sub(sx)?;
+            let dy = n.checked_mul(y)?.checked_sub(sy)?;
+
+            sxx = sxx.checked_add(dx.checked_mul(dx)?)?;
+            sxy = sxy.checked_add(dx.checked_mul(dy)?)?;
+        }
+
+        // y = sy / n + (x - sx / n) * slope
+        let (slope_num, slope_den) = if n < 2 || sxx == 0 {
+            Self::NOMINAL
+        } else {
+            (sxy, sxx)
+        };
+
+        Some(Line {
+            c0: sy
+                .checked_mul(slope_den)?
+                .checked_sub(sx.checked_mul(slope_num)?)?,
+            slope: n.checked_mul(slope_num)?,
+            den: n.checked_mul(slope_den)?,
+        })
+    }
+}
+
+macro_rules! impl_clock_mapping_for_integers {
+    ($l:ty, $ls:ty, $r:ty, $rs:ty) => {
+        impl<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+                const N: usize,
+            > ClockMapping<$l, L_NOM, L_DENOM, $r, R_NOM, R_DENOM, N>
+        {
+            /// Create an empty mapping.
+            pub const fn new() -> Self {
+                ClockMapping {
+                    pairs: [(
+                        Instant::<$l, L_NOM, L_DENOM>::from_ticks(0),
+                        Instant::<$r, R_NOM, R_DENOM>::from_ticks(0),
+                    ); N],
+                    head: 0,
+                    len: 0,
+                    line: None,
+                }
+            }
+
+            /// Add a pair of instants which occurred at the same time, dropping the oldest pair if
+            /// full, and update the estimate.
+            pub fn push(
+                &mut self,
+                local: Instant<$l, L_NOM, L_DENOM>,
+                remote: Instant<$r, R_NOM, R_DENOM>,
+            ) {
+                if N == 0 {
+                    return;
+                }
+
+                self.pairs[self.head] = (local, remote);
+                self.head = (self.head + 1) % N;
+
+                if self.len < N {
+                    self.len += 1;
+                }
+
+                let (l_ref, r_ref) = self.reference();
+                let points = (0..self.len).map(|k| {
+                    let (l, r) = self.pairs[(self.head + N - self.len + k) % N];
+
+                    (
+                        l.ticks().wrapping_sub(l_ref.ticks()) as $ls as i128,
+                        r.ticks().wrapping_sub(r_ref.ticks()) as $rs as i128,
+                    )
+                });
+
+                self.line = Self::fit(points);
+            }
+
+            /// The most recent pair.
+            fn reference(&self) -> (Instant<$l, L_NOM, L_DENOM>, Instant<$r, R_NOM, R_DENOM>) {
+                self.pairs[(self.head + N - 1) % N]
+            }
+
+            /// Translate a local instant to the remote clock domain, `None` if there are no pairs
+            /// or the result is out of range.
+            pub fn translate(
+                &self,
+                local: Instant<$l, L_NOM, L_DENOM>,
+            ) -> Option<Instant<$r, R_NOM, R_DENOM>> {
+                let line = self.line?;
+                let (l_ref, r_ref) = self.reference();
+
+                let x = local.ticks().wrapping_sub(l_ref.ticks()) as $ls as i128;
+                let y = div_round(line.c0.checked_add(x.checked_mul(line.slope)?)?, line.den)?;
+
+                if y < <$rs>::MIN as i128 || y > <$rs>::MAX as i128 {
+                    None
+                } else {
+                    Some(Instant::<$r, R_NOM, R_DENOM>::from_ticks(
+                        r_ref.ticks().wrapping_add(y as $rs as $r),
+                    ))
+                }
+            }
+
+            /// Translate a remote instant to the local clock domain, `None` if there are no pairs
+            /// or the result is out of range.
+            pub fn translate_back(
+                &self,
+                remote: Instant<$r, R_NOM, R_DENOM>,
+            ) -> Option<Instant<$l, L_NOM, L_DENOM>> {
+                let line = self.line?;
+                let (l_ref, r_ref) = self.reference();
+
+                let y = remote.ticks().wrapping_sub(r_ref.ticks()) as $rs as i128;
+                let x = div_round(y.checked_mul(line.den)?.checked_sub(line.c0)?, line.slope)?;
+
+                if x < <$ls>::MIN as i128 || x > <$ls>::MAX as i128 {
+                    None
+                } else {
+                    Some(Instant::<$l, L_NOM, L_DENOM>::from_ticks(
+                        l_ref.ticks().wrapping_add(x as $ls as $l),
+                    ))
+                }
+            }
+
+            /// Estimated frequency skew of the remote clock relative to the local clock, in parts
+            /// per billion. Positive if the remote clock runs fast.
+            pub fn skew_ppb(&self) -> Option<i64> {
+                let line = self.line?;
+                let (nominal_num, nominal_den) = Self::NOMINAL;
+
+                // slope / nominal - 1
+                let num = line
+                    .slope
+                    .checked_mul(nominal_den)?
+                    .checked_sub(line.den.checked_mul(nominal_num)?)?;
+                let den = line.den.checked_mul(nominal_num)?;
+
+                let ppb = div_round(num.checked_mul(1_000_000_000)?, den)?;
+                if ppb < i64::MIN as i128 || ppb > i64::MAX as i128 {
+                    None
+                } else {
+                    Some(ppb as i64)
+                }
+            }
+
+            /// Estimated offset of the remote clock relative to the local clock in nanoseconds,
+            /// i.e. the remote time minus the local time since their respective tick zero, at
+            /// the most recent pair.
+            pub fn offset_nanos(&self) -> Option<i64> {
+                let line = self.line?;
+                let (l_ref, r_ref) = self.reference();
+
+                // Remote time at the local reference in units of `1 / den` remote ticks
+                let remote = (r_ref.ticks() as i128)
+                    .checked_mul(line.den)?
+                    .checked_add(line.c0)?;
+
+                let remote = div_round(
+                    remote.checked_mul(R_NOM as i128 * 1_000_000_000)?,
+                    line.den.checked_mul(R_DENOM as i128)?,
+                )?;
+                let local = div_round(
+                    (l_ref.ticks() as i128).checked_mul(L_NOM as i128 * 1_000_000_000)?,
+                    L_DENOM as i128,
+                )?;
+
+                let offset = remote.checked_sub(local)?;
+                if offset < i64::MIN as i128 || offset > i64::MAX as i128 {
+                    None
+                } else {
+                    Some(offset as i64)
+                }
+            }
+        }
+
+        impl<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+                const N: usize,
+            > Default for ClockMapping<$l, L_NOM, L_DENOM, $r, R_NOM, R_DENOM, N>
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_clock_mapping_for_integers!(u32, i32, u32, i32);
+impl_clock_mapping_for_integers!(u32, i32, u64, i64);
+impl_clock_mapping_for_integers!(u64, i64, u32, i32);
+impl_clock_mapping_for_integers!(u64, i64, u64, i64);
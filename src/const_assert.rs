@@ -0,0 +1,94 @@
+//! Compile time checks of bases.
+//!
+//! These fail compilation wherever they are instantiated, also when called from non-const
+//! code. Checks of values, e.g. [`Duration::fits_timer`](crate::Duration) and
+//! [`Rate::is_within_ppm`](crate::Rate), are `const fn`s returning `bool`, which fail
+//! compilation when asserted in a const item:
+//!
+//! ```
+//! # use fugit::*;
+//! const CLOCK: HertzU32 = HertzU32::MHz(1);
+//! const TIMEOUT: MillisDurationU32 = MillisDurationU32::millis(50);
+//!
+//! const _: () = assert!(TIMEOUT.fits_timer(CLOCK, 16));
+//! ```
+//!
+//! ```compile_fail
+//! # use fugit::*;
+//! const CLOCK: HertzU32 = HertzU32::MHz(1);
+//! const TIMEOUT: MillisDurationU32 = MillisDurationU32::millis(100);
+//!
+//! // Fails as 100 000 cycles do not fit a 16-bit timer
+//! const _: () = assert!(TIMEOUT.fits_timer(CLOCK, 16));
+//! ```
+
+use crate::helpers::{self, AssertBase, Helpers};
+
+/// Compile time check that a base is valid, i.e. that `NOM` and `DENOM` are not zero.
+///
+/// ```
+/// # use fugit::*;
+/// assert_valid_base::<1, 32_768>();
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// assert_valid_base::<1, 0>();
+/// ```
+#[allow(path_statements)]
+#[inline(always)]
+pub const fn assert_valid_base<const NOM: u64, const DENOM: u64>() {
+    AssertBase::<NOM>::GREATER_THAN_0;
+    AssertBase::<DENOM>::GREATER_THAN_0;
+}
+
+/// Compile time check that a tick of the `L` base is a whole number of ticks of the `R` base,
+/// so converting from `L` to `R` is exact.
+///
+/// ```
+/// # use fugit::*;
+/// // 1 ms is 32 ticks of a 32 kHz clock
+/// assert_exact_base::<1, 1_000, 1, 32_000>();
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// // Fails as 1 ms is 32.768 ticks of a 32.768 kHz clock
+/// assert_exact_base::<1, 1_000, 1, 32_768>();
+/// ```
+#[inline(always)]
+pub const fn assert_exact_base<
+    const L_NOM: u64,
+    const L_DENOM: u64,
+    const R_NOM: u64,
+    const R_DENOM: u64,
+>() {
+    assert_valid_base::<L_NOM, L_DENOM>();
+    assert_valid_base::<R_NOM, R_DENOM>();
+    helpers::exact::<L_NOM, L_DENOM, R_NOM, R_DENOM>();
+}
+
+/// Compile time check that the `L` and `R` bases are the same, e.g. `1 / 1_000` and
+/// `2 / 2_000`.
+///
+/// ```
+/// # use fugit::*;
+/// assert_same_base::<1, 1_000, 2, 2_000>();
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// assert_same_base::<1, 1_000, 1, 1_024>();
+/// ```
+#[allow(path_statements)]
+#[inline(always)]
+pub const fn assert_same_base<
+    const L_NOM: u64,
+    const L_DENOM: u64,
+    const R_NOM: u64,
+    const R_DENOM: u64,
+>() {
+    assert_valid_base::<L_NOM, L_DENOM>();
+    assert_valid_base::<R_NOM, R_DENOM>();
+    Helpers::<L_NOM, L_DENOM, R_NOM, R_DENOM>::SAME;
+}
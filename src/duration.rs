@@ -1,12 +1,14 @@
 use crate::helpers::{self, Helpers};
-use crate::Rate;
+use crate::literal;
+use crate::tick_int::{factor, ExtDuration, TickInt};
+use crate::{Error, ExactError, Rate};
 use core::cmp::Ordering;
 use core::convert;
 use core::ops;
 
 /// Represents a duration of time.
 ///
-/// The generic `T` can either be `u32` or `u64`, and the const generics represent the ratio of the
+/// The generic `T` is a [`TickInt`](crate::TickInt), and the const generics represent the ratio of the
 /// ticks contained within the duration: `duration in seconds = NOM / DENOM * ticks`
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(
@@ -14,85 +16,101 @@ use core::ops;
     derive(postcard::experimental::max_size::MaxSize)
 )]
 #[derive(Clone, Copy, Debug)]
-pub struct Duration<T, const NOM: u32, const DENOM: u32> {
+pub struct Duration<T, const NOM: u64, const DENOM: u64> {
     pub(crate) ticks: T,
 }
 
 macro_rules! shorthand {
-    ($i:ty, $nom:literal, $denum:literal, $unit:ident, $to_unit:ident, $unital:ident, $unitstr:literal) => {
+    ($i:ty, $w:ty, $nom:literal, $denum:literal, $unit:ident, $to_unit:ident, $unital:ident, $unitstr:literal) => {
         #[doc = concat!("Convert the Duration to an integer number of ", $unitstr, ".")]
         #[inline]
         pub const fn $to_unit(&self) -> $i {
-            (Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN as $i * self.ticks)
-                / Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN as $i
+            Self::_narrow(Self::_scale(
+                self.ticks as $w,
+                Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN,
+                Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN,
+                false,
+            ))
         }
 
         #[doc = concat!("Shorthand for creating a duration which represents ", $unitstr, ".")]
         #[inline]
         pub const fn $unit(val: $i) -> Self {
-            Self::from_ticks(
-                (Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN as $i * val)
-                    / Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN as $i
-            )
+            Self::from_ticks(Self::_narrow(Self::_scale(
+                val as $w,
+                Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN,
+                Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN,
+                false,
+            )))
         }
 
         #[doc = concat!("Shorthand for creating a duration which represents ", $unitstr, " (ceil rounded).")]
         #[inline]
         pub const fn $unital(val: $i) -> Self {
-            let mul = Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN as $i * val;
-            let ld_times_rn = Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN as $i;
-            Self::from_ticks(if mul % ld_times_rn == 0 {
-                mul / ld_times_rn
-            } else {
-                mul / ld_times_rn + 1
-            })
+            Self::from_ticks(Self::_narrow(Self::_scale(
+                val as $w,
+                Helpers::<$nom, $denum, NOM, DENOM>::RD_TIMES_LN,
+                Helpers::<$nom, $denum, NOM, DENOM>::LD_TIMES_RN,
+                true,
+            )))
         }
     };
 }
 
 macro_rules! impl_duration_for_integer {
-    ($i:ty) => {
-        impl<const NOM: u32, const DENOM: u32> Duration<$i, NOM, DENOM> {
-            /// Create a `Duration` from a ticks value.
-            ///
-            /// ```
-            /// # use fugit::*;
-            #[doc = concat!("let _d = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
-            /// ```
-            #[inline]
-            pub const fn from_ticks(ticks: $i) -> Self {
-                helpers::greater_than_0::<NOM>();
-                helpers::greater_than_0::<DENOM>();
-
-                Duration { ticks }
-            }
-
-            /// Extract the ticks from a `Duration`.
+    ($i:ty, $w:ty) => {
+        impl<const NOM: u64, const DENOM: u64> Duration<$i, NOM, DENOM> {
+            /// Returns true if this `Duration` spans no time
             ///
             /// ```
             /// # use fugit::*;
-            #[doc = concat!("let d = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(234);")]
+            #[doc = concat!("let zero = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(0);")]
+            #[doc = concat!("let one = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
             ///
-            /// assert_eq!(d.ticks(), 234);
+            /// assert_eq!(zero.is_zero(), true);
+            /// assert_eq!(one.is_zero(), false);
             /// ```
             #[inline]
-            pub const fn ticks(&self) -> $i {
-                self.ticks
+            pub const fn is_zero(&self) -> bool {
+                self.ticks == 0
             }
 
-            /// Returns true if this `Duration` spans no time
+            /// Returns true if this `Duration` is at most `2^bits` cycles of `clock`, with the
+            /// cycles rounded up, i.e. it fits a period of a `bits` wide timer.
+            ///
+            /// Asserting it in a const item turns a configuration which does not fit into a
+            /// compile error.
             ///
             /// ```
             /// # use fugit::*;
-            #[doc = concat!("let zero = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(0);")]
-            #[doc = concat!("let one = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("const CLOCK: Rate<", stringify!($i), ", 1, 1> = Rate::<", stringify!($i), ", 1, 1>::from_raw(100);")]
+            #[doc = concat!("const TIMEOUT: Duration<", stringify!($i), ", 1, 1_000> = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(50);")]
             ///
-            /// assert_eq!(zero.is_zero(), true);
-            /// assert_eq!(one.is_zero(), false);
+            /// // 5 cycles
+            /// const _: () = assert!(TIMEOUT.fits_timer(CLOCK, 3));
+            /// assert!(!TIMEOUT.fits_timer(CLOCK, 2));
             /// ```
             #[inline]
-            pub const fn is_zero(&self) -> bool {
-                self.ticks == 0
+            pub const fn fits_timer<const C_NOM: u64, const C_DENOM: u64>(
+                &self,
+                clock: Rate<$i, C_NOM, C_DENOM>,
+                bits: u32,
+            ) -> bool {
+                let max = if bits >= u128::BITS {
+                    u128::MAX
+                } else {
+                    1 << bits
+                };
+
+                helpers::cycles_at_most(
+                    self.ticks as u128,
+                    NOM,
+                    DENOM,
+                    clock.raw as u128,
+                    C_NOM,
+                    C_DENOM,
+                    max,
+                )
             }
 
             /// Add two durations while checking for overflow.
@@ -106,31 +124,18 @@ macro_rules! impl_duration_for_integer {
             /// assert_eq!(d1.checked_add(d2).unwrap().ticks(), 3);
             /// assert_eq!(d1.checked_add(d3), None);
             /// ```
-            pub const fn checked_add<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Duration<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Self::const_try_from(other) {
                     if let Some(ticks) = self.ticks.checked_add(other.ticks) {
                         Some(Duration::<$i, NOM, DENOM>::from_ticks(ticks))
                     } else {
                         None
                     }
                 } else {
-                    if let Some(lh) = other
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let ticks = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        if let Some(ticks) = self.ticks.checked_add(ticks) {
-                            Some(Duration::<$i, NOM, DENOM>::from_ticks(ticks))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
 
@@ -145,37 +150,24 @@ macro_rules! impl_duration_for_integer {
             /// assert_eq!(d2.checked_sub(d1).unwrap().ticks(), 1);
             /// assert_eq!(d1.checked_sub(d3), None);
             /// ```
-            pub const fn checked_sub<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Duration<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Self::const_try_from(other) {
                     if let Some(ticks) = self.ticks.checked_sub(other.ticks) {
                         Some(Duration::<$i, NOM, DENOM>::from_ticks(ticks))
                     } else {
                         None
                     }
                 } else {
-                    if let Some(lh) = other
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let ticks = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        if let Some(ticks) = self.ticks.checked_sub(ticks) {
-                            Some(Duration::<$i, NOM, DENOM>::from_ticks(ticks))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
 
-            #[doc = concat!("Const `cmp` for ", stringify!($i))]
+            #[doc = concat!("Const `cmp` for ", stringify!($w))]
             #[inline(always)]
-            const fn _const_cmp(a: $i, b: $i) -> Ordering {
+            const fn _const_cmp(a: $w, b: $w) -> Ordering {
                 if a < b {
                     Ordering::Less
                 } else if a > b {
@@ -195,7 +187,7 @@ macro_rules! impl_duration_for_integer {
             /// assert_eq!(d1.const_partial_cmp(d2), Some(core::cmp::Ordering::Greater));
             /// ```
             #[inline]
-            pub const fn const_partial_cmp<const R_NOM: u32, const R_DENOM: u32>(
+            pub const fn const_partial_cmp<const R_NOM: u64, const R_DENOM: u64>(
                 self,
                 other: Duration<$i, R_NOM, R_DENOM>
             ) -> Option<Ordering> {
@@ -216,14 +208,20 @@ macro_rules! impl_duration_for_integer {
 
                 if Helpers::<NOM, DENOM, R_NOM, R_DENOM>::SAME_BASE {
                     // If we are in the same base, comparison in trivial
-                    Some(Self::_const_cmp(self.ticks, other.ticks))
+                    Some(Self::_const_cmp(self.ticks as $w, other.ticks as $w))
                 } else {
-                    let lh = self
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN as $i);
-                    let rh = other
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN as $i);
+                    let lh = Self::_scale(
+                        self.ticks as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN,
+                        1,
+                        false,
+                    );
+                    let rh = Self::_scale(
+                        other.ticks as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN,
+                        1,
+                        false,
+                    );
 
                     if let (Some(lh), Some(rh)) = (lh, rh) {
                         Some(Self::_const_cmp(lh, rh))
@@ -243,7 +241,7 @@ macro_rules! impl_duration_for_integer {
             /// assert!(d1.const_eq(d2));
             /// ```
             #[inline]
-            pub const fn const_eq<const R_NOM: u32, const R_DENOM: u32>(
+            pub const fn const_eq<const R_NOM: u64, const R_DENOM: u64>(
                 self,
                 other: Duration<$i, R_NOM, R_DENOM>
             ) -> bool {
@@ -251,12 +249,18 @@ macro_rules! impl_duration_for_integer {
                     // If we are in the same base, comparison in trivial
                     self.ticks == other.ticks
                 } else {
-                    let lh = self
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN as $i);
-                    let rh = other
-                        .ticks
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN as $i);
+                    let lh = Self::_scale(
+                        self.ticks as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN,
+                        1,
+                        false,
+                    );
+                    let rh = Self::_scale(
+                        other.ticks as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN,
+                        1,
+                        false,
+                    );
 
                     if let (Some(lh), Some(rh)) = (lh, rh) {
                         lh == rh
@@ -275,18 +279,20 @@ macro_rules! impl_duration_for_integer {
             ///
             /// assert_eq!(d2.unwrap().ticks(), 10);
             /// ```
-            pub const fn const_try_from<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn const_try_from<const I_NOM: u64, const I_DENOM: u64>(
                 duration: Duration<$i, I_NOM, I_DENOM>,
             ) -> Option<Self> {
                 if Helpers::<I_NOM, I_DENOM, NOM, DENOM>::SAME_BASE {
                     Some(Self::from_ticks(duration.ticks))
                 } else {
-                    if let Some(lh) = (duration.ticks as u64)
-                        .checked_mul(Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN as u64)
-                    {
-                        let ticks = lh / Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN as u64;
-
-                        if ticks <= <$i>::MAX as u64 {
+                    if let Some(ticks) = Self::_scale(
+                        duration.ticks as $w,
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN,
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN,
+                        false,
+                    ) {
+
+                        if ticks <= <$i>::MAX as $w {
                             Some(Self::from_ticks(ticks as $i))
                         } else {
                             None
@@ -307,7 +313,7 @@ macro_rules! impl_duration_for_integer {
             /// assert_eq!(d2.unwrap().ticks(), 10);
             /// ```
             #[inline]
-            pub const fn const_try_into<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Option<Duration<$i, O_NOM, O_DENOM>> {
                 Duration::<$i, O_NOM, O_DENOM>::const_try_from(self)
@@ -317,13 +323,13 @@ macro_rules! impl_duration_for_integer {
             ///
             /// ```
             /// # use fugit::*;
-            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(2);")]
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(2);")]
             #[doc = concat!("let r1: Option<Rate::<", stringify!($i), ", 1, 1>> = d1.try_into_rate();")]
             ///
-            /// assert_eq!(r1.unwrap().raw(), 500);
+            /// assert_eq!(r1.unwrap().raw(), 50);
             /// ```
             #[inline]
-            pub const fn try_into_rate<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn try_into_rate<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Option<Rate<$i, O_NOM, O_DENOM>> {
                 Rate::<$i, O_NOM, O_DENOM>::try_from_duration(self)
@@ -331,7 +337,7 @@ macro_rules! impl_duration_for_integer {
 
             /// Convert from duration to rate.
             #[inline]
-            pub const fn into_rate<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn into_rate<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Rate<$i, O_NOM, O_DENOM> {
                 if let Some(v) = self.try_into_rate() {
@@ -346,19 +352,25 @@ macro_rules! impl_duration_for_integer {
             /// ```
             /// # use fugit::*;
             #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1>::from_raw(1);")]
-            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::try_from_rate(r1);")]
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::try_from_rate(r1);")]
             ///
-            /// assert_eq!(d1.unwrap().ticks(), 1_000);
+            /// assert_eq!(d1.unwrap().ticks(), 100);
             /// ```
             #[inline]
-            pub const fn try_from_rate<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn try_from_rate<const I_NOM: u64, const I_DENOM: u64>(
                 rate: Rate<$i, I_NOM, I_DENOM>,
             ) -> Option<Self> {
                 if rate.raw > 0 {
-                    Some(Self::from_ticks(
-                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RATE_TO_DURATION_NUMERATOR as $i
-                        / rate.raw
-                    ))
+                    let ticks = Self::_inverse(
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RATE_TO_DURATION_NUMERATOR,
+                        rate.raw as $w,
+                    );
+
+                    if ticks <= <$i>::MAX as u128 {
+                        Some(Self::from_ticks(ticks as $i))
+                    } else {
+                        None
+                    }
                 } else {
                     None
                 }
@@ -366,7 +378,7 @@ macro_rules! impl_duration_for_integer {
 
             /// Convert from rate to duration.
             #[inline]
-            pub const fn from_rate<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn from_rate<const I_NOM: u64, const I_DENOM: u64>(
                 rate: Rate<$i, I_NOM, I_DENOM>,
             ) -> Self {
                 if let Some(v) = Self::try_from_rate(rate) {
@@ -395,7 +407,7 @@ macro_rules! impl_duration_for_integer {
             /// // Fails conversion due to tick overflow
             #[doc = concat!("const D2: Duration::<", stringify!($i), ", 1, 200> = D1.convert();")]
             #[inline]
-            pub const fn convert<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn convert<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Duration<$i, O_NOM, O_DENOM> {
                 if let Some(v) = self.const_try_into() {
@@ -405,234 +417,575 @@ macro_rules! impl_duration_for_integer {
                 }
             }
 
-            shorthand!($i, 1, 1_000_000_000, nanos, to_nanos, nanos_at_least, "nanoseconds");
-            shorthand!($i, 1, 1_000_000, micros, to_micros, micros_at_least, "microseconds");
-            shorthand!($i, 1, 1_000, millis, to_millis, millis_at_least, "milliseconds");
-            shorthand!($i, 1, 1, secs, to_secs, secs_at_least, "seconds");
-            shorthand!($i, 60, 1, minutes, to_minutes, minutes_at_least, "minutes");
-            shorthand!($i, 3600, 1, hours, to_hours, hours_at_least, "hours");
-
-            /// Shorthand for creating a duration which represents hertz.
+            /// Convert between bases for a duration, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(1);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(d1.try_convert::<1, 1_000>().unwrap().ticks(), 10);
+            /// assert_eq!(d2.try_convert::<1, 1_000>(), Err(Error::Overflow));
+            /// ```
             #[inline]
-            #[allow(non_snake_case)]
-            pub const fn Hz(val: $i) -> Self {
-                Self::from_rate(crate::Hertz::<$i>::from_raw(val))
+            pub const fn try_convert<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Duration<$i, O_NOM, O_DENOM>, Error> {
+                if let Some(v) = self.const_try_into() {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
             }
 
-            /// Shorthand for creating a duration which represents kilohertz.
-            #[inline]
-            #[allow(non_snake_case)]
-            pub const fn kHz(val: $i) -> Self {
-                Self::from_rate(crate::Kilohertz::<$i>::from_raw(val))
+            /// Const try from, succeeding only if the base change is exact.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(250);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 100>::exact_try_from(d1);")]
+            #[doc = concat!("let d3 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(255);")]
+            #[doc = concat!("let d4 = Duration::<", stringify!($i), ", 1, 100>::exact_try_from(d3);")]
+            ///
+            /// assert_eq!(d2.unwrap().ticks(), 25);
+            /// assert_eq!(
+            ///     d4,
+            ///     Err(ExactError::PrecisionLoss {
+            #[doc = concat!("        truncated: Duration::<", stringify!($i), ", 1, 100>::from_ticks(25),")]
+            ///         remainder: 5,
+            ///         denom: 10,
+            ///     })
+            /// );
+            /// ```
+            pub const fn exact_try_from<const I_NOM: u64, const I_DENOM: u64>(
+                duration: Duration<$i, I_NOM, I_DENOM>,
+            ) -> Result<Self, ExactError<Self>> {
+                let prod = match Self::_scale(
+                    duration.ticks as $w,
+                    Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN,
+                    1,
+                    false,
+                ) {
+                    Some(prod) => prod,
+                    None => return Err(ExactError::Overflow),
+                };
+                let denom = Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN;
+
+                // `_scale` can not fail when dividing, and gives zero for a divisor which does not
+                // fit the intermediate
+                let quotient = match Self::_scale(prod, 1, denom, false) {
+                    Some(quotient) => quotient,
+                    None => 0,
+                };
+                let remainder = prod - quotient * denom as $w;
+
+                if quotient > <$i>::MAX as $w {
+                    Err(ExactError::Overflow)
+                } else if remainder != 0 {
+                    Err(ExactError::PrecisionLoss {
+                        truncated: Self::from_ticks(quotient as $i),
+                        remainder: remainder as u128,
+                        denom,
+                    })
+                } else {
+                    Ok(Self::from_ticks(quotient as $i))
+                }
             }
 
-            /// Shorthand for creating a duration which represents megahertz.
+            /// Convert between bases, succeeding only if the base change is exact.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(250);")]
+            ///
+            /// assert_eq!(d1.exact_convert::<1, 100>().unwrap().ticks(), 25);
+            /// assert!(d1.exact_convert::<1, 1>().is_err());
+            /// ```
             #[inline]
-            #[allow(non_snake_case)]
-            pub const fn MHz(val: $i) -> Self {
-                Self::from_rate(crate::Megahertz::<$i>::from_raw(val))
+            pub const fn exact_convert<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Duration<$i, O_NOM, O_DENOM>, ExactError<Duration<$i, O_NOM, O_DENOM>>> {
+                Duration::<$i, O_NOM, O_DENOM>::exact_try_from(self)
             }
-        }
 
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
-            PartialOrd<Duration<$i, R_NOM, R_DENOM>> for Duration<$i, L_NOM, L_DENOM>
-        {
+            /// Convert from a base where a tick is a whole number of ticks in this base, which
+            /// can not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(25);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 1_000>::exact_from(d1);")]
+            ///
+            /// assert_eq!(d2.ticks(), 250);
+            /// ```
+            ///
+            /// ```compile_fail
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(250);")]
+            /// // Fails as a tick of 1 ms is not a whole number of ticks of 10 ms
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 100>::exact_from(d1);")]
+            /// ```
             #[inline]
-            fn partial_cmp(&self, other: &Duration<$i, R_NOM, R_DENOM>) -> Option<Ordering> {
-                self.const_partial_cmp(*other)
+            pub const fn exact_from<const I_NOM: u64, const I_DENOM: u64>(
+                duration: Duration<$i, I_NOM, I_DENOM>,
+            ) -> Self {
+                helpers::exact::<I_NOM, I_DENOM, NOM, DENOM>();
+
+                if let Some(v) = Self::const_try_from(duration) {
+                    v
+                } else {
+                    panic!("Exact conversion failed, overflow!");
+                }
             }
-        }
 
-        impl<const NOM: u32, const DENOM: u32> Ord for Duration<$i, NOM, DENOM> {
+            /// Convert to a base where a tick of this base is a whole number of ticks, which can
+            /// not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(25);")]
+            ///
+            /// assert_eq!(d1.exact_into::<1, 1_000>().ticks(), 250);
+            /// ```
             #[inline]
-            fn cmp(&self, other: &Self) -> Ordering {
-                Self::_const_cmp(self.ticks, other.ticks)
+            pub const fn exact_into<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Duration<$i, O_NOM, O_DENOM> {
+                Duration::<$i, O_NOM, O_DENOM>::exact_from(self)
             }
-        }
 
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
-            PartialEq<Duration<$i, R_NOM, R_DENOM>> for Duration<$i, L_NOM, L_DENOM>
-        {
+            /// Add two durations, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(d1.try_add(d1).unwrap().ticks(), 2);
+            /// assert_eq!(d1.try_add(d2), Err(Error::Overflow));
+            /// ```
             #[inline]
-            fn eq(&self, other: &Duration<$i, R_NOM, R_DENOM>) -> bool {
-                self.const_eq(*other)
+            pub const fn try_add<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Duration<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
+                if let Some(v) = self.checked_add(other) {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
             }
-        }
-
-        impl<const NOM: u32, const DENOM: u32> Eq for Duration<$i, NOM, DENOM> {}
-
-        // Duration - Duration = Duration (only same base until const_generics_defaults is
-        // stabilized)
-        impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<$i, NOM, DENOM>>
-            for Duration<$i, NOM, DENOM>
-        {
-            type Output = Duration<$i, NOM, DENOM>;
 
+            /// Subtract two durations, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(2);")]
+            ///
+            /// assert_eq!(d2.try_sub(d1).unwrap().ticks(), 1);
+            /// assert_eq!(d1.try_sub(d2), Err(Error::Underflow));
+            /// ```
             #[inline]
-            fn sub(self, other: Duration<$i, NOM, DENOM>) -> Self::Output {
+            pub const fn try_sub<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Duration<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
+                // A subtrahend which does not fit this base is larger than any duration in it
                 if let Some(v) = self.checked_sub(other) {
-                    v
+                    Ok(v)
                 } else {
-                    panic!("Sub failed!");
+                    Err(Error::Underflow)
                 }
             }
-        }
 
-        // Duration -= Duration
-        impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<$i, NOM, DENOM>>
-            for Duration<$i, NOM, DENOM>
-        {
+            /// Convert from duration to rate, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(2);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(0);")]
+            ///
+            /// assert_eq!(d1.try_to_rate::<1, 1>().unwrap().raw(), 50);
+            /// assert_eq!(d2.try_to_rate::<1, 1>(), Err(Error::DivideByZero));
+            /// ```
             #[inline]
-            fn sub_assign(&mut self, other: Self) {
-                *self = *self - other;
+            pub const fn try_to_rate<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Rate<$i, O_NOM, O_DENOM>, Error> {
+                if self.ticks == 0 {
+                    Err(Error::DivideByZero)
+                } else if let Some(v) = self.try_into_rate() {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
             }
-        }
 
-        // Duration + Duration = Duration (only same base until const_generics_defaults is
-        // stabilized)
-        impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<$i, NOM, DENOM>>
-            for Duration<$i, NOM, DENOM>
-        {
-            type Output = Duration<$i, NOM, DENOM>;
+            #[doc = concat!("Narrow a `", stringify!($w), "` intermediate, panics on overflow")]
+            #[inline(always)]
+            const fn _narrow(val: Option<$w>) -> $i {
+                match val {
+                    Some(val) if val <= <$i>::MAX as $w => val as $i,
+                    _ => panic!("Overflow!"),
+                }
+            }
 
-            #[inline]
-            fn add(self, other: Duration<$i, NOM, DENOM>) -> Self::Output {
-                if let Some(v) = self.checked_add(other) {
-                    v
+            /// `val * mul / div` in the intermediate, rounded up if `ceil` and `None` on overflow.
+            /// The `u128` constants of [`Helpers`] do not need to fit the intermediate.
+            ///
+            /// When the constants fit the backing integer the product is first tried at its native
+            /// width, so the wider (and on 32-bit targets much slower) intermediate arithmetic is
+            /// only used if the native product overflows. As `mul` and `div` are constants this
+            /// is folded at compile time, and a `mul` of 1 never reaches the wide path.
+            #[inline(always)]
+            const fn _scale(val: $w, mul: u128, div: u128, ceil: bool) -> Option<$w> {
+                if val <= <$i>::MAX as $w
+                    && mul <= <$i>::MAX as u128
+                    && div != 0
+                    && div <= <$i>::MAX as u128
+                {
+                    if let Some(prod) = (val as $i).checked_mul(mul as $i) {
+                        let div = div as $i;
+                        let quotient = if ceil && prod % div != 0 {
+                            prod / div + 1
+                        } else {
+                            prod / div
+                        };
+                        return Some(quotient as $w);
+                    }
+                }
+
+                let prod = if mul as $w as u128 == mul {
+                    match val.checked_mul(mul as $w) {
+                        Some(prod) => prod,
+                        None => return None,
+                    }
+                } else if val == 0 {
+                    0
+                } else {
+                    return None;
+                };
+
+                if div as $w as u128 != div {
+                    // The divisor is larger than any product
+                    Some((ceil && prod != 0) as $w)
+                } else if ceil && prod % div as $w != 0 {
+                    Some(prod / div as $w + 1)
                 } else {
-                    panic!("Add failed!");
+                    Some(prod / div as $w)
                 }
             }
-        }
 
-        // Duration += Duration
-        impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<$i, NOM, DENOM>>
-            for Duration<$i, NOM, DENOM>
-        {
-            #[inline]
-            fn add_assign(&mut self, other: Self) {
-                *self = *self + other;
+            /// `num / val` for a `u128` constant `num`, in the intermediate if `num` fits.
+            #[inline(always)]
+            const fn _inverse(num: u128, val: $w) -> u128 {
+                if num as $w as u128 == num {
+                    (num as $w / val) as u128
+                } else {
+                    num / val as u128
+                }
             }
-        }
 
-        // integer * Duration = Duration
-        impl<const NOM: u32, const DENOM: u32> ops::Mul<Duration<$i, NOM, DENOM>> for u32 {
-            type Output = Duration<$i, NOM, DENOM>;
+            /// Used by the literal macros, panics if the literal is not exact in this base.
+            #[doc(hidden)]
+            pub const fn __from_literal(lit: &str, unit: (u64, u64)) -> Self {
+                let val = literal::ticks(lit, unit, NOM, DENOM);
 
-            #[inline]
-            fn mul(self, mut other: Duration<$i, NOM, DENOM>) -> Self::Output {
-                other.ticks *= self as $i;
-                other
+                if val > <$i>::MAX as u128 {
+                    panic!("The value does not fit the backing integer");
+                }
+
+                Self::from_ticks(val as $i)
             }
-        }
 
-        // Duration * integer = Duration
-        impl<const NOM: u32, const DENOM: u32> ops::Mul<u32> for Duration<$i, NOM, DENOM> {
-            type Output = Duration<$i, NOM, DENOM>;
+            shorthand!($i, $w, 1, 1_000_000_000_000_000, femtos, to_femtos, femtos_at_least, "femtoseconds");
+            shorthand!($i, $w, 1, 1_000_000_000_000, picos, to_picos, picos_at_least, "picoseconds");
+            shorthand!($i, $w, 1, 1_000_000_000, nanos, to_nanos, nanos_at_least, "nanoseconds");
+            shorthand!($i, $w, 1, 1_000_000, micros, to_micros, micros_at_least, "microseconds");
+            shorthand!($i, $w, 1, 1_000, millis, to_millis, millis_at_least, "milliseconds");
+            shorthand!($i, $w, 1, 1, secs, to_secs, secs_at_least, "seconds");
+            shorthand!($i, $w, 60, 1, minutes, to_minutes, minutes_at_least, "minutes");
+            shorthand!($i, $w, 3600, 1, hours, to_hours, hours_at_least, "hours");
+            shorthand!($i, $w, 86_400, 1, days, to_days, days_at_least, "days");
+            shorthand!($i, $w, 604_800, 1, weeks, to_weeks, weeks_at_least, "weeks");
 
+            /// Shorthand for creating a duration which represents hertz.
             #[inline]
-            fn mul(mut self, other: u32) -> Self::Output {
-                self.ticks *= other as $i;
-                self
+            #[allow(non_snake_case)]
+            pub const fn Hz(val: $i) -> Self {
+                Self::from_rate(crate::Hertz::<$i>::from_raw(val))
             }
-        }
 
-        // Duration *= integer
-        impl<const NOM: u32, const DENOM: u32> ops::MulAssign<u32>
-            for Duration<$i, NOM, DENOM>
-        {
+            /// Shorthand for creating a duration which represents kilohertz.
             #[inline]
-            fn mul_assign(&mut self, other: u32) {
-                *self = *self * other;
+            #[allow(non_snake_case)]
+            pub const fn kHz(val: $i) -> Self {
+                Self::from_rate(crate::Kilohertz::<$i>::from_raw(val))
             }
-        }
-
-        // Duration / integer = Duration
-        impl<const NOM: u32, const DENOM: u32> ops::Div<u32> for Duration<$i, NOM, DENOM> {
-            type Output = Duration<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a duration which represents megahertz.
             #[inline]
-            fn div(mut self, other: u32) -> Self::Output {
-                self.ticks /= other as $i;
-                self
+            #[allow(non_snake_case)]
+            pub const fn MHz(val: $i) -> Self {
+                Self::from_rate(crate::Megahertz::<$i>::from_raw(val))
             }
         }
+    };
+}
 
-        // Duration /= integer
-        impl<const NOM: u32, const DENOM: u32> ops::DivAssign<u32>
-            for Duration<$i, NOM, DENOM>
-        {
-            #[inline]
-            fn div_assign(&mut self, other: u32) {
-                *self = *self / other;
-            }
-        }
+impl_duration_for_integer!(u8, u64);
+impl_duration_for_integer!(u16, u64);
+impl_duration_for_integer!(u32, u64);
+impl_duration_for_integer!(u64, u128);
+impl_duration_for_integer!(u128, u128);
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Duration<T, NOM, DENOM> {
+    /// Create a `Duration` from a ticks value.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let _d = Duration::<u32, 1, 1_000>::from_ticks(1);
+    /// ```
+    #[inline]
+    pub const fn from_ticks(ticks: T) -> Self {
+        helpers::greater_than_0::<NOM>();
+        helpers::greater_than_0::<DENOM>();
 
-        // Duration / Duration = integer
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32> ops::Div<Duration<$i, R_NOM, R_DENOM>>
-            for Duration<$i, L_NOM, L_DENOM>
-        {
-            type Output = $i;
+        Duration { ticks }
+    }
 
-            #[inline]
-            fn div(self, other: Duration<$i, R_NOM, R_DENOM>) -> Self::Output {
-                let conv: Duration<$i, R_NOM, R_DENOM> = self.convert();
-                conv.ticks / other.ticks
-            }
+    /// Extract the ticks from a `Duration`.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let d = Duration::<u32, 1, 1_000>::from_ticks(234);
+    ///
+    /// assert_eq!(d.ticks(), 234);
+    /// ```
+    #[inline]
+    pub const fn ticks(&self) -> T {
+        self.ticks
+    }
+}
+
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    PartialOrd<Duration<T, R_NOM, R_DENOM>> for Duration<T, L_NOM, L_DENOM>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Duration<T, R_NOM, R_DENOM>) -> Option<Ordering> {
+        T::duration_partial_cmp(*self, *other)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Ord for Duration<T, NOM, DENOM> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ticks.cmp(&other.ticks)
+    }
+}
+
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    PartialEq<Duration<T, R_NOM, R_DENOM>> for Duration<T, L_NOM, L_DENOM>
+{
+    #[inline]
+    fn eq(&self, other: &Duration<T, R_NOM, R_DENOM>) -> bool {
+        T::duration_eq(*self, *other)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Eq for Duration<T, NOM, DENOM> {}
+
+// Duration - Duration = Duration (only same base until const_generics_defaults is
+// stabilized)
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Sub<Duration<T, NOM, DENOM>>
+    for Duration<T, NOM, DENOM>
+{
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn sub(self, other: Duration<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_sub(other) {
+            v
+        } else {
+            panic!("Sub failed!");
         }
+    }
+}
 
-        #[cfg(feature = "defmt")]
-        impl<const NOM: u32, const DENOM: u32> defmt::Format for Duration<$i, NOM, DENOM>
-        {
-            fn format(&self, f: defmt::Formatter) {
-                if NOM == 3_600 && DENOM == 1 {
-                    defmt::write!(f, "{} h", self.ticks)
-                } else if NOM == 60 && DENOM == 1 {
-                    defmt::write!(f, "{} min", self.ticks)
-                } else if NOM == 1 && DENOM == 1 {
-                    defmt::write!(f, "{} s", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000 {
-                    defmt::write!(f, "{} ms", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000 {
-                    defmt::write!(f, "{} us", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000_000 {
-                    defmt::write!(f, "{} ns", self.ticks)
-                } else {
-                    defmt::write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
-                }
-            }
+// Duration -= Duration
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::SubAssign<Duration<T, NOM, DENOM>>
+    for Duration<T, NOM, DENOM>
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+// Duration + Duration = Duration (only same base until const_generics_defaults is
+// stabilized)
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Add<Duration<T, NOM, DENOM>>
+    for Duration<T, NOM, DENOM>
+{
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn add(self, other: Duration<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_add(other) {
+            v
+        } else {
+            panic!("Add failed!");
         }
+    }
+}
 
-        impl<const NOM: u32, const DENOM: u32> core::fmt::Display for Duration<$i, NOM, DENOM> {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if NOM == 3_600 && DENOM == 1 {
-                    write!(f, "{} h", self.ticks)
-                } else if NOM == 60 && DENOM == 1 {
-                    write!(f, "{} min", self.ticks)
-                } else if NOM == 1 && DENOM == 1 {
-                    write!(f, "{} s", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000 {
-                    write!(f, "{} ms", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000 {
-                    write!(f, "{} us", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000_000 {
-                    write!(f, "{} ns", self.ticks)
-                } else {
-                    write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
-                }
-            }
+// Duration += Duration
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::AddAssign<Duration<T, NOM, DENOM>>
+    for Duration<T, NOM, DENOM>
+{
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+// integer * Duration = Duration
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Mul<Duration<T, NOM, DENOM>> for u32 {
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn mul(self, mut other: Duration<T, NOM, DENOM>) -> Self::Output {
+        other.ticks = other.ticks * factor::<T>(self);
+        other
+    }
+}
+
+// Duration * integer = Duration
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Mul<u32> for Duration<T, NOM, DENOM> {
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn mul(mut self, other: u32) -> Self::Output {
+        self.ticks = self.ticks * factor::<T>(other);
+        self
+    }
+}
+
+// Duration *= integer
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::MulAssign<u32> for Duration<T, NOM, DENOM> {
+    #[inline]
+    fn mul_assign(&mut self, other: u32) {
+        *self = *self * other;
+    }
+}
+
+// Duration / integer = Duration
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Div<u32> for Duration<T, NOM, DENOM> {
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn div(mut self, other: u32) -> Self::Output {
+        // A divisor larger than the backing integer always gives 0
+        self.ticks = match T::from_u128(other as u128) {
+            Some(other) => self.ticks / other,
+            None => T::ZERO,
+        };
+        self
+    }
+}
+
+// Duration /= integer
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::DivAssign<u32> for Duration<T, NOM, DENOM> {
+    #[inline]
+    fn div_assign(&mut self, other: u32) {
+        *self = *self / other;
+    }
+}
+
+// Duration / Duration = integer
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    ops::Div<Duration<T, R_NOM, R_DENOM>> for Duration<T, L_NOM, L_DENOM>
+{
+    type Output = T;
+
+    #[inline]
+    fn div(self, other: Duration<T, R_NOM, R_DENOM>) -> Self::Output {
+        let conv: Duration<T, R_NOM, R_DENOM> = self.convert();
+        conv.ticks / other.ticks
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: TickInt + defmt::Format, const NOM: u64, const DENOM: u64> defmt::Format
+    for Duration<T, NOM, DENOM>
+{
+    // `defmt::write!` interns the format string, which clippy cannot see
+    #[allow(clippy::if_same_then_else)]
+    fn format(&self, f: defmt::Formatter) {
+        if NOM == 604_800 && DENOM == 1 {
+            defmt::write!(f, "{} w", self.ticks)
+        } else if NOM == 86_400 && DENOM == 1 {
+            defmt::write!(f, "{} d", self.ticks)
+        } else if NOM == 3_600 && DENOM == 1 {
+            defmt::write!(f, "{} h", self.ticks)
+        } else if NOM == 60 && DENOM == 1 {
+            defmt::write!(f, "{} min", self.ticks)
+        } else if NOM == 1 && DENOM == 1 {
+            defmt::write!(f, "{} s", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000 {
+            defmt::write!(f, "{} ms", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000 {
+            defmt::write!(f, "{} us", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000 {
+            defmt::write!(f, "{} ns", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000 {
+            defmt::write!(f, "{} ps", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000_000 {
+            defmt::write!(f, "{} fs", self.ticks)
+        } else {
+            defmt::write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
         }
-    };
+    }
 }
 
-impl_duration_for_integer!(u32);
-impl_duration_for_integer!(u64);
+impl<T: TickInt, const NOM: u64, const DENOM: u64> core::fmt::Display for Duration<T, NOM, DENOM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if NOM == 604_800 && DENOM == 1 {
+            write!(f, "{} w", self.ticks)
+        } else if NOM == 86_400 && DENOM == 1 {
+            write!(f, "{} d", self.ticks)
+        } else if NOM == 3_600 && DENOM == 1 {
+            write!(f, "{} h", self.ticks)
+        } else if NOM == 60 && DENOM == 1 {
+            write!(f, "{} min", self.ticks)
+        } else if NOM == 1 && DENOM == 1 {
+            write!(f, "{} s", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000 {
+            write!(f, "{} ms", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000 {
+            write!(f, "{} us", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000 {
+            write!(f, "{} ns", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000 {
+            write!(f, "{} ps", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000_000 {
+            write!(f, "{} fs", self.ticks)
+        } else {
+            write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
+        }
+    }
+}
 
 //
 // Operations between u32 and u64 Durations
 //
 
-impl<const NOM: u32, const DENOM: u32> From<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> From<Duration<u32, NOM, DENOM>>
     for Duration<u64, NOM, DENOM>
 {
     #[inline]
@@ -641,22 +994,22 @@ impl<const NOM: u32, const DENOM: u32> From<Duration<u32, NOM, DENOM>>
     }
 }
 
-impl<const NOM: u32, const DENOM: u32> convert::TryFrom<Duration<u64, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> convert::TryFrom<Duration<u64, NOM, DENOM>>
     for Duration<u32, NOM, DENOM>
 {
-    type Error = ();
+    type Error = Error;
 
     #[inline]
-    fn try_from(val: Duration<u64, NOM, DENOM>) -> Result<Duration<u32, NOM, DENOM>, ()> {
+    fn try_from(val: Duration<u64, NOM, DENOM>) -> Result<Duration<u32, NOM, DENOM>, Error> {
         Ok(Duration::<u32, NOM, DENOM>::from_ticks(
-            val.ticks().try_into().map_err(|_| ())?,
+            val.ticks().try_into().map_err(|_| Error::Overflow)?,
         ))
     }
 }
 
 // Duration - Duration = Duration (to make shorthands work, until const_generics_defaults is
 // stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::Sub<Duration<u32, NOM, DENOM>>
     for Duration<u64, NOM, DENOM>
 {
     type Output = Duration<u64, NOM, DENOM>;
@@ -674,7 +1027,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<u32, NOM, DENOM>>
 }
 
 // Duration -= Duration (to make shorthands work, until const_generics_defaults is stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::SubAssign<Duration<u32, NOM, DENOM>>
     for Duration<u64, NOM, DENOM>
 {
     #[inline]
@@ -685,7 +1038,7 @@ impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<u32, NOM, DENOM>>
 
 // Duration + Duration = Duration (to make shorthands work, until const_generics_defaults is
 // stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::Add<Duration<u32, NOM, DENOM>>
     for Duration<u64, NOM, DENOM>
 {
     type Output = Duration<u64, NOM, DENOM>;
@@ -703,7 +1056,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<u32, NOM, DENOM>>
 }
 
 // Duration += Duration (to make shorthands work, until const_generics_defaults is stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::AddAssign<Duration<u32, NOM, DENOM>>
     for Duration<u64, NOM, DENOM>
 {
     #[inline]
@@ -712,7 +1065,7 @@ impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<u32, NOM, DENOM>>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialOrd<Duration<u32, R_NOM, R_DENOM>> for Duration<u64, L_NOM, L_DENOM>
 {
     #[inline]
@@ -723,7 +1076,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialEq<Duration<u32, R_NOM, R_DENOM>> for Duration<u64, L_NOM, L_DENOM>
 {
     #[inline]
@@ -734,7 +1087,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialOrd<Duration<u64, R_NOM, R_DENOM>> for Duration<u32, L_NOM, L_DENOM>
 {
     #[inline]
@@ -743,7 +1096,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialEq<Duration<u64, R_NOM, R_DENOM>> for Duration<u32, L_NOM, L_DENOM>
 {
     #[inline]
@@ -752,214 +1105,379 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
+//
+// Widening and narrowing between the other backing integers
+//
+
+macro_rules! impl_duration_widening {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl<const NOM: u64, const DENOM: u64> From<Duration<$from, NOM, DENOM>>
+                for Duration<$to, NOM, DENOM>
+            {
+                #[inline]
+                fn from(val: Duration<$from, NOM, DENOM>) -> Duration<$to, NOM, DENOM> {
+                    Duration::<$to, NOM, DENOM>::from_ticks(val.ticks() as $to)
+                }
+            }
+
+            impl<const NOM: u64, const DENOM: u64> convert::TryFrom<Duration<$to, NOM, DENOM>>
+                for Duration<$from, NOM, DENOM>
+            {
+                type Error = Error;
+
+                #[inline]
+                fn try_from(val: Duration<$to, NOM, DENOM>) -> Result<Duration<$from, NOM, DENOM>, Error> {
+                    Ok(Duration::<$from, NOM, DENOM>::from_ticks(
+                        val.ticks().try_into().map_err(|_| Error::Overflow)?,
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+impl_duration_widening!(u8 => u16, u32, u64, u128);
+impl_duration_widening!(u16 => u32, u64, u128);
+impl_duration_widening!(u32 => u128);
+impl_duration_widening!(u64 => u128);
+
 /// Extension trait for simple short-hands for u32 Durations
 pub trait ExtU32 {
+    /// Shorthand for creating a duration which represents femtoseconds.
+    fn femtos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents picoseconds.
+    fn picos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
     /// Shorthand for creating a duration which represents nanoseconds.
-    fn nanos<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn nanos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents microseconds.
-    fn micros<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn micros<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents milliseconds.
-    fn millis<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn millis<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents seconds.
-    fn secs<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn secs<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents minutes.
-    fn minutes<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn minutes<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents hours.
-    fn hours<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn hours<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents days.
+    fn days<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents weeks.
+    fn weeks<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 }
 
 impl ExtU32 for u32 {
     #[inline]
-    fn nanos<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn femtos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::femtos(self)
+    }
+
+    #[inline]
+    fn picos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::picos(self)
+    }
+
+    #[inline]
+    fn nanos<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::nanos(self)
     }
 
     #[inline]
-    fn micros<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn micros<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::micros(self)
     }
 
     #[inline]
-    fn millis<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn millis<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::millis(self)
     }
 
     #[inline]
-    fn secs<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn secs<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::secs(self)
     }
 
     #[inline]
-    fn minutes<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn minutes<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::minutes(self)
     }
 
     #[inline]
-    fn hours<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn hours<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::hours(self)
     }
+
+    #[inline]
+    fn days<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::days(self)
+    }
+
+    #[inline]
+    fn weeks<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::weeks(self)
+    }
 }
 
 /// Extension trait for simple short-hands for u32 Durations (ceil rounded)
 pub trait ExtU32Ceil {
+    /// Shorthand for creating a duration which represents femtoseconds.
+    fn femtos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents picoseconds.
+    fn picos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
     /// Shorthand for creating a duration which represents nanoseconds.
-    fn nanos_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn nanos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents microseconds.
-    fn micros_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn micros_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents milliseconds.
-    fn millis_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn millis_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents seconds.
-    fn secs_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn secs_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents minutes.
-    fn minutes_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn minutes_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents hours.
-    fn hours_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM>;
+    fn hours_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents days.
+    fn days_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents weeks.
+    fn weeks_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM>;
 }
 
 impl ExtU32Ceil for u32 {
     #[inline]
-    fn nanos_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn femtos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::femtos_at_least(self)
+    }
+
+    #[inline]
+    fn picos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::picos_at_least(self)
+    }
+
+    #[inline]
+    fn nanos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::nanos_at_least(self)
     }
 
     #[inline]
-    fn micros_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn micros_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::micros_at_least(self)
     }
 
     #[inline]
-    fn millis_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn millis_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::millis_at_least(self)
     }
 
     #[inline]
-    fn secs_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn secs_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::secs_at_least(self)
     }
 
     #[inline]
-    fn minutes_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn minutes_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::minutes_at_least(self)
     }
 
     #[inline]
-    fn hours_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u32, NOM, DENOM> {
+    fn hours_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
         Duration::<u32, NOM, DENOM>::hours_at_least(self)
     }
+
+    #[inline]
+    fn days_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::days_at_least(self)
+    }
+
+    #[inline]
+    fn weeks_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u32, NOM, DENOM> {
+        Duration::<u32, NOM, DENOM>::weeks_at_least(self)
+    }
 }
 
 /// Extension trait for simple short-hands for u64 Durations
 pub trait ExtU64 {
+    /// Shorthand for creating a duration which represents femtoseconds.
+    fn femtos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents picoseconds.
+    fn picos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
     /// Shorthand for creating a duration which represents nanoseconds.
-    fn nanos<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn nanos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents microseconds.
-    fn micros<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn micros<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents milliseconds.
-    fn millis<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn millis<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents seconds.
-    fn secs<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn secs<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents minutes.
-    fn minutes<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn minutes<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents hours.
-    fn hours<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn hours<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents days.
+    fn days<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents weeks.
+    fn weeks<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 }
 
 impl ExtU64 for u64 {
     #[inline]
-    fn nanos<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn femtos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::femtos(self)
+    }
+
+    #[inline]
+    fn picos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::picos(self)
+    }
+
+    #[inline]
+    fn nanos<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::nanos(self)
     }
 
     #[inline]
-    fn micros<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn micros<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::micros(self)
     }
 
     #[inline]
-    fn millis<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn millis<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::millis(self)
     }
 
     #[inline]
-    fn secs<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn secs<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::secs(self)
     }
 
     #[inline]
-    fn minutes<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn minutes<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::minutes(self)
     }
 
     #[inline]
-    fn hours<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn hours<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::hours(self)
     }
+
+    #[inline]
+    fn days<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::days(self)
+    }
+
+    #[inline]
+    fn weeks<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::weeks(self)
+    }
 }
 
 /// Extension trait for simple short-hands for u64 Durations (ceil rounded)
 pub trait ExtU64Ceil {
+    /// Shorthand for creating a duration which represents femtoseconds.
+    fn femtos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents picoseconds.
+    fn picos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
     /// Shorthand for creating a duration which represents nanoseconds.
-    fn nanos_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn nanos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents microseconds.
-    fn micros_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn micros_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents milliseconds.
-    fn millis_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn millis_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents seconds.
-    fn secs_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn secs_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents minutes.
-    fn minutes_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn minutes_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 
     /// Shorthand for creating a duration which represents hours.
-    fn hours_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM>;
+    fn hours_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents days.
+    fn days_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a duration which represents weeks.
+    fn weeks_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM>;
 }
 
 impl ExtU64Ceil for u64 {
     #[inline]
-    fn nanos_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn femtos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::femtos_at_least(self)
+    }
+
+    #[inline]
+    fn picos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::picos_at_least(self)
+    }
+
+    #[inline]
+    fn nanos_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::nanos_at_least(self)
     }
 
     #[inline]
-    fn micros_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn micros_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::micros_at_least(self)
     }
 
     #[inline]
-    fn millis_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn millis_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::millis_at_least(self)
     }
 
     #[inline]
-    fn secs_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn secs_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::secs_at_least(self)
     }
 
     #[inline]
-    fn minutes_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn minutes_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::minutes_at_least(self)
     }
 
     #[inline]
-    fn hours_at_least<const NOM: u32, const DENOM: u32>(self) -> Duration<u64, NOM, DENOM> {
+    fn hours_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
         Duration::<u64, NOM, DENOM>::hours_at_least(self)
     }
+
+    #[inline]
+    fn days_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::days_at_least(self)
+    }
+
+    #[inline]
+    fn weeks_at_least<const NOM: u64, const DENOM: u64>(self) -> Duration<u64, NOM, DENOM> {
+        Duration::<u64, NOM, DENOM>::weeks_at_least(self)
+    }
 }
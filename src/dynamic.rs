@@ -0,0 +1,1053 @@
+//! `Duration`, `Instant` and `Rate` with a base only known at run time.
+//!
+//! These mirror the const generic types for clocks which are configured at run time, e.g. from
+//! a PLL setting read from EEPROM or an oscillator trimmed against USB start-of-frame packets.
+//! All conversions between bases are done at run time and round down, as `convert` does for the
+//! const generic types, while comparisons are exact. Arithmetic which would overflow gives `None`
+//! in the `checked_` methods and panics in the operators.
+
+use crate::tick_int::{factor, TickInt};
+use crate::{Duration, Error, Instant, Rate};
+use core::cmp::Ordering;
+use core::convert;
+use core::ops;
+
+/// Run time base of a [`DynDuration`], [`DynInstant`] or [`DynRate`], the equivalent of the
+/// `NOM / DENOM` const generics.
+///
+/// Always kept reduced, so equal bases compare equal.
+///
+/// ```
+/// # use fugit::*;
+/// // Tick period of a 48 MHz clock
+/// let base = Fraction::new(1, 48_000_000);
+///
+/// assert_eq!(Fraction::new(10, 480_000_000), base);
+/// assert_eq!(base.denom(), 48_000_000);
+/// assert_eq!(Fraction::try_new(1, 0), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    nom: u64,
+    denom: u64,
+}
+
+impl Fraction {
+    /// Create a fraction, `None` if the nominator or denominator is zero.
+    pub const fn try_new(nom: u64, denom: u64) -> Option<Self> {
+        if nom == 0 || denom == 0 {
+            return None;
+        }
+
+        let divisor = gcd::binary_u64(nom, denom);
+
+        Some(Fraction {
+            nom: nom / divisor,
+            denom: denom / divisor,
+        })
+    }
+
+    /// Create a fraction.
+    ///
+    /// Panics if the nominator or denominator is zero.
+    pub const fn new(nom: u64, denom: u64) -> Self {
+        if let Some(fraction) = Self::try_new(nom, denom) {
+            fraction
+        } else {
+            panic!("Invalid fraction!");
+        }
+    }
+
+    /// The nominator.
+    #[inline]
+    pub const fn nom(&self) -> u64 {
+        self.nom
+    }
+
+    /// The denominator.
+    #[inline]
+    pub const fn denom(&self) -> u64 {
+        self.denom
+    }
+
+    /// Factors `(mul, div)` which move a value from this base to `other`, reduced.
+    const fn factors_to(self, other: Fraction) -> (u128, u128) {
+        let mul = self.nom as u128 * other.denom as u128;
+        let div = self.denom as u128 * other.nom as u128;
+        let divisor = gcd::binary_u128(mul, div);
+
+        (mul / divisor, div / divisor)
+    }
+}
+
+/// `value` moved from base `from` to base `to`, rounded down. `None` if it does not fit in `max`.
+const fn rebase(value: u128, from: Fraction, to: Fraction, max: u128) -> Option<u128> {
+    let (mul, div) = from.factors_to(to);
+
+    match value.checked_mul(mul) {
+        Some(v) if v / div <= max => Some(v / div),
+        _ => None,
+    }
+}
+
+/// Full 256-bit product of two `u128`, as `(high, low)`.
+const fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    const LO: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & LO);
+    let (b_hi, b_lo) = (b >> 64, b & LO);
+
+    let lo_lo = a_lo * b_lo;
+    let mid = (lo_lo >> 64) + ((a_hi * b_lo) & LO) + ((a_lo * b_hi) & LO);
+
+    let lo = (mid << 64) | (lo_lo & LO);
+    let hi = a_hi * b_hi + ((a_hi * b_lo) >> 64) + ((a_lo * b_hi) >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+/// Compare two values in different bases.
+const fn compare(lh: u128, lh_base: Fraction, rh: u128, rh_base: Fraction) -> Ordering {
+    let (mul, div) = lh_base.factors_to(rh_base);
+
+    // The factors are up to 128 bits, so compare the full products
+    let (lh, rh) = (mul_wide(lh, mul), mul_wide(rh, div));
+
+    if lh.0 < rh.0 || (lh.0 == rh.0 && lh.1 < rh.1) {
+        Ordering::Less
+    } else if lh.0 > rh.0 || lh.1 > rh.1 {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// The inverse of `value` in base `from`, in base `to`, rounded down. Used to move between
+/// durations and rates. `None` if `value` is zero or the result does not fit in `max`.
+const fn invert(value: u128, from: Fraction, to: Fraction, max: u128) -> Option<u128> {
+    if value == 0 {
+        return None;
+    }
+
+    // 1 / (value * from.nom / from.denom) / (to.nom / to.denom)
+    let num = from.denom as u128 * to.denom as u128;
+    let den = match (from.nom as u128 * to.nom as u128).checked_mul(value) {
+        Some(den) => den,
+        // Larger than the numerator
+        None => return Some(0),
+    };
+
+    if num / den <= max {
+        Some(num / den)
+    } else {
+        None
+    }
+}
+
+/// Represents a duration of time in a base only known at run time.
+///
+/// The generic `T` can either be `u32` or `u64`: `duration in seconds = base * ticks`.
+///
+/// ```
+/// # use fugit::*;
+/// // A timer clocked from a PLL which is configured at run time
+/// let base = Fraction::new(1, 72_000_000);
+/// let d = DynDuration::<u32>::from_ticks(7_200, base);
+///
+/// assert_eq!(d.to_micros(), 100);
+/// assert_eq!(d, MicrosDurationU32::micros(100).into());
+///
+/// let us: Option<MicrosDurationU32> = d.try_into_duration();
+/// assert_eq!(us, Some(MicrosDurationU32::micros(100)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DynDuration<T> {
+    ticks: T,
+    base: Fraction,
+}
+
+/// Represents an instant in time in a base only known at run time.
+///
+/// The generic `T` can either be `u32` or `u64`, and the ticks may wrap around as for
+/// [`Instant`]. Instants can only be compared with instants of the same base.
+///
+/// ```
+/// # use fugit::*;
+/// let base = Fraction::new(1, 32_768);
+/// let i1 = DynInstant::<u32>::from_ticks(u32::MAX, base);
+/// let i2 = i1 + DynDuration::<u32>::millis(500, base);
+///
+/// assert_eq!(i2.ticks(), 16_383);
+/// assert!(i2 > i1);
+/// assert_eq!((i2 - i1).to_millis(), 500);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DynInstant<T> {
+    ticks: T,
+    base: Fraction,
+}
+
+/// Represents a frequency in a base only known at run time.
+///
+/// The generic `T` can either be `u32` or `u64`: `rate in Hz = base * raw`.
+///
+/// ```
+/// # use fugit::*;
+/// // A trimmed oscillator measured in steps of 1/16 Hz
+/// let r = DynRate::<u32>::from_raw(768_000_003, Fraction::new(1, 16));
+///
+/// assert_eq!(r.to_MHz(), 48);
+/// assert!(r > HertzU32::MHz(48).into());
+///
+/// let period = r.try_into_duration(Fraction::new(1, 1_000_000_000)).unwrap();
+/// assert_eq!(period.ticks(), 20);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DynRate<T> {
+    raw: T,
+    base: Fraction,
+}
+
+macro_rules! dyn_shorthand {
+    ($i:ty, $nom:literal, $denom:literal, $unit:ident, $to_unit:ident, $unitstr:literal) => {
+        #[doc = concat!("Convert the duration to an integer number of ", $unitstr, ".")]
+        ///
+        /// Panics if the result does not fit.
+        #[inline]
+        pub const fn $to_unit(&self) -> $i {
+            self.convert(Fraction::new($nom, $denom)).ticks
+        }
+
+        #[doc = concat!("Shorthand for creating a duration which represents ", $unitstr, ".")]
+        ///
+        /// Panics if the result does not fit.
+        #[inline]
+        pub const fn $unit(val: $i, base: Fraction) -> Self {
+            DynDuration::<$i>::from_ticks(val, Fraction::new($nom, $denom)).convert(base)
+        }
+    };
+}
+
+macro_rules! impl_dyn_for_integer {
+    ($i:ty) => {
+        impl DynDuration<$i> {
+            /// Create a `DynDuration` from a ticks value and base.
+            #[inline]
+            pub const fn from_ticks(ticks: $i, base: Fraction) -> Self {
+                DynDuration { ticks, base }
+            }
+
+            /// Extract the ticks.
+            #[inline]
+            pub const fn ticks(&self) -> $i {
+                self.ticks
+            }
+
+            /// The base of the ticks.
+            #[inline]
+            pub const fn base(&self) -> Fraction {
+                self.base
+            }
+
+            /// Returns true if the duration is zero.
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.ticks == 0
+            }
+
+            /// Create a `DynDuration` with the base of a const generic `Duration`.
+            #[inline]
+            pub const fn from_duration<const NOM: u64, const DENOM: u64>(
+                duration: Duration<$i, NOM, DENOM>,
+            ) -> Self {
+                DynDuration {
+                    ticks: duration.ticks,
+                    base: Fraction::new(NOM, DENOM),
+                }
+            }
+
+            /// Convert to a const generic `Duration`, rounding down. `None` on overflow.
+            pub const fn try_into_duration<const NOM: u64, const DENOM: u64>(
+                self,
+            ) -> Option<Duration<$i, NOM, DENOM>> {
+                if let Some(d) = self.checked_convert(Fraction::new(NOM, DENOM)) {
+                    Some(Duration::<$i, NOM, DENOM>::from_ticks(d.ticks))
+                } else {
+                    None
+                }
+            }
+
+            /// Convert to another base, rounding down. `None` on overflow.
+            pub const fn checked_convert(self, base: Fraction) -> Option<Self> {
+                if let Some(ticks) = rebase(self.ticks as u128, self.base, base, <$i>::MAX as u128)
+                {
+                    Some(DynDuration {
+                        ticks: ticks as $i,
+                        base,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Convert to another base, rounding down.
+            ///
+            /// Panics on overflow.
+            pub const fn convert(self, base: Fraction) -> Self {
+                if let Some(v) = self.checked_convert(base) {
+                    v
+                } else {
+                    panic!("Convert failed!");
+                }
+            }
+
+            /// Add two durations while checking for overflow, the result is in the base of
+            /// `self`.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    if let Some(ticks) = self.ticks.checked_add(other.ticks) {
+                        return Some(DynDuration {
+                            ticks,
+                            base: self.base,
+                        });
+                    }
+                }
+
+                None
+            }
+
+            /// Subtract two durations while checking for overflow, the result is in the base of
+            /// `self`.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    if let Some(ticks) = self.ticks.checked_sub(other.ticks) {
+                        return Some(DynDuration {
+                            ticks,
+                            base: self.base,
+                        });
+                    }
+                }
+
+                None
+            }
+
+            /// Const comparison, exact for all bases.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> Ordering {
+                compare(
+                    self.ticks as u128,
+                    self.base,
+                    other.ticks as u128,
+                    other.base,
+                )
+            }
+
+            /// Const equality check.
+            #[inline]
+            pub const fn const_eq(self, other: Self) -> bool {
+                matches!(self.const_cmp(other), Ordering::Equal)
+            }
+
+            /// Convert to the period of a rate, rounding down. `None` if the duration is zero or
+            /// on overflow.
+            pub const fn try_into_rate(self, base: Fraction) -> Option<DynRate<$i>> {
+                if let Some(raw) = invert(self.ticks as u128, self.base, base, <$i>::MAX as u128) {
+                    Some(DynRate {
+                        raw: raw as $i,
+                        base,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Create from the period of a rate, rounding down. `None` if the rate is zero or on
+            /// overflow.
+            #[inline]
+            pub const fn try_from_rate(rate: DynRate<$i>, base: Fraction) -> Option<Self> {
+                rate.try_into_duration(base)
+            }
+
+            dyn_shorthand!(
+                $i,
+                1,
+                1_000_000_000_000_000,
+                femtos,
+                to_femtos,
+                "femtoseconds"
+            );
+            dyn_shorthand!($i, 1, 1_000_000_000_000, picos, to_picos, "picoseconds");
+            dyn_shorthand!($i, 1, 1_000_000_000, nanos, to_nanos, "nanoseconds");
+            dyn_shorthand!($i, 1, 1_000_000, micros, to_micros, "microseconds");
+            dyn_shorthand!($i, 1, 1_000, millis, to_millis, "milliseconds");
+            dyn_shorthand!($i, 1, 1, secs, to_secs, "seconds");
+            dyn_shorthand!($i, 60, 1, minutes, to_minutes, "minutes");
+            dyn_shorthand!($i, 3_600, 1, hours, to_hours, "hours");
+            dyn_shorthand!($i, 86_400, 1, days, to_days, "days");
+            dyn_shorthand!($i, 604_800, 1, weeks, to_weeks, "weeks");
+        }
+
+        impl DynInstant<$i> {
+            /// Create a `DynInstant` from a ticks value and base.
+            #[inline]
+            pub const fn from_ticks(ticks: $i, base: Fraction) -> Self {
+                DynInstant { ticks, base }
+            }
+
+            /// Extract the ticks.
+            #[inline]
+            pub const fn ticks(&self) -> $i {
+                self.ticks
+            }
+
+            /// The base of the ticks.
+            #[inline]
+            pub const fn base(&self) -> Fraction {
+                self.base
+            }
+
+            /// Create a `DynInstant` with the base of a const generic `Instant`.
+            #[inline]
+            pub const fn from_instant<const NOM: u64, const DENOM: u64>(
+                instant: Instant<$i, NOM, DENOM>,
+            ) -> Self {
+                DynInstant {
+                    ticks: instant.ticks(),
+                    base: Fraction::new(NOM, DENOM),
+                }
+            }
+
+            /// Convert to a const generic `Instant`, converting the duration since the epoch and
+            /// rounding down. `None` on overflow.
+            pub const fn try_into_instant<const NOM: u64, const DENOM: u64>(
+                self,
+            ) -> Option<Instant<$i, NOM, DENOM>> {
+                if let Some(d) = self
+                    .duration_since_epoch()
+                    .try_into_duration::<NOM, DENOM>()
+                {
+                    Some(Instant::<$i, NOM, DENOM>::from_ticks(d.ticks))
+                } else {
+                    None
+                }
+            }
+
+            /// Const comparison of `DynInstant`s, taking wrapping into account as for
+            /// [`Instant::const_cmp`]. `None` if the bases differ.
+            pub const fn const_cmp(self, other: Self) -> Option<Ordering> {
+                if self.base.nom != other.base.nom || self.base.denom != other.base.denom {
+                    return None;
+                }
+
+                Some(
+                    Instant::<$i, 1, 1>::from_ticks(self.ticks)
+                        .const_cmp(Instant::<$i, 1, 1>::from_ticks(other.ticks)),
+                )
+            }
+
+            /// Duration since the start of the instant.
+            #[inline]
+            pub const fn duration_since_epoch(self) -> DynDuration<$i> {
+                DynDuration {
+                    ticks: self.ticks,
+                    base: self.base,
+                }
+            }
+
+            /// Duration between instants, `None` if `other` is later than `self` or the bases
+            /// differ.
+            pub const fn checked_duration_since(self, other: Self) -> Option<DynDuration<$i>> {
+                match self.const_cmp(other) {
+                    Some(Ordering::Greater | Ordering::Equal) => Some(DynDuration {
+                        ticks: self.ticks.wrapping_sub(other.ticks),
+                        base: self.base,
+                    }),
+                    _ => None,
+                }
+            }
+
+            /// Subtract a duration while checking for overflow of the duration conversion.
+            pub const fn checked_sub_duration(self, other: DynDuration<$i>) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    Some(DynInstant {
+                        ticks: self.ticks.wrapping_sub(other.ticks),
+                        base: self.base,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Add a duration while checking for overflow of the duration conversion.
+            pub const fn checked_add_duration(self, other: DynDuration<$i>) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    Some(DynInstant {
+                        ticks: self.ticks.wrapping_add(other.ticks),
+                        base: self.base,
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl DynRate<$i> {
+            /// Create a `DynRate` from a raw value and base.
+            #[inline]
+            pub const fn from_raw(raw: $i, base: Fraction) -> Self {
+                DynRate { raw, base }
+            }
+
+            /// Extract the raw value.
+            #[inline]
+            pub const fn raw(&self) -> $i {
+                self.raw
+            }
+
+            /// The base of the raw value.
+            #[inline]
+            pub const fn base(&self) -> Fraction {
+                self.base
+            }
+
+            /// Create a `DynRate` with the base of a const generic `Rate`.
+            #[inline]
+            pub const fn from_rate<const NOM: u64, const DENOM: u64>(
+                rate: Rate<$i, NOM, DENOM>,
+            ) -> Self {
+                DynRate {
+                    raw: rate.raw,
+                    base: Fraction::new(NOM, DENOM),
+                }
+            }
+
+            /// Convert to a const generic `Rate`, rounding down. `None` on overflow.
+            pub const fn try_into_rate<const NOM: u64, const DENOM: u64>(
+                self,
+            ) -> Option<Rate<$i, NOM, DENOM>> {
+                if let Some(r) = self.checked_convert(Fraction::new(NOM, DENOM)) {
+                    Some(Rate::<$i, NOM, DENOM>::from_raw(r.raw))
+                } else {
+                    None
+                }
+            }
+
+            /// Convert to another base, rounding down. `None` on overflow.
+            pub const fn checked_convert(self, base: Fraction) -> Option<Self> {
+                if let Some(raw) = rebase(self.raw as u128, self.base, base, <$i>::MAX as u128) {
+                    Some(DynRate {
+                        raw: raw as $i,
+                        base,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Convert to another base, rounding down.
+            ///
+            /// Panics on overflow.
+            pub const fn convert(self, base: Fraction) -> Self {
+                if let Some(v) = self.checked_convert(base) {
+                    v
+                } else {
+                    panic!("Convert failed!");
+                }
+            }
+
+            /// Add two rates while checking for overflow, the result is in the base of `self`.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    if let Some(raw) = self.raw.checked_add(other.raw) {
+                        return Some(DynRate {
+                            raw,
+                            base: self.base,
+                        });
+                    }
+                }
+
+                None
+            }
+
+            /// Subtract two rates while checking for overflow, the result is in the base of
+            /// `self`.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                if let Some(other) = other.checked_convert(self.base) {
+                    if let Some(raw) = self.raw.checked_sub(other.raw) {
+                        return Some(DynRate {
+                            raw,
+                            base: self.base,
+                        });
+                    }
+                }
+
+                None
+            }
+
+            /// Const comparison, exact for all bases.
+            #[inline]
+            pub const fn const_cmp(self, other: Self) -> Ordering {
+                compare(self.raw as u128, self.base, other.raw as u128, other.base)
+            }
+
+            /// Const equality check.
+            #[inline]
+            pub const fn const_eq(self, other: Self) -> bool {
+                matches!(self.const_cmp(other), Ordering::Equal)
+            }
+
+            /// Convert to the period of the rate, rounding down. `None` if the rate is zero or on
+            /// overflow.
+            pub const fn try_into_duration(self, base: Fraction) -> Option<DynDuration<$i>> {
+                if let Some(ticks) = invert(self.raw as u128, self.base, base, <$i>::MAX as u128) {
+                    Some(DynDuration {
+                        ticks: ticks as $i,
+                        base,
+                    })
+                } else {
+                    None
+                }
+            }
+
+            /// Create from the period of a duration, rounding down. `None` if the duration is
+            /// zero or on overflow.
+            #[inline]
+            pub const fn try_from_duration(
+                duration: DynDuration<$i>,
+                base: Fraction,
+            ) -> Option<Self> {
+                duration.try_into_rate(base)
+            }
+
+            /// Convert the rate to an integer number of Hz.
+            ///
+            /// Panics if the result does not fit.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn to_Hz(&self) -> $i {
+                self.convert(Fraction::new(1, 1)).raw
+            }
+
+            /// Convert the rate to an integer number of kHz.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn to_kHz(&self) -> $i {
+                self.convert(Fraction::new(1_000, 1)).raw
+            }
+
+            /// Convert the rate to an integer number of MHz.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn to_MHz(&self) -> $i {
+                self.convert(Fraction::new(1_000_000, 1)).raw
+            }
+
+            /// Shorthand for creating a rate which represents hertz.
+            ///
+            /// Panics if the result does not fit.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn Hz(val: $i, base: Fraction) -> Self {
+                DynRate::<$i>::from_raw(val, Fraction::new(1, 1)).convert(base)
+            }
+
+            /// Shorthand for creating a rate which represents kilohertz.
+            ///
+            /// Panics if the result does not fit.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn kHz(val: $i, base: Fraction) -> Self {
+                DynRate::<$i>::from_raw(val, Fraction::new(1_000, 1)).convert(base)
+            }
+
+            /// Shorthand for creating a rate which represents megahertz.
+            ///
+            /// Panics if the result does not fit.
+            #[inline]
+            #[allow(non_snake_case)]
+            pub const fn MHz(val: $i, base: Fraction) -> Self {
+                DynRate::<$i>::from_raw(val, Fraction::new(1_000_000, 1)).convert(base)
+            }
+        }
+
+        impl PartialOrd for DynDuration<$i> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.const_cmp(*other))
+            }
+        }
+
+        impl PartialEq for DynDuration<$i> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.const_eq(*other)
+            }
+        }
+
+        impl PartialOrd for DynRate<$i> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.const_cmp(*other))
+            }
+        }
+
+        impl PartialEq for DynRate<$i> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.const_eq(*other)
+            }
+        }
+
+        impl PartialOrd for DynInstant<$i> {
+            /// Takes wrapping into account as for [`Instant`], `None` if the bases differ.
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.const_cmp(*other)
+            }
+        }
+
+        impl PartialEq for DynInstant<$i> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.ticks == other.ticks && self.base == other.base
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> From<Duration<$i, NOM, DENOM>> for DynDuration<$i> {
+            #[inline]
+            fn from(val: Duration<$i, NOM, DENOM>) -> Self {
+                Self::from_duration(val)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> convert::TryFrom<DynDuration<$i>>
+            for Duration<$i, NOM, DENOM>
+        {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(val: DynDuration<$i>) -> Result<Self, Error> {
+                val.try_into_duration().ok_or(Error::Overflow)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> From<Instant<$i, NOM, DENOM>> for DynInstant<$i> {
+            #[inline]
+            fn from(val: Instant<$i, NOM, DENOM>) -> Self {
+                Self::from_instant(val)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> convert::TryFrom<DynInstant<$i>>
+            for Instant<$i, NOM, DENOM>
+        {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(val: DynInstant<$i>) -> Result<Self, Error> {
+                val.try_into_instant().ok_or(Error::Overflow)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> From<Rate<$i, NOM, DENOM>> for DynRate<$i> {
+            #[inline]
+            fn from(val: Rate<$i, NOM, DENOM>) -> Self {
+                Self::from_rate(val)
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> convert::TryFrom<DynRate<$i>>
+            for Rate<$i, NOM, DENOM>
+        {
+            type Error = Error;
+
+            #[inline]
+            fn try_from(val: DynRate<$i>) -> Result<Self, Error> {
+                val.try_into_rate().ok_or(Error::Overflow)
+            }
+        }
+
+        // DynDuration + DynDuration = DynDuration
+        impl ops::Add for DynDuration<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self::Output {
+                if let Some(v) = self.checked_add(other) {
+                    v
+                } else {
+                    panic!("Add failed!");
+                }
+            }
+        }
+
+        // DynDuration += DynDuration
+        impl ops::AddAssign for DynDuration<$i> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        // DynDuration - DynDuration = DynDuration
+        impl ops::Sub for DynDuration<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self::Output {
+                if let Some(v) = self.checked_sub(other) {
+                    v
+                } else {
+                    panic!("Sub failed!");
+                }
+            }
+        }
+
+        // DynDuration -= DynDuration
+        impl ops::SubAssign for DynDuration<$i> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        // DynDuration * integer = DynDuration
+        impl ops::Mul<u32> for DynDuration<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(mut self, other: u32) -> Self::Output {
+                self.ticks *= factor::<$i>(other);
+                self
+            }
+        }
+
+        // integer * DynDuration = DynDuration
+        impl ops::Mul<DynDuration<$i>> for u32 {
+            type Output = DynDuration<$i>;
+
+            #[inline]
+            fn mul(self, other: DynDuration<$i>) -> Self::Output {
+                other * self
+            }
+        }
+
+        // DynDuration *= integer
+        impl ops::MulAssign<u32> for DynDuration<$i> {
+            #[inline]
+            fn mul_assign(&mut self, other: u32) {
+                *self = *self * other;
+            }
+        }
+
+        // DynDuration / integer = DynDuration
+        impl ops::Div<u32> for DynDuration<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn div(mut self, other: u32) -> Self::Output {
+                // A divisor larger than the backing integer always gives 0
+                self.ticks = match <$i as TickInt>::from_u128(other as u128) {
+                    Some(other) => self.ticks / other,
+                    None => 0,
+                };
+                self
+            }
+        }
+
+        // DynDuration /= integer
+        impl ops::DivAssign<u32> for DynDuration<$i> {
+            #[inline]
+            fn div_assign(&mut self, other: u32) {
+                *self = *self / other;
+            }
+        }
+
+        // DynDuration / DynDuration = integer
+        impl ops::Div for DynDuration<$i> {
+            type Output = $i;
+
+            #[inline]
+            fn div(self, other: Self) -> Self::Output {
+                self.convert(other.base).ticks / other.ticks
+            }
+        }
+
+        // DynInstant - DynInstant = DynDuration
+        impl ops::Sub for DynInstant<$i> {
+            type Output = DynDuration<$i>;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self::Output {
+                if let Some(v) = self.checked_duration_since(other) {
+                    v
+                } else {
+                    panic!("Sub failed! Other > self");
+                }
+            }
+        }
+
+        // DynInstant - DynDuration = DynInstant
+        impl ops::Sub<DynDuration<$i>> for DynInstant<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: DynDuration<$i>) -> Self::Output {
+                if let Some(v) = self.checked_sub_duration(other) {
+                    v
+                } else {
+                    panic!("Sub failed! Overflow");
+                }
+            }
+        }
+
+        // DynInstant -= DynDuration
+        impl ops::SubAssign<DynDuration<$i>> for DynInstant<$i> {
+            #[inline]
+            fn sub_assign(&mut self, other: DynDuration<$i>) {
+                *self = *self - other;
+            }
+        }
+
+        // DynInstant + DynDuration = DynInstant
+        impl ops::Add<DynDuration<$i>> for DynInstant<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: DynDuration<$i>) -> Self::Output {
+                if let Some(v) = self.checked_add_duration(other) {
+                    v
+                } else {
+                    panic!("Add failed! Overflow");
+                }
+            }
+        }
+
+        // DynInstant += DynDuration
+        impl ops::AddAssign<DynDuration<$i>> for DynInstant<$i> {
+            #[inline]
+            fn add_assign(&mut self, other: DynDuration<$i>) {
+                *self = *self + other;
+            }
+        }
+
+        // DynRate + DynRate = DynRate
+        impl ops::Add for DynRate<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self::Output {
+                if let Some(v) = self.checked_add(other) {
+                    v
+                } else {
+                    panic!("Add failed!");
+                }
+            }
+        }
+
+        // DynRate += DynRate
+        impl ops::AddAssign for DynRate<$i> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        // DynRate - DynRate = DynRate
+        impl ops::Sub for DynRate<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self::Output {
+                if let Some(v) = self.checked_sub(other) {
+                    v
+                } else {
+                    panic!("Sub failed!");
+                }
+            }
+        }
+
+        // DynRate -= DynRate
+        impl ops::SubAssign for DynRate<$i> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        // DynRate * integer = DynRate
+        impl ops::Mul<u32> for DynRate<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(mut self, other: u32) -> Self::Output {
+                self.raw *= factor::<$i>(other);
+                self
+            }
+        }
+
+        // DynRate / integer = DynRate
+        impl ops::Div<u32> for DynRate<$i> {
+            type Output = Self;
+
+            #[inline]
+            fn div(mut self, other: u32) -> Self::Output {
+                // A divisor larger than the backing integer always gives 0
+                self.raw = match <$i as TickInt>::from_u128(other as u128) {
+                    Some(other) => self.raw / other,
+                    None => 0,
+                };
+                self
+            }
+        }
+
+        impl core::fmt::Display for DynDuration<$i> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} ticks @ ({}/{})",
+                    self.ticks, self.base.nom, self.base.denom
+                )
+            }
+        }
+
+        impl core::fmt::Display for DynRate<$i> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{} raw @ ({}/{})",
+                    self.raw, self.base.nom, self.base.denom
+                )
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for DynDuration<$i> {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(
+                    f,
+                    "{} ticks @ ({}/{})",
+                    self.ticks,
+                    self.base.nom,
+                    self.base.denom
+                )
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for DynRate<$i> {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(
+                    f,
+                    "{} raw @ ({}/{})",
+                    self.raw,
+                    self.base.nom,
+                    self.base.denom
+                )
+            }
+        }
+    };
+}
+
+impl_dyn_for_integer!(u32);
+impl_dyn_for_integer!(u64);
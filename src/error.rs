@@ -0,0 +1,111 @@
+//! Errors of the fallible conversions and arithmetic.
+
+use core::fmt;
+
+/// Reason a conversion or arithmetic operation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The result is too large for the backing integer.
+    Overflow,
+    /// The result is below zero.
+    Underflow,
+    /// A zero duration or rate was inverted.
+    DivideByZero,
+    /// The result is not exactly representable in the target base.
+    PrecisionLoss,
+    /// The earlier instant is after the later one.
+    InstantInFuture,
+}
+
+impl Error {
+    /// Short description of the error.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Error::Overflow => "overflow",
+            Error::Underflow => "underflow",
+            Error::DivideByZero => "divide-by-zero",
+            Error::PrecisionLoss => "precision loss",
+            Error::InstantInFuture => "instant in the future",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Error::Overflow => defmt::write!(f, "overflow"),
+            Error::Underflow => defmt::write!(f, "underflow"),
+            Error::DivideByZero => defmt::write!(f, "divide-by-zero"),
+            Error::PrecisionLoss => defmt::write!(f, "precision loss"),
+            Error::InstantInFuture => defmt::write!(f, "instant in the future"),
+        }
+    }
+}
+
+/// Reason an exact base change failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExactError<T> {
+    /// The result is too large for the backing integer.
+    Overflow,
+    /// The base change is not exact.
+    PrecisionLoss {
+        /// The result rounded down.
+        truncated: T,
+        /// What was rounded away, in units of `1 / denom` ticks of the target base.
+        remainder: u128,
+        /// The denominator of `remainder`.
+        denom: u128,
+    },
+}
+
+impl<T> From<ExactError<T>> for Error {
+    #[inline]
+    fn from(val: ExactError<T>) -> Self {
+        match val {
+            ExactError::Overflow => Error::Overflow,
+            ExactError::PrecisionLoss { .. } => Error::PrecisionLoss,
+        }
+    }
+}
+
+impl<T> fmt::Display for ExactError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactError::Overflow => f.write_str("overflow"),
+            ExactError::PrecisionLoss {
+                remainder, denom, ..
+            } => write!(f, "precision loss of {}/{} ticks", remainder, denom),
+        }
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for ExactError<T> {}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for ExactError<T> {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            ExactError::Overflow => defmt::write!(f, "overflow"),
+            ExactError::PrecisionLoss {
+                truncated,
+                remainder,
+                denom,
+            } => defmt::write!(
+                f,
+                "precision loss of {}/{} ticks, truncated to {}",
+                remainder,
+                denom,
+                truncated
+            ),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Frequency measurement from input capture timestamps.
+
+use crate::{Duration, Instant, Rate};
+
+/// Reciprocal frequency counter over the last `N` captured edges.
+///
+/// Periods are the wrapping difference between consecutive captures, so the counter may wrap
+/// around any number of times as long as each single period is shorter than its range. The rate
+/// is measured over the whole window of `N - 1` periods which gives sub-tick resolution, while
+/// the mean period is rounded to whole ticks.
+///
+/// ```
+/// # use fugit::*;
+/// // Input capture of a 1 MHz timer
+/// let mut counter = FrequencyCounter::<u32, 1, 1_000_000, 4>::new();
+///
+/// for ticks in [0, 333, 667, 1_000] {
+///     counter.push(Instant::<u32, 1, 1_000_000>::from_ticks(ticks));
+/// }
+///
+/// // 3 periods in 1000 us
+/// assert_eq!(counter.rate::<1, 1>(), Some(HertzU32::Hz(3_000)));
+/// assert_eq!(counter.rate::<1, 1_000>(), Some(Rate::<u32, 1, 1_000>::from_raw(3_000_000)));
+/// assert_eq!(counter.mean_period().map(|d| d.ticks()), Some(333));
+/// assert_eq!(counter.jitter().map(|d| d.ticks()), Some(1));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FrequencyCounter<T, const NOM: u64, const DENOM: u64, const N: usize> {
+    captures: [Instant<T, NOM, DENOM>; N],
+    /// Index of the next capture to be overwritten
+    head: usize,
+    len: usize,
+}
+
+impl<T, const NOM: u64, const DENOM: u64, const N: usize> FrequencyCounter<T, NOM, DENOM, N> {
+    /// Number of captures in the window.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no captures.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of complete periods in the window.
+    #[inline]
+    pub const fn periods(&self) -> usize {
+        self.len.saturating_sub(1)
+    }
+
+    /// Remove all captures, e.g. after the input has stopped.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Index of the `k`th oldest capture.
+    #[inline]
+    const fn index(&self, k: usize) -> usize {
+        (self.head + N - self.len + k) % N
+    }
+}
+
+macro_rules! impl_frequency_counter_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64, const N: usize> FrequencyCounter<$i, NOM, DENOM, N> {
+            /// Create an empty frequency counter.
+            pub const fn new() -> Self {
+                FrequencyCounter {
+                    captures: [Instant::<$i, NOM, DENOM>::from_ticks(0); N],
+                    head: 0,
+                    len: 0,
+                }
+            }
+
+            /// Add a captured edge, dropping the oldest one if the window is full.
+            pub fn push(&mut self, capture: Instant<$i, NOM, DENOM>) {
+                if N == 0 {
+                    return;
+                }
+
+                self.captures[self.head] = capture;
+                self.head = (self.head + 1) % N;
+
+                if self.len < N {
+                    self.len += 1;
+                }
+            }
+
+            /// Ticks of the `k`th oldest period.
+            #[inline]
+            fn period_ticks(&self, k: usize) -> $i {
+                let start = self.captures[self.index(k)].ticks();
+                let end = self.captures[self.index(k + 1)].ticks();
+
+                end.wrapping_sub(start)
+            }
+
+            /// Sum of the ticks of all periods in the window.
+            fn total_ticks(&self) -> u128 {
+                (0..self.periods())
+                    .map(|k| self.period_ticks(k) as u128)
+                    .sum()
+            }
+
+            /// The measured rate, averaged over all periods in the window and rounded to the
+            /// nearest value in the requested base. `None` if there is not a complete period, the
+            /// periods are zero or the rate does not fit.
+            pub fn rate<const R_NOM: u64, const R_DENOM: u64>(
+                &self,
+            ) -> Option<Rate<$i, R_NOM, R_DENOM>> {
+                let total = self.total_ticks();
+
+                if total == 0 {
+                    return None;
+                }
+
+                // rate = periods / (total * NOM / DENOM) in Hz
+                let num = (self.periods() as u128)
+                    .checked_mul(DENOM as u128)?
+                    .checked_mul(R_DENOM as u128)?;
+                let den = total.checked_mul(NOM as u128)?.checked_mul(R_NOM as u128)?;
+
+                let raw = (num + den / 2) / den;
+                if raw > <$i>::MAX as u128 {
+                    None
+                } else {
+                    Some(Rate::<$i, R_NOM, R_DENOM>::from_raw(raw as $i))
+                }
+            }
+
+            /// The mean period, rounded to the nearest tick. `None` if there is not a complete
+            /// period.
+            pub fn mean_period(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                let periods = self.periods() as u128;
+
+                if periods == 0 {
+                    return None;
+                }
+
+                let mean = (self.total_ticks() + periods / 2) / periods;
+                Some(Duration::<$i, NOM, DENOM>::from_ticks(mean as $i))
+            }
+
+            /// The peak-to-peak jitter, i.e. the difference between the longest and the shortest
+            /// period. `None` if there is not a complete period.
+            pub fn jitter(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                let min = (0..self.periods()).map(|k| self.period_ticks(k)).min()?;
+                let max = (0..self.periods()).map(|k| self.period_ticks(k)).max()?;
+
+                Some(Duration::<$i, NOM, DENOM>::from_ticks(max - min))
+            }
+
+            /// The RMS jitter, i.e. the standard deviation of the periods rounded down to whole
+            /// ticks. `None` if there is not a complete period.
+            pub fn rms_jitter(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                let periods = self.periods() as u128;
+
+                if periods == 0 {
+                    return None;
+                }
+
+                // Sum of squared deviations in units of `1 / periods^2` ticks^2, exact for the
+                // mean
+                let total = self.total_ticks();
+                let sum_sq = (0..self.periods()).fold(0u128, |acc, k| {
+                    let deviation = (self.period_ticks(k) as u128 * periods).abs_diff(total);
+                    acc.saturating_add(deviation.saturating_mul(deviation))
+                });
+
+                let variance = sum_sq / (periods * periods * periods);
+                Some(Duration::<$i, NOM, DENOM>::from_ticks(
+                    variance.isqrt() as $i
+                ))
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64, const N: usize> Default
+            for FrequencyCounter<$i, NOM, DENOM, N>
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_frequency_counter_for_integer!(u32);
+impl_frequency_counter_for_integer!(u64);
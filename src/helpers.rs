@@ -5,7 +5,7 @@
 /// error[E0401]: can't use generic parameters from outer function
 ///   --> src/main.rs:25:47
 ///    |
-/// 21 | impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+/// 21 | impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
 ///    |                                                                    ------- const parameter from outer function
 /// ...
 /// 25 |         const TEST: u32 = gcd_binary_u32(L_DENOM, R_DENOM);
@@ -13,42 +13,70 @@
 ///
 /// For more information about this error, try `rustc --explain E0401`
 /// ```
-pub struct Helpers<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>;
+pub struct Helpers<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>;
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     Helpers<L_NOM, L_DENOM, R_NOM, R_DENOM>
 {
     /// Helper constants generated at compile time
-    pub const DIVISOR: u64 =
-        gcd::binary_u64(L_DENOM as u64 * R_NOM as u64, R_DENOM as u64 * L_NOM as u64);
+    pub const DIVISOR: u128 = gcd::binary_u128(
+        L_DENOM as u128 * R_NOM as u128,
+        R_DENOM as u128 * L_NOM as u128,
+    );
 
     /// Helper constants generated at compile time
-    pub const DIVISOR_2: u64 =
-        gcd::binary_u64(L_NOM as u64 * R_NOM as u64, R_DENOM as u64 * L_DENOM as u64);
+    pub const DIVISOR_2: u128 = gcd::binary_u128(
+        L_NOM as u128 * R_NOM as u128,
+        R_DENOM as u128 * L_DENOM as u128,
+    );
 
     /// Helper constants generated at compile time for Durations
-    pub const RD_TIMES_LN: u64 = (R_DENOM as u64 * L_NOM as u64) / Self::DIVISOR;
+    pub const RD_TIMES_LN: u128 = (R_DENOM as u128 * L_NOM as u128) / Self::DIVISOR;
 
     /// Helper constants generated at compile time
-    pub const LD_TIMES_RN: u64 = (L_DENOM as u64 * R_NOM as u64) / Self::DIVISOR;
+    pub const LD_TIMES_RN: u128 = (L_DENOM as u128 * R_NOM as u128) / Self::DIVISOR;
 
     /// Helper constants generated at compile time for Rates
-    pub const LN_TIMES_RN: u64 = (L_NOM as u64 * R_NOM as u64) / Self::DIVISOR_2;
+    pub const LN_TIMES_RN: u128 = (L_NOM as u128 * R_NOM as u128) / Self::DIVISOR_2;
 
     /// Helper constants generated at compile time for Rates
-    pub const RD_TIMES_LD: u64 = (R_DENOM as u64 * L_DENOM as u64) / Self::DIVISOR_2;
+    pub const RD_TIMES_LD: u128 = (R_DENOM as u128 * L_DENOM as u128) / Self::DIVISOR_2;
 
     /// Helper constants generated at compile time for Rates
-    pub const RATE_TO_DURATION_NUMERATOR: u64 = Self::RD_TIMES_LD / Self::LN_TIMES_RN;
+    pub const RATE_TO_DURATION_NUMERATOR: u128 = Self::RD_TIMES_LD / Self::LN_TIMES_RN;
 
     /// Helper constants generated at compile time
     pub const SAME_BASE: bool = Self::LD_TIMES_RN == Self::RD_TIMES_LN;
+
+    /// Const assert hack, the bases are the same
+    pub const SAME: () = assert!(Self::SAME_BASE, "The bases are not the same");
+
+    /// Const assert hack, a tick of the left base is a whole number of ticks of the right base
+    pub const EXACT: () = assert!(Self::LD_TIMES_RN == 1, "The base change is not exact");
 }
 
 #[allow(dead_code)]
 #[allow(path_statements)]
-pub(crate) const fn greater_than_0<const N: u32>() {
-    Assert::<N, 0>::GREATER;
+pub(crate) const fn greater_than_0<const N: u64>() {
+    AssertBase::<N>::GREATER_THAN_0;
+}
+
+/// Compile time check that a base fits in 32 bits, for the helpers which do their arithmetic
+/// in `u128` and can not overflow with 32-bit bases.
+#[allow(path_statements)]
+pub(crate) const fn fits_u32<const N: u64>() {
+    AssertBase::<N>::FITS_U32;
+}
+
+/// Compile time check that converting from the left base to the right base is exact.
+#[allow(path_statements)]
+pub(crate) const fn exact<
+    const L_NOM: u64,
+    const L_DENOM: u64,
+    const R_NOM: u64,
+    const R_DENOM: u64,
+>() {
+    Helpers::<L_NOM, L_DENOM, R_NOM, R_DENOM>::EXACT;
 }
 
 #[allow(dead_code)]
@@ -78,3 +106,79 @@ impl<const L: u32, const R: u32> Assert<L, R> {
     /// Const assert hack
     pub const POWER_OF_TWO: () = assert!(L.is_power_of_two());
 }
+
+/// Const assert hack for the 64-bit bases
+pub struct AssertBase<const N: u64>;
+
+impl<const N: u64> AssertBase<N> {
+    /// Const assert hack
+    pub const GREATER_THAN_0: () = assert!(N > 0);
+
+    /// Const assert hack
+    pub const FITS_U32: () = assert!(N <= u32::MAX as u64);
+}
+
+/// Signed error of `actual_num / actual_den` relative to `target_num / target_den` in parts per
+/// million, saturating at the bounds of `i32`. `None` if the inputs overflow or the target is zero.
+pub(crate) const fn error_ppm(
+    actual_num: u128,
+    actual_den: u128,
+    target_num: u128,
+    target_den: u128,
+) -> Option<i32> {
+    let (lh, rh) = match (
+        actual_num.checked_mul(target_den),
+        target_num.checked_mul(actual_den),
+    ) {
+        (Some(lh), Some(rh)) if rh > 0 => (lh, rh),
+        _ => return None,
+    };
+
+    let (diff, negative) = if lh >= rh {
+        (lh - rh, false)
+    } else {
+        (rh - lh, true)
+    };
+
+    let ppm = match diff.checked_mul(1_000_000) {
+        Some(v) => v / rh,
+        // Only reachable for huge errors, where the precision loss is irrelevant
+        None if rh >= 1_000_000 => diff / (rh / 1_000_000),
+        None => u128::MAX,
+    };
+
+    let ppm = if ppm > i32::MAX as u128 {
+        i32::MAX
+    } else {
+        ppm as i32
+    };
+
+    Some(if negative { -ppm } else { ppm })
+}
+
+/// True if `ticks * nom / denom` seconds are at most `max` cycles of a clock at
+/// `raw * c_nom / c_denom` Hz, with the cycles rounded up. False on overflow.
+pub(crate) const fn cycles_at_most(
+    ticks: u128,
+    nom: u64,
+    denom: u64,
+    raw: u128,
+    c_nom: u64,
+    c_denom: u64,
+    max: u128,
+) -> bool {
+    let num = match ticks.checked_mul(nom as u128) {
+        Some(v) => match v.checked_mul(raw) {
+            Some(v) => v.checked_mul(c_nom as u128),
+            None => None,
+        },
+        None => None,
+    };
+    let num = match num {
+        Some(num) => num,
+        None => return false,
+    };
+    let den = denom as u128 * c_denom as u128;
+
+    num.div_ceil(den) <= max
+}
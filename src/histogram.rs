@@ -0,0 +1,292 @@
+//! Fixed memory log-linear histogram of `Duration`s.
+
+use crate::helpers::Assert;
+use crate::Duration;
+
+/// Log-linear (HDR style) histogram of `Duration` samples, with `N` buckets and `P` bits of
+/// precision.
+///
+/// Values below `2^P` ticks are recorded exactly, above that each power of two is split into
+/// `2^(P - 1)` equally wide buckets so the relative error of a value is at most `2^(1 - P)`. The
+/// minimum, maximum and mean are exact.
+///
+/// All values of `B` bits are covered by `N = 2^P + (B - P) * 2^(P - 1)` buckets, e.g. 124 for
+/// `u32` and 252 for `u64` at `P = 3`. Values beyond the last bucket are counted in the last
+/// bucket.
+///
+/// ```
+/// # use fugit::*;
+/// let mut hist = Histogram::<u32, 1, 1_000_000, 3, 124>::new();
+///
+/// for us in 1..=100 {
+///     hist.record(MicrosDurationU32::micros(us));
+/// }
+///
+/// assert_eq!(hist.count(), 100);
+/// assert_eq!(hist.min(), Some(MicrosDurationU32::micros(1)));
+/// assert_eq!(hist.max(), Some(MicrosDurationU32::micros(100)));
+/// assert_eq!(hist.mean(), Some(MicrosDurationU32::micros(51)));
+///
+/// // Within 25 % for P = 3
+/// assert_eq!(hist.percentile(1, 2), Some(MicrosDurationU32::micros(55)));
+/// assert_eq!(hist.percentile(99, 100), Some(MicrosDurationU32::micros(100)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Histogram<T, const NOM: u64, const DENOM: u64, const P: u32, const N: usize> {
+    buckets: [u32; N],
+    count: u64,
+    /// Sum of all samples in ticks
+    sum: u128,
+    min: Duration<T, NOM, DENOM>,
+    max: Duration<T, NOM, DENOM>,
+}
+
+impl<T, const NOM: u64, const DENOM: u64, const P: u32, const N: usize>
+    Histogram<T, NOM, DENOM, P, N>
+{
+    /// Const assert hack
+    const NOT_EMPTY: () = assert!(N > 0);
+
+    /// Number of recorded samples.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns true if no samples have been recorded.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Bucket of a value.
+    const fn index(ticks: u64) -> usize {
+        let sub = 1 << P;
+
+        let index = if ticks < sub {
+            ticks as usize
+        } else {
+            let exp = ticks.ilog2();
+            let shift = exp - P + 1;
+
+            (sub + (exp - P) as u64 * (sub / 2) + ((ticks >> shift) - sub / 2)) as usize
+        };
+
+        if index < N {
+            index
+        } else {
+            N - 1
+        }
+    }
+
+    /// Largest value of a bucket.
+    const fn highest(index: usize) -> u64 {
+        let sub = 1 << P;
+
+        if (index as u64) < sub {
+            index as u64
+        } else {
+            let i = index as u64 - sub;
+            let exp = P as u64 + i / (sub / 2);
+            let shift = exp - P as u64 + 1;
+
+            if shift >= 64 {
+                u64::MAX
+            } else {
+                ((i % (sub / 2) + sub / 2 + 1) << shift).wrapping_sub(1)
+            }
+        }
+    }
+
+    /// Largest value of the bucket holding the sample of rank `num / den`, `None` if empty or the
+    /// rank is invalid.
+    fn rank(&self, num: u32, den: u32) -> Option<u64> {
+        if self.count == 0 || den == 0 || num > den {
+            return None;
+        }
+
+        let rank = (self.count as u128 * num as u128)
+            .div_ceil(den as u128)
+            .max(1);
+
+        let mut seen = 0u128;
+        let mut index = 0;
+        for &count in self.buckets.iter() {
+            seen += count as u128;
+
+            if seen >= rank {
+                break;
+            }
+
+            index += 1;
+        }
+
+        // The last bucket also holds all larger values
+        if index >= N - 1 {
+            Some(u64::MAX)
+        } else {
+            Some(Self::highest(index))
+        }
+    }
+}
+
+macro_rules! impl_histogram_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64, const P: u32, const N: usize>
+            Histogram<$i, NOM, DENOM, P, N>
+        {
+            /// Create an empty histogram.
+            ///
+            /// `P` must be between 1 and 16 and `N` must be non-zero, else compilation fails.
+            #[allow(path_statements)]
+            pub const fn new() -> Self {
+                Assert::<P, 0>::GREATER;
+                Assert::<P, 16>::LESS_EQ;
+                Self::NOT_EMPTY;
+
+                Histogram {
+                    buckets: [0; N],
+                    count: 0,
+                    sum: 0,
+                    min: Duration::<$i, NOM, DENOM>::from_ticks(<$i>::MAX),
+                    max: Duration::<$i, NOM, DENOM>::from_ticks(0),
+                }
+            }
+
+            /// Record a sample of any base, saturating if it does not fit the histogram's base.
+            pub fn record<const O_NOM: u64, const O_DENOM: u64>(
+                &mut self,
+                sample: Duration<$i, O_NOM, O_DENOM>,
+            ) {
+                let sample = match sample.const_try_into::<NOM, DENOM>() {
+                    Some(sample) => sample,
+                    None => Duration::<$i, NOM, DENOM>::from_ticks(<$i>::MAX),
+                };
+                let ticks = sample.ticks();
+
+                let bucket = &mut self.buckets[Self::index(ticks as u64)];
+                *bucket = bucket.saturating_add(1);
+
+                self.count = self.count.saturating_add(1);
+                self.sum = self.sum.saturating_add(ticks as u128);
+
+                if ticks < self.min.ticks() {
+                    self.min = sample;
+                }
+
+                if ticks > self.max.ticks() {
+                    self.max = sample;
+                }
+            }
+
+            /// Add all samples of `other` to this histogram.
+            pub fn merge(&mut self, other: &Self) {
+                for (bucket, &count) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+                    *bucket = bucket.saturating_add(count);
+                }
+
+                self.count = self.count.saturating_add(other.count);
+                self.sum = self.sum.saturating_add(other.sum);
+
+                if other.min.ticks() < self.min.ticks() {
+                    self.min = other.min;
+                }
+
+                if other.max.ticks() > self.max.ticks() {
+                    self.max = other.max;
+                }
+            }
+
+            /// Remove all samples.
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            /// The smallest sample.
+            pub const fn min(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.count == 0 {
+                    None
+                } else {
+                    Some(self.min)
+                }
+            }
+
+            /// The largest sample.
+            pub const fn max(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.count == 0 {
+                    None
+                } else {
+                    Some(self.max)
+                }
+            }
+
+            /// The mean of all samples, rounded to the nearest tick.
+            pub const fn mean(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.count == 0 {
+                    None
+                } else {
+                    let count = self.count as u128;
+                    let mean = (self.sum + count / 2) / count;
+
+                    Some(Duration::<$i, NOM, DENOM>::from_ticks(mean as $i))
+                }
+            }
+
+            /// The value below which `num / den` of the samples fall, e.g. `(99, 100)` for the
+            /// 99th percentile. The value is the largest of its bucket, limited to the range of
+            /// recorded samples. `None` if empty, `den` is zero or `num > den`.
+            pub fn percentile(&self, num: u32, den: u32) -> Option<Duration<$i, NOM, DENOM>> {
+                let ticks = self.rank(num, den)?;
+
+                let ticks = if ticks < self.min.ticks() as u64 {
+                    self.min.ticks()
+                } else if ticks > self.max.ticks() as u64 {
+                    self.max.ticks()
+                } else {
+                    ticks as $i
+                };
+
+                Some(Duration::<$i, NOM, DENOM>::from_ticks(ticks))
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64, const P: u32, const N: usize> Default
+            for Histogram<$i, NOM, DENOM, P, N>
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl<const NOM: u64, const DENOM: u64, const P: u32, const N: usize> defmt::Format
+            for Histogram<$i, NOM, DENOM, P, N>
+        {
+            fn format(&self, f: defmt::Formatter) {
+                if let (Some(min), Some(mean), Some(p50), Some(p99), Some(max)) = (
+                    self.min(),
+                    self.mean(),
+                    self.percentile(1, 2),
+                    self.percentile(99, 100),
+                    self.max(),
+                ) {
+                    defmt::write!(
+                        f,
+                        "n: {}, min: {}, mean: {}, p50: {}, p99: {}, max: {}",
+                        self.count,
+                        min,
+                        mean,
+                        p50,
+                        p99,
+                        max
+                    )
+                } else {
+                    defmt::write!(f, "n: 0")
+                }
+            }
+        }
+    };
+}
+
+impl_histogram_for_integer!(u32);
+impl_histogram_for_integer!(u64);
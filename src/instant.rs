@@ -1,11 +1,13 @@
 use crate::duration::Duration;
-use crate::helpers::{self, Helpers};
+use crate::helpers;
+use crate::tick_int::{ExtInstant, TickInt};
+use crate::{Error, ExactError};
 use core::cmp::Ordering;
 use core::ops;
 
 /// Represents an instant in time.
 ///
-/// The generic `T` can either be `u32` or `u64`, and the const generics represent the ratio of the
+/// The generic `T` is a [`TickInt`](crate::TickInt), and the const generics represent the ratio of the
 /// ticks contained within the instant: `instant in seconds = NOM / DENOM * ticks`
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(
@@ -13,40 +15,13 @@ use core::ops;
     derive(postcard::experimental::max_size::MaxSize)
 )]
 #[derive(Clone, Copy, Debug)]
-pub struct Instant<T, const NOM: u32, const DENOM: u32> {
+pub struct Instant<T, const NOM: u64, const DENOM: u64> {
     ticks: T,
 }
 
 macro_rules! impl_instant_for_integer {
     ($i:ty) => {
-        impl<const NOM: u32, const DENOM: u32> Instant<$i, NOM, DENOM> {
-            /// Create an `Instant` from a ticks value.
-            ///
-            /// ```
-            /// # use fugit::*;
-            #[doc = concat!("let _i = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
-            /// ```
-            #[inline]
-            pub const fn from_ticks(ticks: $i) -> Self {
-                helpers::greater_than_0::<NOM>();
-                helpers::greater_than_0::<DENOM>();
-
-                Instant { ticks }
-            }
-
-            /// Extract the ticks from an `Instant`.
-            ///
-            /// ```
-            /// # use fugit::*;
-            #[doc = concat!("let i = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(234);")]
-            ///
-            /// assert_eq!(i.ticks(), 234);
-            /// ```
-            #[inline]
-            pub const fn ticks(&self) -> $i {
-                self.ticks
-            }
-
+        impl<const NOM: u64, const DENOM: u64> Instant<$i, NOM, DENOM> {
             /// Const comparison of `Instant`s.
             ///
             /// ```
@@ -134,27 +109,16 @@ macro_rules! impl_instant_for_integer {
             ///
             /// assert_eq!(i.checked_sub_duration(d).unwrap().ticks(), 0);
             /// ```
-            pub const fn checked_sub_duration<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_sub_duration<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Duration<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Duration::<$i, NOM, DENOM>::const_try_from(other) {
                     Some(Instant::<$i, NOM, DENOM>::from_ticks(
                         self.ticks.wrapping_sub(other.ticks()),
                     ))
                 } else {
-                    if let Some(lh) = other
-                        .ticks()
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let ticks = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        Some(Instant::<$i, NOM, DENOM>::from_ticks(
-                            self.ticks.wrapping_sub(ticks),
-                        ))
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
 
@@ -167,198 +131,390 @@ macro_rules! impl_instant_for_integer {
             ///
             /// assert_eq!(i.checked_add_duration(d).unwrap().ticks(), 2);
             /// ```
-            pub const fn checked_add_duration<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_add_duration<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Duration<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Duration::<$i, NOM, DENOM>::const_try_from(other) {
                     Some(Instant::<$i, NOM, DENOM>::from_ticks(
                         self.ticks.wrapping_add(other.ticks()),
                     ))
                 } else {
-                    if let Some(lh) = other
-                        .ticks()
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let ticks = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        Some(Instant::<$i, NOM, DENOM>::from_ticks(
-                            self.ticks.wrapping_add(ticks),
-                        ))
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
-        }
 
-        impl<const NOM: u32, const DENOM: u32> PartialOrd for Instant<$i, NOM, DENOM> {
-            /// This implementation deviates from the definition of
-            /// [PartialOrd::partial_cmp](core::cmp::PartialOrd::partial_cmp):
+            /// Const try from, succeeding only if the base change is exact.
             ///
-            /// It takes into account that ticks might wrap around. If the absolute
-            /// values of `self` and `other` differ by more than half the possible range, it is
-            /// assumed that an overflow occured and the result is reversed.
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i1 = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(250);")]
+            #[doc = concat!("let i2 = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(255);")]
             ///
-            /// That breaks the transitivity invariant: a < b and b < c no longer implies a < c.
-            #[inline]
-            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-                Some(self.const_cmp(*other))
+            #[doc = concat!("assert_eq!(Instant::<", stringify!($i), ", 1, 100>::exact_try_from(i1).unwrap().ticks(), 25);")]
+            #[doc = concat!("assert!(Instant::<", stringify!($i), ", 1, 100>::exact_try_from(i2).is_err());")]
+            /// ```
+            pub const fn exact_try_from<const I_NOM: u64, const I_DENOM: u64>(
+                instant: Instant<$i, I_NOM, I_DENOM>,
+            ) -> Result<Self, ExactError<Self>> {
+                match Duration::<$i, NOM, DENOM>::exact_try_from(instant.duration_since_epoch()) {
+                    Ok(d) => Ok(Self::from_ticks(d.ticks())),
+                    Err(ExactError::Overflow) => Err(ExactError::Overflow),
+                    Err(ExactError::PrecisionLoss {
+                        truncated,
+                        remainder,
+                        denom,
+                    }) => Err(ExactError::PrecisionLoss {
+                        truncated: Self::from_ticks(truncated.ticks()),
+                        remainder,
+                        denom,
+                    }),
+                }
             }
-        }
 
-        impl<const NOM: u32, const DENOM: u32> Ord for Instant<$i, NOM, DENOM> {
-            /// This implementation deviates from the definition of
-            /// [Ord::cmp](core::cmp::Ord::cmp):
+            /// Convert between bases, succeeding only if the base change is exact.
             ///
-            /// It takes into account that ticks might wrap around. If the absolute
-            /// values of `self` and `other` differ by more than half the possible range, it is
-            /// assumed that an overflow occured and the result is reversed.
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i1 = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(250);")]
             ///
-            /// That breaks the transitivity invariant: a < b and b < c no longer implies a < c.
+            /// assert_eq!(i1.exact_convert::<1, 100>().unwrap().ticks(), 25);
+            /// assert!(i1.exact_convert::<1, 1>().is_err());
+            /// ```
             #[inline]
-            fn cmp(&self, other: &Self) -> Ordering {
-                self.const_cmp(*other)
+            pub const fn exact_convert<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Instant<$i, O_NOM, O_DENOM>, ExactError<Instant<$i, O_NOM, O_DENOM>>> {
+                Instant::<$i, O_NOM, O_DENOM>::exact_try_from(self)
             }
-        }
 
-        impl<const NOM: u32, const DENOM: u32> PartialEq for Instant<$i, NOM, DENOM> {
+            /// Convert from a base where a tick is a whole number of ticks in this base, which
+            /// can not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i1 = Instant::<", stringify!($i), ", 1, 100>::from_ticks(25);")]
+            #[doc = concat!("let i2 = Instant::<", stringify!($i), ", 1, 1_000>::exact_from(i1);")]
+            ///
+            /// assert_eq!(i2.ticks(), 250);
+            /// ```
             #[inline]
-            fn eq(&self, other: &Self) -> bool {
-                self.ticks.eq(&other.ticks)
+            pub const fn exact_from<const I_NOM: u64, const I_DENOM: u64>(
+                instant: Instant<$i, I_NOM, I_DENOM>,
+            ) -> Self {
+                Self::from_ticks(
+                    Duration::<$i, NOM, DENOM>::exact_from(instant.duration_since_epoch()).ticks(),
+                )
             }
-        }
-
-        impl<const NOM: u32, const DENOM: u32> Eq for Instant<$i, NOM, DENOM> {}
 
-        // Instant - Instant = Duration
-        // We have limited this to use same numerator and denominator in both left and right hand sides,
-        // this allows for the extension traits to work. For usage with different fraction, use
-        // `checked_duration_since`.
-        impl<const NOM: u32, const DENOM: u32> ops::Sub<Instant<$i, NOM, DENOM>>
-            for Instant<$i, NOM, DENOM>
-        {
-            type Output = Duration<$i, NOM, DENOM>;
+            /// Convert to a base where a tick of this base is a whole number of ticks, which can
+            /// not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i1 = Instant::<", stringify!($i), ", 1, 100>::from_ticks(25);")]
+            ///
+            /// assert_eq!(i1.exact_into::<1, 1_000>().ticks(), 250);
+            /// ```
+            #[inline]
+            pub const fn exact_into<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Instant<$i, O_NOM, O_DENOM> {
+                Instant::<$i, O_NOM, O_DENOM>::exact_from(self)
+            }
 
+            /// Duration between `Instant`s, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i1 = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let i2 = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(2);")]
+            ///
+            /// assert_eq!(i2.try_duration_since(i1).unwrap().ticks(), 1);
+            /// assert_eq!(i1.try_duration_since(i2), Err(Error::InstantInFuture));
+            /// ```
             #[inline]
-            fn sub(self, other: Self) -> Self::Output {
+            pub const fn try_duration_since(
+                self,
+                other: Self,
+            ) -> Result<Duration<$i, NOM, DENOM>, Error> {
                 if let Some(v) = self.checked_duration_since(other) {
-                    v
+                    Ok(v)
                 } else {
-                    panic!("Sub failed! Other > self");
+                    Err(Error::InstantInFuture)
                 }
             }
-        }
-
-        // Instant - Duration = Instant
-        // We have limited this to use same numerator and denominator in both left and right hand sides,
-        // this allows for the extension traits to work. For usage with different fraction, use
-        // `checked_sub_duration`.
-        impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<$i, NOM, DENOM>>
-            for Instant<$i, NOM, DENOM>
-        {
-            type Output = Instant<$i, NOM, DENOM>;
 
+            /// Subtract a `Duration` from an `Instant`, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 1>::from_ticks(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(i.try_sub_duration(d1).unwrap().ticks(), 0);
+            /// assert_eq!(i.try_sub_duration(d2), Err(Error::Overflow));
+            /// ```
             #[inline]
-            fn sub(self, other: Duration<$i, NOM, DENOM>) -> Self::Output {
+            pub const fn try_sub_duration<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Duration<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
                 if let Some(v) = self.checked_sub_duration(other) {
-                    v
+                    Ok(v)
                 } else {
-                    panic!("Sub failed! Overflow");
+                    Err(Error::Overflow)
                 }
             }
-        }
-
-        // Instant -= Duration
-        // We have limited this to use same numerator and denominator in both left and right hand sides,
-        // this allows for the extension traits to work. For usage with different fraction, use
-        // `checked_sub_duration`.
-        impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<$i, NOM, DENOM>>
-            for Instant<$i, NOM, DENOM>
-        {
-            #[inline]
-            fn sub_assign(&mut self, other: Duration<$i, NOM, DENOM>) {
-                *self = *self - other;
-            }
-        }
-
-        // Instant + Duration = Instant
-        // We have limited this to use same numerator and denominator in both left and right hand sides,
-        // this allows for the extension traits to work. For usage with different fraction, use
-        // `checked_add_duration`.
-        impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<$i, NOM, DENOM>>
-            for Instant<$i, NOM, DENOM>
-        {
-            type Output = Instant<$i, NOM, DENOM>;
 
+            /// Add a `Duration` to an `Instant`, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let i = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(1);")]
+            #[doc = concat!("let d2 = Duration::<", stringify!($i), ", 1, 1>::from_ticks(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(i.try_add_duration(d1).unwrap().ticks(), 2);
+            /// assert_eq!(i.try_add_duration(d2), Err(Error::Overflow));
+            /// ```
             #[inline]
-            fn add(self, other: Duration<$i, NOM, DENOM>) -> Self::Output {
+            pub const fn try_add_duration<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Duration<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
                 if let Some(v) = self.checked_add_duration(other) {
-                    v
+                    Ok(v)
                 } else {
-                    panic!("Add failed! Overflow");
+                    Err(Error::Overflow)
                 }
             }
         }
+    };
+}
 
-        // Instant += Duration
-        // We have limited this to use same numerator and denominator in both left and right hand sides,
-        // this allows for the extension traits to work. For usage with different fraction, use
-        // `checked_add_duration`.
-        impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<$i, NOM, DENOM>>
-            for Instant<$i, NOM, DENOM>
-        {
-            #[inline]
-            fn add_assign(&mut self, other: Duration<$i, NOM, DENOM>) {
-                *self = *self + other;
-            }
+impl_instant_for_integer!(u8);
+impl_instant_for_integer!(u16);
+impl_instant_for_integer!(u32);
+impl_instant_for_integer!(u64);
+impl_instant_for_integer!(u128);
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Instant<T, NOM, DENOM> {
+    /// Create an `Instant` from a ticks value.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let _i = Instant::<u32, 1, 1_000>::from_ticks(1);
+    /// ```
+    #[inline]
+    pub const fn from_ticks(ticks: T) -> Self {
+        helpers::greater_than_0::<NOM>();
+        helpers::greater_than_0::<DENOM>();
+
+        Instant { ticks }
+    }
+
+    /// Extract the ticks from an `Instant`.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let i = Instant::<u32, 1, 1_000>::from_ticks(234);
+    ///
+    /// assert_eq!(i.ticks(), 234);
+    /// ```
+    #[inline]
+    pub const fn ticks(&self) -> T {
+        self.ticks
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> PartialOrd for Instant<T, NOM, DENOM> {
+    /// This implementation deviates from the definition of
+    /// [PartialOrd::partial_cmp](core::cmp::PartialOrd::partial_cmp):
+    ///
+    /// It takes into account that ticks might wrap around. If the absolute
+    /// values of `self` and `other` differ by more than half the possible range, it is
+    /// assumed that an overflow occured and the result is reversed.
+    ///
+    /// That breaks the transitivity invariant: a < b and b < c no longer implies a < c.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Ord for Instant<T, NOM, DENOM> {
+    /// This implementation deviates from the definition of
+    /// [Ord::cmp](core::cmp::Ord::cmp):
+    ///
+    /// It takes into account that ticks might wrap around. If the absolute
+    /// values of `self` and `other` differ by more than half the possible range, it is
+    /// assumed that an overflow occured and the result is reversed.
+    ///
+    /// That breaks the transitivity invariant: a < b and b < c no longer implies a < c.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        T::instant_cmp(*self, *other)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> PartialEq for Instant<T, NOM, DENOM> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ticks.eq(&other.ticks)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Eq for Instant<T, NOM, DENOM> {}
+
+// Instant - Instant = Duration
+// We have limited this to use same numerator and denominator in both left and right hand sides,
+// this allows for the extension traits to work. For usage with different fraction, use
+// `checked_duration_since`.
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Sub<Instant<T, NOM, DENOM>>
+    for Instant<T, NOM, DENOM>
+{
+    type Output = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self::Output {
+        if let Some(v) = self.checked_duration_since(other) {
+            v
+        } else {
+            panic!("Sub failed! Other > self");
         }
+    }
+}
 
-        #[cfg(feature = "defmt")]
-        impl<const NOM: u32, const DENOM: u32> defmt::Format for Instant<$i, NOM, DENOM> {
-            fn format(&self, f: defmt::Formatter) {
-                if NOM == 3_600 && DENOM == 1 {
-                    defmt::write!(f, "{} h", self.ticks)
-                } else if NOM == 60 && DENOM == 1 {
-                    defmt::write!(f, "{} min", self.ticks)
-                } else if NOM == 1 && DENOM == 1 {
-                    defmt::write!(f, "{} s", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000 {
-                    defmt::write!(f, "{} ms", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000 {
-                    defmt::write!(f, "{} us", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000_000 {
-                    defmt::write!(f, "{} ns", self.ticks)
-                } else {
-                    defmt::write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
-                }
-            }
+// Instant - Duration = Instant
+// We have limited this to use same numerator and denominator in both left and right hand sides,
+// this allows for the extension traits to work. For usage with different fraction, use
+// `checked_sub_duration`.
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Sub<Duration<T, NOM, DENOM>>
+    for Instant<T, NOM, DENOM>
+{
+    type Output = Instant<T, NOM, DENOM>;
+
+    #[inline]
+    fn sub(self, other: Duration<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_sub_duration(other) {
+            v
+        } else {
+            panic!("Sub failed! Overflow");
         }
+    }
+}
 
-        impl<const NOM: u32, const DENOM: u32> core::fmt::Display for Instant<$i, NOM, DENOM> {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if NOM == 3_600 && DENOM == 1 {
-                    write!(f, "{} h", self.ticks)
-                } else if NOM == 60 && DENOM == 1 {
-                    write!(f, "{} min", self.ticks)
-                } else if NOM == 1 && DENOM == 1 {
-                    write!(f, "{} s", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000 {
-                    write!(f, "{} ms", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000 {
-                    write!(f, "{} us", self.ticks)
-                } else if NOM == 1 && DENOM == 1_000_000_000 {
-                    write!(f, "{} ns", self.ticks)
-                } else {
-                    write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
-                }
-            }
+// Instant -= Duration
+// We have limited this to use same numerator and denominator in both left and right hand sides,
+// this allows for the extension traits to work. For usage with different fraction, use
+// `checked_sub_duration`.
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::SubAssign<Duration<T, NOM, DENOM>>
+    for Instant<T, NOM, DENOM>
+{
+    #[inline]
+    fn sub_assign(&mut self, other: Duration<T, NOM, DENOM>) {
+        *self = *self - other;
+    }
+}
+
+// Instant + Duration = Instant
+// We have limited this to use same numerator and denominator in both left and right hand sides,
+// this allows for the extension traits to work. For usage with different fraction, use
+// `checked_add_duration`.
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Add<Duration<T, NOM, DENOM>>
+    for Instant<T, NOM, DENOM>
+{
+    type Output = Instant<T, NOM, DENOM>;
+
+    #[inline]
+    fn add(self, other: Duration<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_add_duration(other) {
+            v
+        } else {
+            panic!("Add failed! Overflow");
         }
-    };
+    }
 }
 
-impl_instant_for_integer!(u32);
-impl_instant_for_integer!(u64);
+// Instant += Duration
+// We have limited this to use same numerator and denominator in both left and right hand sides,
+// this allows for the extension traits to work. For usage with different fraction, use
+// `checked_add_duration`.
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::AddAssign<Duration<T, NOM, DENOM>>
+    for Instant<T, NOM, DENOM>
+{
+    #[inline]
+    fn add_assign(&mut self, other: Duration<T, NOM, DENOM>) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: TickInt + defmt::Format, const NOM: u64, const DENOM: u64> defmt::Format
+    for Instant<T, NOM, DENOM>
+{
+    // `defmt::write!` interns the format string, which clippy cannot see
+    #[allow(clippy::if_same_then_else)]
+    fn format(&self, f: defmt::Formatter) {
+        if NOM == 604_800 && DENOM == 1 {
+            defmt::write!(f, "{} w", self.ticks)
+        } else if NOM == 86_400 && DENOM == 1 {
+            defmt::write!(f, "{} d", self.ticks)
+        } else if NOM == 3_600 && DENOM == 1 {
+            defmt::write!(f, "{} h", self.ticks)
+        } else if NOM == 60 && DENOM == 1 {
+            defmt::write!(f, "{} min", self.ticks)
+        } else if NOM == 1 && DENOM == 1 {
+            defmt::write!(f, "{} s", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000 {
+            defmt::write!(f, "{} ms", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000 {
+            defmt::write!(f, "{} us", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000 {
+            defmt::write!(f, "{} ns", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000 {
+            defmt::write!(f, "{} ps", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000_000 {
+            defmt::write!(f, "{} fs", self.ticks)
+        } else {
+            defmt::write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
+        }
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> core::fmt::Display for Instant<T, NOM, DENOM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if NOM == 604_800 && DENOM == 1 {
+            write!(f, "{} w", self.ticks)
+        } else if NOM == 86_400 && DENOM == 1 {
+            write!(f, "{} d", self.ticks)
+        } else if NOM == 3_600 && DENOM == 1 {
+            write!(f, "{} h", self.ticks)
+        } else if NOM == 60 && DENOM == 1 {
+            write!(f, "{} min", self.ticks)
+        } else if NOM == 1 && DENOM == 1 {
+            write!(f, "{} s", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000 {
+            write!(f, "{} ms", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000 {
+            write!(f, "{} us", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000 {
+            write!(f, "{} ns", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000 {
+            write!(f, "{} ps", self.ticks)
+        } else if NOM == 1 && DENOM == 1_000_000_000_000_000 {
+            write!(f, "{} fs", self.ticks)
+        } else {
+            write!(f, "{} ticks @ ({}/{})", self.ticks, NOM, DENOM)
+        }
+    }
+}
 
 //
 // Operations between u32 Duration and u64 Instant
@@ -368,7 +524,7 @@ impl_instant_for_integer!(u64);
 // We have limited this to use same numerator and denominator in both left and right hand sides,
 // this allows for the extension traits to work. For usage with different fraction, use
 // `checked_sub_duration`.
-impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::Sub<Duration<u32, NOM, DENOM>>
     for Instant<u64, NOM, DENOM>
 {
     type Output = Instant<u64, NOM, DENOM>;
@@ -387,7 +543,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Sub<Duration<u32, NOM, DENOM>>
 // We have limited this to use same numerator and denominator in both left and right hand sides,
 // this allows for the extension traits to work. For usage with different fraction, use
 // `checked_sub_duration`.
-impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::SubAssign<Duration<u32, NOM, DENOM>>
     for Instant<u64, NOM, DENOM>
 {
     #[inline]
@@ -400,7 +556,7 @@ impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Duration<u32, NOM, DENOM>>
 // We have limited this to use same numerator and denominator in both left and right hand sides,
 // this allows for the extension traits to work. For usage with different fraction, use
 // `checked_add_duration`.
-impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::Add<Duration<u32, NOM, DENOM>>
     for Instant<u64, NOM, DENOM>
 {
     type Output = Instant<u64, NOM, DENOM>;
@@ -419,7 +575,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Add<Duration<u32, NOM, DENOM>>
 // We have limited this to use same numerator and denominator in both left and right hand sides,
 // this allows for the extension traits to work. For usage with different fraction, use
 // `checked_add_duration`.
-impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::AddAssign<Duration<u32, NOM, DENOM>>
     for Instant<u64, NOM, DENOM>
 {
     #[inline]
@@ -428,7 +584,7 @@ impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Duration<u32, NOM, DENOM>>
     }
 }
 
-// impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+// impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
 //     ops::Add<Duration<u32, R_NOM, R_DENOM>> for Duration<u64, L_NOM, L_DENOM>
 // {
 //     type Output = Duration<u64, L_NOM, L_DENOM>;
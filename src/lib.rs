@@ -45,19 +45,63 @@
 #![deny(missing_docs)]
 
 mod aliases;
+mod backoff;
+mod baud;
+#[cfg(feature = "calendar")]
+mod calendar;
+mod clock_mapping;
+mod const_assert;
 mod duration;
+mod dynamic;
+mod error;
+mod frequency_counter;
 mod helpers;
+mod histogram;
 mod instant;
+mod literal;
+mod pll;
+mod pwm;
 mod rate;
+mod rate_limit;
+mod stats;
+mod stopwatch;
+mod tick_int;
+mod timer_config;
+mod timer_queue;
+mod timescale;
+mod timestamp;
 
 pub use aliases::*;
+pub use backoff::{ExponentialBackoff, JitteredBackoff, LinearBackoff};
+pub use baud::{BaudConfig, BaudConstraints};
+#[cfg(feature = "calendar")]
+pub use calendar::{DateTime, WallClock};
+pub use clock_mapping::ClockMapping;
+pub use const_assert::{assert_exact_base, assert_same_base, assert_valid_base};
 pub use duration::{Duration, ExtU32, ExtU32Ceil, ExtU64, ExtU64Ceil};
+pub use dynamic::{DynDuration, DynInstant, DynRate, Fraction};
+pub use error::{Error, ExactError};
+pub use frequency_counter::FrequencyCounter;
+pub use histogram::Histogram;
 pub use instant::Instant;
+#[doc(hidden)]
+pub use literal::__literal_base;
+pub use pll::{PllConfig, PllLimits};
+pub use pwm::{PwmAlignment, PwmTiming, Rounding};
 pub use rate::{ExtU32 as RateExtU32, ExtU64 as RateExtU64, Rate};
+pub use rate_limit::{LeakyBucket, TokenBucket};
+pub use stats::{DurationStats, RateStats};
+pub use stopwatch::{ScopeTimer, Stopwatch};
+pub use tick_int::{ExtDuration, ExtInstant, ExtRate, TickInt};
+pub use timer_config::{PrescalerEncoding, TimerConfig, TimerConstraints};
+pub use timer_queue::{TimerHandle, TimerQueue};
+pub use timescale::{Gps, GpsWeekTime, LeapSeconds, Tai, Utc};
+pub use timestamp::{NtpShort, NtpTimestamp, PtpTimestamp};
 
 #[cfg(test)]
 mod test {
     use crate::Duration;
+    use crate::Error;
     use crate::Instant;
     use crate::Rate;
     use crate::{
@@ -677,6 +721,21 @@ mod test {
         let d: Duration<u32, 1, 10_000> = 1.hours();
         assert_eq!(d.ticks(), 36_000_000);
 
+        let d: Duration<u32, 1, 10_000> = 1.days();
+        assert_eq!(d.ticks(), 864_000_000);
+
+        let d: Duration<u32, 1, 1> = 1.weeks();
+        assert_eq!(d.ticks(), 604_800);
+
+        let d: Duration<u32, 86_400, 1> = 25.hours_at_least();
+        assert_eq!(d.ticks(), 2);
+
+        let d: Duration<u32, 604_800, 1> = 8.days_at_least();
+        assert_eq!(d.ticks(), 2);
+
+        let d: Duration<u32, 604_800, 1> = 8.days();
+        assert_eq!(d.ticks(), 1);
+
         let d = Duration::<u32, 1, 10_000>::millis(10);
         assert_eq!(d.ticks(), 100);
 
@@ -708,6 +767,14 @@ mod test {
 
         let d = Duration::<u32, 1, 10_000>::from_ticks(180_000_000);
         assert_eq!(d.to_hours(), 5);
+
+        let d = Duration::<u32, 1, 1>::from_ticks(1_300_000);
+        assert_eq!(d.to_days(), 15);
+        assert_eq!(d.to_weeks(), 2);
+
+        let d = Duration::<u32, 604_800, 1>::weeks(3);
+        assert_eq!(d.ticks(), 3);
+        assert_eq!(d.to_days(), 21);
     }
 
     #[test]
@@ -741,6 +808,21 @@ mod test {
         let d: Duration<u64, 1, 10_000> = 1.hours();
         assert_eq!(d.ticks(), 36_000_000);
 
+        let d: Duration<u64, 1, 10_000> = 1.days();
+        assert_eq!(d.ticks(), 864_000_000);
+
+        let d: Duration<u64, 1, 10_000> = 1.weeks();
+        assert_eq!(d.ticks(), 6_048_000_000);
+
+        let d: Duration<u64, 86_400, 1> = 25.hours_at_least();
+        assert_eq!(d.ticks(), 2);
+
+        let d: Duration<u64, 604_800, 1> = 8.days_at_least();
+        assert_eq!(d.ticks(), 2);
+
+        let d: Duration<u64, 604_800, 1> = 8.days();
+        assert_eq!(d.ticks(), 1);
+
         let d = Duration::<u64, 1, 10_000>::millis(10);
         assert_eq!(d.ticks(), 100);
 
@@ -772,18 +854,29 @@ mod test {
 
         let d = Duration::<u64, 1, 10_000>::from_ticks(180_000_000);
         assert_eq!(d.to_hours(), 5);
+
+        let d = Duration::<u64, 1, 1>::from_ticks(1_300_000);
+        assert_eq!(d.to_days(), 15);
+        assert_eq!(d.to_weeks(), 2);
+
+        let d = Duration::<u64, 604_800, 1>::weeks(3);
+        assert_eq!(d.ticks(), 3);
+        assert_eq!(d.to_days(), 21);
+
+        assert_eq!(format!("{}", d), "3 w");
+        assert_eq!(format!("{}", Duration::<u64, 86_400, 1>::days(2)), "2 d");
     }
 
     #[test]
     fn duration_is_zero() {
         let d = Duration::<u64, 1, 1_000>::from_ticks(0);
-        assert_eq!(d.is_zero(), true);
+        assert!(d.is_zero());
         let d = Duration::<u64, 1, 1_000>::from_ticks(1);
-        assert_eq!(d.is_zero(), false);
+        assert!(!d.is_zero());
         let d = Duration::<u32, 1, 1_000>::from_ticks(0);
-        assert_eq!(d.is_zero(), true);
+        assert!(d.is_zero());
         let d = Duration::<u32, 1, 1_000>::from_ticks(1);
-        assert_eq!(d.is_zero(), false);
+        assert!(!d.is_zero());
     }
 
     ////////////////////////////////////////////////////////////////////////////////
@@ -1270,6 +1363,31 @@ mod test {
 
         let r = Rate::<u32, 1, 1>::micros(50);
         assert_eq!(r.raw(), 20_000);
+
+        let r: Rate<u32, 1_000, 1> = 2.GHz();
+        assert_eq!(r.raw(), 2_000_000);
+        assert_eq!(r.to_GHz(), 2);
+
+        let r: Rate<u32, 1, 1_000> = 1.mHz();
+        assert_eq!(r.raw(), 1);
+
+        let r: Rate<u32, 1, 1_000> = 1.Hz();
+        assert_eq!(r.to_mHz(), 1_000);
+
+        let r: Rate<u32, 1, 1> = 3_000.rpm();
+        assert_eq!(r.raw(), 50);
+        assert_eq!(r.to_rpm(), 3_000);
+
+        // Rounded down to whole hertz
+        let r: Rate<u32, 1, 1> = 90.rpm();
+        assert_eq!(r.raw(), 1);
+
+        let r: Rate<u32, 1, 1> = 115_200.bps();
+        assert_eq!(r.to_baud(), 115_200);
+
+        let r: Rate<u32, 1_000, 1> = 9_600.baud();
+        assert_eq!(r.raw(), 9);
+        assert_eq!(r.to_bps(), 9_000);
     }
 
     #[test]
@@ -1290,6 +1408,34 @@ mod test {
 
         let r = Rate::<u64, 1, 1>::micros(50);
         assert_eq!(r.raw(), 20_000);
+
+        let r: Rate<u64, 1_000, 1> = 2.GHz();
+        assert_eq!(r.raw(), 2_000_000);
+        assert_eq!(r.to_GHz(), 2);
+
+        let r: Rate<u64, 1, 1_000> = 1.mHz();
+        assert_eq!(r.raw(), 1);
+
+        let r: Rate<u64, 1, 1_000> = 1.Hz();
+        assert_eq!(r.to_mHz(), 1_000);
+
+        let r: Rate<u64, 1, 1> = 3_000.rpm();
+        assert_eq!(r.raw(), 50);
+        assert_eq!(r.to_rpm(), 3_000);
+
+        // Rounded down to whole hertz
+        let r: Rate<u64, 1, 1> = 90.rpm();
+        assert_eq!(r.raw(), 1);
+
+        let r: Rate<u64, 1, 1> = 115_200.bps();
+        assert_eq!(r.to_baud(), 115_200);
+
+        let r: Rate<u64, 1_000, 1> = 9_600.baud();
+        assert_eq!(r.raw(), 9);
+        assert_eq!(r.to_bps(), 9_000);
+
+        assert_eq!(format!("{}", Rate::<u64, 1, 1_000>::mHz(5)), "5 mHz");
+        assert_eq!(format!("{}", Rate::<u64, 1, 60>::rpm(5)), "5 rpm");
     }
 
     #[test]
@@ -1345,4 +1491,2237 @@ mod test {
             TimerRateU64::<1_000_000>::from_raw(1)
         );
     }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Backoff tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn backoff_exponential() {
+        use crate::{ExponentialBackoff, ExtU32};
+
+        let mut b = ExponentialBackoff::<u32, 1, 1_000>::new(100.millis(), 1.secs());
+        assert_eq!(b.next(), Some(100.millis()));
+        assert_eq!(b.next(), Some(200.millis()));
+        assert_eq!(b.next(), Some(400.millis()));
+        assert_eq!(b.next(), Some(800.millis()));
+        assert_eq!(b.next(), Some(1.secs()));
+        assert_eq!(b.next(), Some(1.secs()));
+
+        b.reset();
+        assert_eq!(b.next(), Some(100.millis()));
+
+        // Saturates instead of overflowing
+        let mut b = ExponentialBackoff::<u32, 1, 1_000>::new(
+            Duration::<u32, 1, 1_000>::from_ticks(u32::MAX / 2 + 1),
+            Duration::<u32, 1, 1_000>::from_ticks(u32::MAX),
+        )
+        .with_factor(10);
+        assert_eq!(b.nth(1).unwrap().ticks(), u32::MAX);
+
+        // Base larger than max is clamped
+        let mut b = ExponentialBackoff::<u64, 1, 1_000>::new(
+            Duration::<u64, 1, 1_000>::from_ticks(10),
+            Duration::<u64, 1, 1_000>::from_ticks(5),
+        );
+        assert_eq!(b.next().unwrap().ticks(), 5);
+
+        // Zero factor is clamped to 1
+        let b = ExponentialBackoff::<u32, 1, 1_000>::new(100.millis(), 1.secs()).with_factor(0);
+        assert!(b.take(5).all(|d| d.ticks() == 100));
+    }
+
+    #[test]
+    fn backoff_linear() {
+        use crate::{ExtU64, LinearBackoff};
+
+        let b = LinearBackoff::<u64, 1, 1_000>::new(1.secs(), 500.millis(), 2.secs());
+        let ticks: Vec<u64> = b.take(5).map(|d| d.ticks()).collect();
+        assert_eq!(ticks, [1_000, 1_500, 2_000, 2_000, 2_000]);
+
+        let mut b = LinearBackoff::<u32, 1, 1>::new(
+            Duration::<u32, 1, 1>::from_ticks(1),
+            Duration::<u32, 1, 1>::from_ticks(u32::MAX),
+            Duration::<u32, 1, 1>::from_ticks(u32::MAX),
+        );
+        assert_eq!(b.nth(2).unwrap().ticks(), u32::MAX);
+    }
+
+    #[test]
+    fn backoff_jittered() {
+        use crate::JitteredBackoff;
+
+        // Delays are drawn from `[base, previous * 3]` and capped
+        let b = JitteredBackoff::<u32, 1, 1_000, _>::new(
+            Duration::<u32, 1, 1_000>::from_ticks(10),
+            Duration::<u32, 1, 1_000>::from_ticks(40),
+            || 1_000,
+        );
+        let ticks: Vec<u32> = b.take(4).map(|d| d.ticks()).collect();
+        assert_eq!(ticks, [23, 40, 11, 26]);
+
+        // Zero random value always picks `base`
+        let b = JitteredBackoff::<u64, 1, 1_000, _>::new(
+            Duration::<u64, 1, 1_000>::from_ticks(10),
+            Duration::<u64, 1, 1_000>::from_ticks(500),
+            || 0,
+        );
+        assert!(b.take(10).all(|d| d.ticks() == 10));
+
+        // Saturating range does not overflow
+        let b = JitteredBackoff::<u64, 1, 1_000, _>::new(
+            Duration::<u64, 1, 1_000>::from_ticks(u64::MAX / 2),
+            Duration::<u64, 1, 1_000>::from_ticks(u64::MAX),
+            || u64::MAX,
+        );
+        assert!(b.take(50).all(|d| d.ticks() >= u64::MAX / 2));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Rate limiter tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn token_bucket_exact_refill() {
+        use crate::{RateExtU32, TokenBucket};
+
+        // 3 tokens per second polled every millisecond, no tokens may be lost to rounding
+        let mut now = Instant::<u32, 1, 1_000>::from_ticks(0);
+        let mut bucket = TokenBucket::<u32, 1, 1_000>::new(3.Hz::<1, 1>(), 10, now);
+        assert!(bucket.try_acquire(now, 10));
+
+        let mut acquired = 0;
+        for _ in 0..3_000 {
+            now += Duration::<u32, 1, 1_000>::from_ticks(1);
+            if bucket.try_acquire(now, 1) {
+                acquired += 1;
+            }
+        }
+        assert_eq!(acquired, 9);
+
+        // Burst capacity caps the refill
+        let mut bucket = TokenBucket::<u32, 1, 1_000>::new(3.kHz::<1, 1>(), 5, now);
+        assert!(bucket.try_acquire(now, 5));
+        assert_eq!(
+            bucket.available(now + Duration::<u32, 1, 1_000>::from_ticks(1_000)),
+            5
+        );
+    }
+
+    #[test]
+    fn token_bucket_time_until_available() {
+        use crate::TokenBucket;
+
+        // Rate in a different base than the instants
+        let now = Instant::<u64, 1, 1_000_000>::from_ticks(u64::MAX - 10);
+        let mut bucket =
+            TokenBucket::<u64, 1, 1_000_000>::new(Rate::<u64, 1, 1_000>::from_raw(1_500), 1, now);
+
+        assert_eq!(bucket.time_until_available(now).ticks(), 0);
+        assert!(bucket.try_acquire(now, 1));
+        assert_eq!(bucket.time_until_available(now).ticks(), 666_667);
+
+        // Across tick wrap-around
+        let later = now + Duration::<u64, 1, 1_000_000>::from_ticks(666_666);
+        assert_eq!(bucket.time_until_available(later).ticks(), 1);
+        assert!(!bucket.try_acquire(later, 1));
+        assert!(bucket.try_acquire(later + Duration::<u64, 1, 1_000_000>::from_ticks(1), 1));
+
+        // Never refills
+        let mut bucket =
+            TokenBucket::<u64, 1, 1_000_000>::new(Rate::<u64, 1, 1>::from_raw(0), 1, now);
+        assert!(bucket.try_acquire(now, 1));
+        assert_eq!(bucket.time_until_available(now).ticks(), u64::MAX);
+    }
+
+    #[test]
+    fn leaky_bucket() {
+        use crate::LeakyBucket;
+
+        let now = Instant::<u32, 1, 32_768>::from_ticks(0);
+        let mut bucket =
+            LeakyBucket::<u32, 1, 32_768>::new(Rate::<u32, 1, 1>::from_raw(10), 3, now);
+
+        assert!(bucket.try_add(now, 3));
+        assert!(!bucket.try_add(now, 1));
+        assert_eq!(bucket.level(now), 3);
+        assert_eq!(bucket.time_until_available(now).ticks(), 3_277);
+
+        let later = now + Duration::<u32, 1, 32_768>::from_ticks(3_277);
+        assert_eq!(bucket.level(later), 2);
+        assert!(bucket.try_add(later, 1));
+
+        // Fully drained
+        let later = later + Duration::<u32, 1, 32_768>::from_ticks(32_768);
+        assert_eq!(bucket.level(later), 0);
+        assert_eq!(bucket.time_until_available(later).ticks(), 0);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Timer queue tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn timer_queue_ordering() {
+        use crate::TimerQueue;
+
+        let mut queue = TimerQueue::<u32, 1, 1_000, u32, 8>::new();
+        for (i, t) in [50, 10, 40, 20, 30, 60, 0, 70].iter().enumerate() {
+            queue
+                .insert(Instant::<u32, 1, 1_000>::from_ticks(*t), i as u32)
+                .unwrap();
+        }
+        assert!(queue.is_full());
+        assert_eq!(
+            queue.insert(Instant::<u32, 1, 1_000>::from_ticks(0), 99),
+            Err(99)
+        );
+
+        let now = Instant::<u32, 1, 1_000>::from_ticks(45);
+        let mut expired = Vec::new();
+        while let Some((deadline, payload)) = queue.pop_expired(now) {
+            expired.push((deadline.ticks(), payload));
+        }
+        assert_eq!(expired, [(0, 6), (10, 1), (20, 3), (30, 4), (40, 2)]);
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.next_deadline().unwrap().ticks(), 50);
+    }
+
+    #[test]
+    fn timer_queue_cancel() {
+        use crate::TimerQueue;
+
+        let mut queue = TimerQueue::<u64, 1, 1_000_000, char, 4>::new();
+        let a = queue
+            .insert(Instant::<u64, 1, 1_000_000>::from_ticks(10), 'a')
+            .unwrap();
+        let b = queue
+            .insert(Instant::<u64, 1, 1_000_000>::from_ticks(20), 'b')
+            .unwrap();
+        let c = queue
+            .insert(Instant::<u64, 1, 1_000_000>::from_ticks(30), 'c')
+            .unwrap();
+
+        assert_eq!(queue.cancel(a), Some('a'));
+        assert_eq!(queue.cancel(a), None);
+        assert_eq!(queue.next_deadline().unwrap().ticks(), 20);
+        assert_eq!(queue.cancel(c), Some('c'));
+        assert_eq!(queue.cancel(b), Some('b'));
+        assert!(queue.is_empty());
+        assert_eq!(queue.next_deadline(), None);
+    }
+
+    #[test]
+    fn timer_queue_wrap_around() {
+        use crate::TimerQueue;
+
+        let mut queue = TimerQueue::<u32, 1, 1_000, u8, 4>::new();
+        queue
+            .insert(Instant::<u32, 1, 1_000>::from_ticks(5), 2)
+            .unwrap();
+        queue
+            .insert(Instant::<u32, 1, 1_000>::from_ticks(u32::MAX - 5), 1)
+            .unwrap();
+
+        // The deadline before the wrap expires first
+        assert_eq!(queue.next_deadline().unwrap().ticks(), u32::MAX - 5);
+
+        let now = Instant::<u32, 1, 1_000>::from_ticks(u32::MAX);
+        assert_eq!(queue.pop_expired(now).map(|(_, p)| p), Some(1));
+        assert_eq!(queue.pop_expired(now), None);
+
+        let now = Instant::<u32, 1, 1_000>::from_ticks(5);
+        assert_eq!(queue.pop_expired(now).map(|(_, p)| p), Some(2));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Timer configuration tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn timer_config_period() {
+        use crate::{
+            MicrosDurationU32, MillisDurationU32, PrescalerEncoding, TimerConfig, TimerConstraints,
+        };
+
+        const TIM16: TimerConstraints = TimerConstraints {
+            min_divider: 1,
+            max_divider: 65_536,
+            counter_bits: 16,
+            encoding: PrescalerEncoding::MinusOne,
+        };
+
+        // Exact, with the smallest prescaler possible
+        const CFG: TimerConfig =
+            TimerConfig::from_period(HertzU32::MHz(84), MicrosDurationU32::micros(1_000), TIM16);
+        assert_eq!(CFG.divider(), 2);
+        assert_eq!(CFG.prescaler(), 1);
+        assert_eq!(CFG.counts(), 42_000);
+        assert_eq!(CFG.reload(), 41_999);
+        assert_eq!(CFG.error_ppm(), 0);
+
+        // Not exactly reachable, picks the smallest error
+        const TIM8: TimerConstraints = TimerConstraints {
+            min_divider: 1,
+            max_divider: 16,
+            counter_bits: 8,
+            encoding: PrescalerEncoding::Direct,
+        };
+        let cfg = TimerConfig::try_from_period(
+            HertzU32::Hz(1_000_003),
+            MillisDurationU32::millis(1),
+            TIM8,
+        )
+        .unwrap();
+        assert_eq!(cfg.divider(), 4);
+        assert_eq!(cfg.prescaler(), 4);
+        assert_eq!(cfg.counts(), 250);
+        assert_eq!(cfg.error_ppm(), -2);
+
+        // Too long or too short
+        assert_eq!(
+            TimerConfig::try_from_period(HertzU32::MHz(1), MillisDurationU32::millis(100), TIM8),
+            None
+        );
+        assert_eq!(
+            TimerConfig::try_from_period(HertzU32::MHz(1), MillisDurationU32::millis(0), TIM8),
+            None
+        );
+
+        // The exact pair is found even far above the smallest divider reaching the period
+        const SLOW: TimerConfig =
+            TimerConfig::from_period(HertzU32::MHz(72), MillisDurationU32::millis(500), TIM16);
+        assert_eq!(SLOW.divider() as u64 * SLOW.counts(), 36_000_000);
+        assert_eq!(SLOW.divider(), 576);
+
+        // Worst case search at compile time with a 16-bit prescaler and no exact pair
+        const PRIME: TimerConfig =
+            TimerConfig::from_rate(HertzU32::Hz(131_071), HertzU32::Hz(1), TIM16);
+        assert_eq!(PRIME.divider(), 2);
+        assert_eq!(PRIME.counts(), 65_536);
+
+        // Worst case search at compile time with a 32-bit counter
+        const CFG32: TimerConfig = TimerConfig::from_period(
+            HertzU32::Hz(72_000_001),
+            MicrosDurationU32::micros(1_000),
+            TimerConstraints {
+                min_divider: 1,
+                max_divider: 65_536,
+                counter_bits: 32,
+                encoding: PrescalerEncoding::Direct,
+            },
+        );
+        assert_eq!(CFG32.divider(), 1);
+        assert_eq!(CFG32.counts(), 72_000);
+        assert_eq!(CFG32.error_ppm(), 0);
+    }
+
+    #[test]
+    fn timer_config_rate() {
+        use crate::{PrescalerEncoding, TimerConfig, TimerConstraints};
+
+        let rp2040 = TimerConstraints {
+            min_divider: 1,
+            max_divider: 255,
+            counter_bits: 16,
+            encoding: PrescalerEncoding::Direct,
+        };
+
+        let cfg = TimerConfig::try_from_rate(HertzU32::MHz(125), HertzU32::kHz(1), rp2040).unwrap();
+        assert_eq!(cfg.divider(), 2);
+        assert_eq!(cfg.counts(), 62_500);
+
+        // Rates above the clock saturate at one count per period, with the error reported
+        let cfg =
+            TimerConfig::try_from_rate(HertzU32::MHz(125), HertzU32::MHz(200), rp2040).unwrap();
+        assert_eq!(cfg.counts(), 1);
+        assert_eq!(cfg.error_ppm(), 600_000);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Baud rate tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn baud_config() {
+        use crate::{BaudConfig, BaudConstraints};
+
+        // STM32 with 16x oversampling, BRR = USARTDIV in 12.4 fixed point
+        const OVER16: BaudConstraints = BaudConstraints {
+            oversampling: 16,
+            fraction_bits: 4,
+            min_divisor: 1,
+            max_divisor: 4_095,
+        };
+        const CFG: BaudConfig =
+            BaudConfig::new(HertzU32::MHz(72), HertzU32::Hz(115_200), OVER16, 1_000);
+        assert_eq!(CFG.divisor(), 625);
+        assert_eq!(CFG.integer(), 39);
+        assert_eq!(CFG.fraction(), 1);
+        assert_eq!(CFG.baud(), HertzU32::Hz(115_200));
+        assert_eq!(CFG.error_ppm(), 0);
+
+        // STM32 with 8x oversampling, in kHz base
+        let over8 = BaudConstraints {
+            oversampling: 8,
+            fraction_bits: 3,
+            min_divisor: 1,
+            max_divisor: 4_095,
+        };
+        let cfg = BaudConfig::try_new(
+            KilohertzU32::from_raw(48_000),
+            Rate::<u32, 1, 1>::from_raw(3_000_000),
+            over8,
+            0,
+        )
+        .unwrap();
+        assert_eq!(cfg.integer(), 2);
+        assert_eq!(cfg.fraction(), 0);
+
+        // The OVER8 `BRR` stores the 3 fraction bits in a 4 bit field
+        let cfg =
+            BaudConfig::try_new(HertzU32::MHz(72), HertzU32::Hz(115_200), over8, 1_000).unwrap();
+        assert_eq!(cfg.integer(), 78);
+        assert_eq!(cfg.fraction(), 1);
+        assert_eq!(cfg.integer() << 4 | cfg.fraction(), 0x4e1);
+
+        // Divisor out of range
+        assert_eq!(
+            BaudConfig::try_new(HertzU32::MHz(72), HertzU32::Hz(300), OVER16, 1_000),
+            None
+        );
+        assert_eq!(
+            BaudConfig::try_new(HertzU32::MHz(1), HertzU32::MHz(1), OVER16, 1_000_000),
+            None
+        );
+        assert_eq!(
+            BaudConfig::try_new(HertzU32::MHz(1), HertzU32::Hz(0), OVER16, 1_000_000),
+            None
+        );
+
+        // The fixed point divisor or the achieved baud rate does not fit in 32 bits
+        let wide = BaudConstraints {
+            oversampling: 1,
+            fraction_bits: 16,
+            min_divisor: 1,
+            max_divisor: u32::MAX,
+        };
+        assert_eq!(
+            BaudConfig::try_new(HertzU32::MHz(4_000), HertzU32::Hz(1), wide, 1_000_000),
+            None
+        );
+        let integer = BaudConstraints {
+            fraction_bits: 0,
+            ..wide
+        };
+        assert_eq!(
+            BaudConfig::try_new(
+                MegahertzU32::from_raw(10_000),
+                MegahertzU32::from_raw(5_000),
+                integer,
+                0
+            ),
+            None
+        );
+        assert!(BaudConfig::try_new(HertzU32::MHz(4_000), HertzU32::Hz(1), integer, 0).is_some());
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // PWM tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn pwm_timing() {
+        use crate::{
+            MicrosDurationU32, MillisDurationU32, NanosDurationU32, PwmAlignment, PwmTiming,
+            Rounding, SecsDurationU32,
+        };
+
+        // 1 kHz from 1 MHz, edge and center aligned
+        let edge = PwmTiming::from_rate(HertzU32::MHz(1), HertzU32::kHz(1), PwmAlignment::Edge);
+        let center = PwmTiming::from_period(
+            HertzU32::MHz(1),
+            MillisDurationU32::millis(1),
+            PwmAlignment::Center,
+        );
+        assert_eq!(edge.counts(), 1_000);
+        assert_eq!(edge.reload(), 999);
+        assert_eq!(center.counts(), 500);
+        assert_eq!(center.reload(), 500);
+        assert_eq!(edge.resolution_bits(), 9);
+        assert_eq!(center.resolution_bits(), 8);
+
+        assert_eq!(
+            edge.period(Rounding::Nearest),
+            Some(MicrosDurationU32::micros(1_000))
+        );
+        assert_eq!(
+            center.period(Rounding::Nearest),
+            Some(MicrosDurationU32::micros(1_000))
+        );
+        assert_eq!(
+            edge.resolution(Rounding::Nearest),
+            Some(MicrosDurationU32::micros(1))
+        );
+        assert_eq!(
+            center.resolution(Rounding::Nearest),
+            Some(MicrosDurationU32::micros(2))
+        );
+        assert_eq!(center.frequency(Rounding::Nearest), Some(HertzU32::kHz(1)));
+
+        // The same pulse needs half the counts in center aligned mode
+        let pulse = MicrosDurationU32::micros(250);
+        assert_eq!(edge.pulse_to_counts(pulse, Rounding::Nearest), Some(250));
+        assert_eq!(center.pulse_to_counts(pulse, Rounding::Nearest), Some(125));
+        assert_eq!(center.counts_to_pulse(125, Rounding::Nearest), Some(pulse));
+        assert_eq!(
+            center.pulse_to_counts(MicrosDurationU32::micros(3), Rounding::Down),
+            Some(1)
+        );
+        assert_eq!(
+            center.pulse_to_counts(MicrosDurationU32::micros(3), Rounding::Up),
+            Some(2)
+        );
+        assert_eq!(
+            center.pulse_to_counts(MicrosDurationU32::micros(1_003), Rounding::Down),
+            None
+        );
+        assert_eq!(
+            center.counts_to_pulse::<1, 1_000_000>(501, Rounding::Down),
+            None
+        );
+
+        // Dead-time does not depend on the alignment
+        let dead_time = NanosDurationU32::nanos(1_500);
+        assert_eq!(edge.dead_time_to_ticks(dead_time, Rounding::Down), Some(1));
+        assert_eq!(center.dead_time_to_ticks(dead_time, Rounding::Up), Some(2));
+        assert_eq!(
+            center.ticks_to_dead_time(2, Rounding::Nearest),
+            Some(NanosDurationU32::nanos(2_000))
+        );
+
+        // Round trip of duty cycles
+        for duty in 0..=100 {
+            let counts = edge.duty_to_counts(duty, 100, Rounding::Nearest).unwrap();
+            assert_eq!(
+                edge.counts_to_duty(counts, 100, Rounding::Nearest),
+                Some(duty)
+            );
+        }
+        assert_eq!(edge.duty_to_counts(1, 0, Rounding::Nearest), None);
+        assert_eq!(edge.counts_to_duty(1, 0, Rounding::Nearest), None);
+
+        // Out of range
+        assert_eq!(
+            PwmTiming::try_from_rate(HertzU32::MHz(1), HertzU32::MHz(3), PwmAlignment::Edge),
+            None
+        );
+        assert_eq!(
+            PwmTiming::try_from_rate(HertzU32::MHz(1), HertzU32::Hz(0), PwmAlignment::Edge),
+            None
+        );
+        assert!(
+            PwmTiming::try_from_rate(HertzU32::MHz(1), HertzU32::MHz(2), PwmAlignment::Edge)
+                .is_some()
+        );
+        assert_eq!(
+            PwmTiming::try_from_period(
+                HertzU32::MHz(1),
+                SecsDurationU32::secs(0),
+                PwmAlignment::Edge
+            ),
+            None
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // PLL tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn pll_config() {
+        use crate::{PllConfig, PllLimits};
+
+        // RP2040 style PLL with a single combined post divider
+        const LIMITS: PllLimits = PllLimits {
+            m_min: 1,
+            m_max: 63,
+            n_min: 16,
+            n_max: 320,
+            p_min: 1,
+            p_max: 49,
+            p_step: 1,
+            ref_min: HertzU32::MHz(5),
+            ref_max: HertzU32::MHz(800),
+            vco_min: HertzU32::MHz(750),
+            vco_max: HertzU32::MHz(1_600),
+        };
+
+        const PLL: PllConfig<1_000, 1> =
+            PllConfig::new(HertzU32::MHz(12), KilohertzU32::kHz(125_000), LIMITS, 0);
+        assert_eq!((PLL.m(), PLL.n(), PLL.p()), (1, 125, 12));
+        assert_eq!(PLL.vco(), HertzU32::MHz(1_500));
+        assert_eq!(PLL.rate(), KilohertzU32::kHz(125_000));
+        assert_eq!(PLL.error_ppm(), 0);
+
+        // Compare against an exhaustive search for the smallest error
+        for target in [
+            48_000_000u32,
+            133_000_000,
+            44_100 * 512,
+            99_999_999,
+            27_000_000,
+        ] {
+            let pll =
+                PllConfig::try_new(HertzU32::MHz(12), HertzU32::Hz(target), LIMITS, 1_000_000)
+                    .unwrap();
+
+            // Smallest error as `(error, m * p)`, i.e. `error / (m * p)` Hz
+            let error =
+                |m: u64, n: u64, p: u64| ((12_000_000 * n).abs_diff(target as u64 * m * p), m * p);
+            let mut best = (u64::MAX, 1);
+            for m in 1..=63 {
+                for p in 1..=49 {
+                    for n in 16..=320 {
+                        if 12_000_000 >= 5_000_000 * m
+                            && 12_000_000 * n >= 750_000_000 * m
+                            && 12_000_000 * n <= 1_600_000_000 * m
+                        {
+                            let e = error(m, n, p);
+                            if (e.0 as u128) * (best.1 as u128) < (best.0 as u128) * (e.1 as u128) {
+                                best = e;
+                            }
+                        }
+                    }
+                }
+            }
+
+            let e = error(pll.m() as u64, pll.n() as u64, pll.p() as u64);
+            assert_eq!(e.0 * best.1, best.0 * e.1, "target {}", target);
+        }
+
+        // Out of range VCO, reference and error
+        assert_eq!(
+            PllConfig::try_new(HertzU32::MHz(12), HertzU32::MHz(1_700), LIMITS, 10_000),
+            None
+        );
+        assert_eq!(
+            PllConfig::try_new(HertzU32::MHz(4), HertzU32::MHz(100), LIMITS, 10_000),
+            None
+        );
+        assert_eq!(
+            PllConfig::try_new(HertzU32::MHz(12), HertzU32::Hz(0), LIMITS, 10_000),
+            None
+        );
+        assert_eq!(
+            PllConfig::try_new(HertzU32::MHz(12), HertzU32::Hz(99_999_000), LIMITS, 0),
+            None
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Frequency counter tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn frequency_counter() {
+        use crate::FrequencyCounter;
+
+        let mut counter = FrequencyCounter::<u32, 1, 1_000_000, 5>::new();
+        assert!(counter.is_empty());
+        assert_eq!(counter.rate::<1, 1>(), None);
+        assert_eq!(counter.mean_period(), None);
+        assert_eq!(counter.jitter(), None);
+
+        // 1234.5 Hz tachometer on a 1 MHz timer which wraps around during the measurement, the
+        // periods alternate between 810 and 811 ticks
+        let mut now = Instant::<u32, 1, 1_000_000>::from_ticks(u32::MAX - 1_000);
+        counter.push(now);
+        assert_eq!(counter.rate::<1, 1>(), None);
+
+        for period in [810, 811, 810, 811, 810, 811] {
+            now += Duration::<u32, 1, 1_000_000>::from_ticks(period);
+            counter.push(now);
+        }
+
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.periods(), 4);
+        assert_eq!(counter.rate::<1, 1>(), Some(HertzU32::Hz(1_234)));
+        assert_eq!(
+            counter.rate::<1, 1_000>(),
+            Some(Rate::<u32, 1, 1_000>::from_raw(1_233_806))
+        );
+        assert_eq!(counter.mean_period().map(|d| d.ticks()), Some(811));
+        assert_eq!(counter.jitter().map(|d| d.ticks()), Some(1));
+        assert_eq!(counter.rms_jitter().map(|d| d.ticks()), Some(0));
+
+        counter.clear();
+        assert!(counter.is_empty());
+
+        // Larger RMS jitter, in u64
+        let mut counter = FrequencyCounter::<u64, 1, 1_000, 8>::default();
+        let mut now = Instant::<u64, 1, 1_000>::from_ticks(0);
+        counter.push(now);
+        for period in [90, 110, 90, 110] {
+            now += Duration::<u64, 1, 1_000>::from_ticks(period);
+            counter.push(now);
+        }
+
+        assert_eq!(
+            counter.rate::<1, 1>(),
+            Some(Rate::<u64, 1, 1>::from_raw(10))
+        );
+        assert_eq!(counter.mean_period().map(|d| d.ticks()), Some(100));
+        assert_eq!(counter.jitter().map(|d| d.ticks()), Some(20));
+        assert_eq!(counter.rms_jitter().map(|d| d.ticks()), Some(10));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Histogram tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn histogram_buckets() {
+        use crate::Histogram;
+
+        // The median of a value and a much larger one is the largest value of the first value's
+        // bucket, which is within the precision of the value
+        for v in (0..100_000u64).chain((20..64).map(|e| (1 << e) - 1)) {
+            let mut hist = Histogram::<u64, 1, 1_000, 3, 252>::new();
+            hist.record(Duration::<u64, 1, 1_000>::from_ticks(v));
+            hist.record(Duration::<u64, 1, 1_000>::from_ticks(u64::MAX));
+
+            let p50 = hist.percentile(1, 2).unwrap().ticks();
+            assert!(p50 >= v && p50 - v <= v / 4, "{} {}", v, p50);
+        }
+
+        // Values beyond the last bucket are counted in it
+        let mut hist = Histogram::<u32, 1, 1, 1, 4>::new();
+        for v in [0, 1, 2, 3, 1_000] {
+            hist.record(Duration::<u32, 1, 1>::from_ticks(v));
+        }
+        assert_eq!(hist.percentile(0, 1).map(|d| d.ticks()), Some(0));
+        assert_eq!(hist.percentile(2, 5).map(|d| d.ticks()), Some(1));
+        assert_eq!(hist.percentile(3, 5).map(|d| d.ticks()), Some(3));
+        assert_eq!(hist.percentile(4, 5).map(|d| d.ticks()), Some(3));
+        assert_eq!(hist.percentile(1, 1).map(|d| d.ticks()), Some(1_000));
+        assert_eq!(hist.percentile(2, 1), None);
+        assert_eq!(hist.percentile(1, 0), None);
+    }
+
+    #[test]
+    fn histogram_merge() {
+        use crate::{
+            Histogram, MicrosDurationU32, MillisDurationU32, NanosDurationU32, SecsDurationU32,
+        };
+
+        let mut a = Histogram::<u32, 1, 1_000_000, 4, 240>::default();
+        let mut b = Histogram::<u32, 1, 1_000_000, 4, 240>::default();
+        assert!(a.is_empty());
+        assert_eq!(a.min(), None);
+        assert_eq!(a.max(), None);
+        assert_eq!(a.mean(), None);
+        assert_eq!(a.percentile(1, 2), None);
+
+        // Samples of any base
+        a.record(MicrosDurationU32::micros(10));
+        a.record(NanosDurationU32::nanos(20_999));
+        b.record(MillisDurationU32::millis(1));
+        b.record(MicrosDurationU32::micros(5));
+        b.record(SecsDurationU32::secs(5_000));
+
+        a.merge(&b);
+        assert_eq!(a.count(), 5);
+        assert_eq!(a.min(), Some(MicrosDurationU32::micros(5)));
+        assert_eq!(a.max(), Some(MicrosDurationU32::micros(u32::MAX)));
+        assert_eq!(a.percentile(1, 5), Some(MicrosDurationU32::micros(5)));
+        assert_eq!(a.percentile(2, 5), Some(MicrosDurationU32::micros(10)));
+        assert_eq!(a.percentile(3, 5), Some(MicrosDurationU32::micros(21)));
+        assert_eq!(a.percentile(4, 5), Some(MicrosDurationU32::micros(1_023)));
+        assert_eq!(
+            a.mean(),
+            Some(MicrosDurationU32::micros(
+                ((u32::MAX as u64 + 1_035 + 2) / 5) as u32
+            ))
+        );
+
+        a.reset();
+        assert_eq!(a.count(), 0);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Statistics tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn duration_stats() {
+        use crate::DurationStats;
+
+        let mut stats = DurationStats::<u64, 1, 1_000_000_000>::default();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.std_dev(), None);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.jitter(), None);
+
+        // Large offset with a small spread, where a naive sum of squares would overflow, and a
+        // first sample above the mean
+        let base = u64::MAX - 1_000;
+        for offset in [500, 400, 600, 300, 700] {
+            stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(base + offset));
+        }
+
+        assert_eq!(stats.count(), 5);
+        assert_eq!(stats.mean().map(|d| d.ticks()), Some(base + 500));
+        // sqrt(20_000) = 141.4
+        assert_eq!(stats.std_dev().map(|d| d.ticks()), Some(141));
+        assert_eq!(stats.min().map(|d| d.ticks()), Some(base + 300));
+        assert_eq!(stats.max().map(|d| d.ticks()), Some(base + 700));
+        assert_eq!(stats.jitter().map(|d| d.ticks()), Some(400));
+
+        // Rounding of a mean below the first sample
+        stats.reset();
+        for ticks in [10, 9, 9, 9] {
+            stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(ticks));
+        }
+        assert_eq!(stats.mean().map(|d| d.ticks()), Some(9));
+        assert_eq!(stats.std_dev().map(|d| d.ticks()), Some(0));
+
+        let mut stats = DurationStats::<u32, 1, 1_000>::new();
+        stats.push(Duration::<u32, 1, 1_000>::from_ticks(0));
+        stats.push(Duration::<u32, 1, 1_000>::from_ticks(u32::MAX));
+        assert_eq!(stats.mean().map(|d| d.ticks()), Some(u32::MAX / 2 + 1));
+        assert_eq!(stats.std_dev().map(|d| d.ticks()), Some(u32::MAX / 2));
+
+        // Deviations wider than 32 bits
+        let mut stats = DurationStats::<u64, 1, 1_000>::new();
+        stats.push(Duration::<u64, 1, 1_000>::from_ticks(1 << 40));
+        stats.push(Duration::<u64, 1, 1_000>::from_ticks(0));
+        assert_eq!(stats.mean().map(|d| d.ticks()), Some(1 << 39));
+        assert_eq!(stats.std_dev().map(|d| d.ticks()), Some(1 << 39));
+
+        // The square of the summed deviations overflows while their sum of squares does not
+        let mut stats = DurationStats::<u64, 1, 1_000_000_000>::new();
+        stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(0));
+        for _ in 0..4_096 {
+            stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(1 << 56));
+        }
+        assert_eq!(stats.std_dev(), None);
+        assert_eq!(
+            stats.mean().map(|d| d.ticks()),
+            Some(72_040_006_145_802_496)
+        );
+
+        // The sum of squares overflows
+        let mut stats = DurationStats::<u64, 1, 1_000_000_000>::new();
+        stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(0));
+        for _ in 0..2 {
+            stats.push(Duration::<u64, 1, 1_000_000_000>::from_ticks(u64::MAX));
+        }
+        assert_eq!(stats.std_dev(), None);
+        assert_eq!(stats.mean().map(|d| d.ticks()), Some(u64::MAX / 3 * 2));
+    }
+
+    #[test]
+    fn rate_stats() {
+        use crate::RateStats;
+
+        let mut stats = RateStats::<u64, 1, 1_000>::new();
+        for raw in [8_000_000, 8_000_040, 7_999_960, 8_000_000] {
+            stats.push(Rate::<u64, 1, 1_000>::from_raw(raw));
+        }
+
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.mean().map(|r| r.raw()), Some(8_000_000));
+        // sqrt(800) = 28.3
+        assert_eq!(stats.std_dev().map(|r| r.raw()), Some(28));
+        assert_eq!(stats.min().map(|r| r.raw()), Some(7_999_960));
+        assert_eq!(stats.max().map(|r| r.raw()), Some(8_000_040));
+        assert_eq!(stats.jitter().map(|r| r.raw()), Some(80));
+
+        stats.reset();
+        assert_eq!(stats.mean(), None);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Stopwatch tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn stopwatch() {
+        use crate::Stopwatch;
+        use core::cell::Cell;
+
+        // Start just before the timer wraps around
+        let ticks = Cell::new(u32::MAX - 5);
+        let mut sw = Stopwatch::<u32, 1, 1_000, _>::new(|| {
+            Instant::<u32, 1, 1_000>::from_ticks(ticks.get())
+        });
+
+        assert!(!sw.is_running());
+        assert_eq!(sw.split().ticks(), 0);
+
+        sw.start();
+        assert!(sw.is_running());
+        ticks.set(4);
+        assert_eq!(sw.lap().ticks(), 10);
+        ticks.set(14);
+
+        // Starting again does not restart
+        sw.start();
+        assert_eq!(sw.split().ticks(), 20);
+        assert_eq!(sw.stop().ticks(), 20);
+        assert!(!sw.is_running());
+
+        ticks.set(1_000);
+        assert_eq!(sw.split().ticks(), 20);
+        assert_eq!(sw.lap().ticks(), 10);
+
+        // A clock going backwards counts as no time passing
+        sw.start();
+        ticks.set(990);
+        assert_eq!(sw.split().ticks(), 20);
+
+        sw.reset();
+        assert!(!sw.is_running());
+        assert_eq!(sw.split().ticks(), 0);
+        assert_eq!(sw.lap().ticks(), 0);
+    }
+
+    #[test]
+    fn scope_timer() {
+        use crate::ScopeTimer;
+        use core::cell::Cell;
+
+        let ticks = Cell::new(u64::MAX);
+        let clock = || Instant::<u64, 1, 1_000_000>::from_ticks(ticks.get());
+        let mut reported = None;
+
+        {
+            let mut t = ScopeTimer::<u64, 1, 1_000_000, _, _>::new(clock, |d| reported = Some(d));
+            ticks.set(ticks.get().wrapping_add(7));
+            assert_eq!(t.elapsed().ticks(), 7);
+            ticks.set(ticks.get().wrapping_add(3));
+        }
+
+        assert_eq!(reported.map(|d| d.ticks()), Some(10));
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Clock mapping tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn clock_mapping_nominal() {
+        use crate::ClockMapping;
+
+        let mut map = ClockMapping::<u32, 1, 32_768, u64, 1, 1_000_000, 4>::new();
+        let local = Instant::<u32, 1, 32_768>::from_ticks(65_536);
+        let remote = Instant::<u64, 1, 1_000_000>::from_ticks(3_000_100);
+
+        assert!(map.is_empty());
+        assert_eq!(map.translate(local), None);
+        assert_eq!(map.translate_back(remote), None);
+        assert_eq!(map.skew_ppb(), None);
+
+        // A single pair gives the offset at the nominal rates
+        map.push(local, remote);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.skew_ppb(), Some(0));
+        assert_eq!(map.offset_nanos(), Some(1_000_100_000));
+        assert_eq!(
+            map.translate(Instant::<u32, 1, 32_768>::from_ticks(65_536 + 16_384)),
+            Some(Instant::<u64, 1, 1_000_000>::from_ticks(3_500_100))
+        );
+        assert_eq!(
+            map.translate(Instant::<u32, 1, 32_768>::from_ticks(65_536 - 1)),
+            Some(Instant::<u64, 1, 1_000_000>::from_ticks(3_000_069))
+        );
+        assert_eq!(
+            map.translate_back(Instant::<u64, 1, 1_000_000>::from_ticks(2_500_100)),
+            Some(Instant::<u32, 1, 32_768>::from_ticks(65_536 - 16_384))
+        );
+
+        map.clear();
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.translate(local), None);
+    }
+
+    #[test]
+    fn clock_mapping_fit() {
+        use crate::ClockMapping;
+
+        // Local 1 MHz clock wrapping during the measurement, remote 1 MHz clock which runs
+        // 100 ppm slow, with +-1 us of noise on the remote timestamps
+        let mut map = ClockMapping::<u32, 1, 1_000_000, u32, 1, 1_000_000, 4>::default();
+        let start = u32::MAX - 1_500_000;
+
+        for (k, noise) in [(0, 1), (1, -1), (2, -1), (3, 1), (4, 0)] {
+            map.push(
+                Instant::<u32, 1, 1_000_000>::from_ticks(start.wrapping_add(k * 1_000_000)),
+                Instant::<u32, 1, 1_000_000>::from_ticks(
+                    (500_000 + k as i32 * 999_900 + noise) as u32,
+                ),
+            );
+        }
+
+        // Only the last 4 pairs are used, the fitted slope is 0.9999005
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.skew_ppb(), Some(-99_500));
+
+        // The fit is not pulled towards the noise of the reference pair
+        let local = Instant::<u32, 1, 1_000_000>::from_ticks(start.wrapping_add(10_000_000));
+        let remote = map.translate(local).unwrap();
+        assert_eq!(remote.ticks(), 500_000 + 9_999_004);
+        assert_eq!(
+            map.translate_back(remote).map(|l| l.ticks()),
+            Some(start.wrapping_add(10_000_001))
+        );
+
+        // Out of range of the remote domain
+        let mut map = ClockMapping::<u64, 1, 1, u32, 1, 1_000_000_000, 2>::new();
+        map.push(
+            Instant::<u64, 1, 1>::from_ticks(0),
+            Instant::<u32, 1, 1_000_000_000>::from_ticks(0),
+        );
+        assert_eq!(map.translate(Instant::<u64, 1, 1>::from_ticks(3)), None);
+        assert_eq!(
+            map.translate(Instant::<u64, 1, 1>::from_ticks(2)),
+            Some(Instant::<u32, 1, 1_000_000_000>::from_ticks(2_000_000_000))
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Timestamp format tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn ntp_timestamp() {
+        use crate::{NanosDurationU64, NtpShort, NtpTimestamp, SecsDurationU64};
+
+        // Nanoseconds round trip losslessly as the fraction is finer
+        for ns in (0..2_000_000_000u64)
+            .step_by(999_983)
+            .chain([999_999_999, u32::MAX as u64 * 1_000_000_000 + 999_999_999])
+        {
+            let d = NanosDurationU64::nanos(ns);
+            let ntp = NtpTimestamp::from_duration(d);
+            assert_eq!(ntp.to_duration(0), Some(d));
+            assert_eq!(NtpTimestamp::from_bytes(ntp.to_bytes()), ntp);
+            assert_eq!(NtpTimestamp::from_bits(ntp.to_bits()), ntp);
+        }
+
+        // Rounding to the nearest fraction carries into the seconds
+        assert_eq!(
+            NtpShort::from_duration(NanosDurationU64::nanos(1_999_999_999)),
+            Some(NtpShort::from_bits(2 << 16))
+        );
+
+        // The fraction is rounded to the nearest tick on the way back
+        let ntp = NtpTimestamp::from_parts(0, (1 << 31) - 1);
+        assert_eq!(ntp.to_duration::<1, 1>(0), Some(SecsDurationU64::secs(0)));
+        let ntp = NtpTimestamp::from_parts(0, 1 << 31);
+        assert_eq!(ntp.to_duration::<1, 1>(0), Some(SecsDurationU64::secs(1)));
+
+        // Era handling around a pivot
+        let era_1 = 1u64 << 32;
+        let ntp = NtpTimestamp::from_duration(SecsDurationU64::secs(era_1 + 5));
+        assert_eq!(ntp.seconds(), 5);
+        assert_eq!(ntp.to_duration(1), Some(SecsDurationU64::secs(era_1 + 5)));
+        assert_eq!(
+            ntp.to_duration_near(SecsDurationU64::secs(era_1 - 1_000)),
+            Some(SecsDurationU64::secs(era_1 + 5))
+        );
+        assert_eq!(
+            ntp.to_duration_near(SecsDurationU64::secs(era_1 + 1_000_000)),
+            Some(SecsDurationU64::secs(era_1 + 5))
+        );
+        let ntp = NtpTimestamp::from_parts(u32::MAX, 0);
+        assert_eq!(
+            ntp.to_duration_near(SecsDurationU64::secs(era_1 + 5)),
+            Some(SecsDurationU64::secs(era_1 - 1))
+        );
+        assert_eq!(ntp.to_duration_near(SecsDurationU64::secs(5)), None);
+        assert_eq!(
+            ntp.to_instant::<1, 1>(0),
+            Some(Instant::<u64, 1, 1>::from_ticks(u32::MAX as u64))
+        );
+        assert_eq!(
+            NtpTimestamp::from_instant(Instant::<u64, 1, 1>::from_ticks(u32::MAX as u64)),
+            ntp
+        );
+        assert_eq!(ntp.to_duration::<1, 1_000_000_000>(u32::MAX), None);
+
+        // Wrapping at the end of the era
+        assert_eq!(
+            ntp.wrapping_add_duration(SecsDurationU64::secs(2)),
+            NtpTimestamp::from_parts(1, 0)
+        );
+
+        // Short format
+        let short = NtpShort::from_bytes([0x00, 0x02, 0x40, 0x00]);
+        assert_eq!((short.seconds(), short.fraction()), (2, 0x4000));
+        assert_eq!(
+            short.to_duration::<1, 1_000_000_000>(),
+            NanosDurationU64::nanos(2_250_000_000)
+        );
+        assert_eq!(
+            NtpShort::from_duration(short.to_duration::<1, 1_000>()),
+            Some(short)
+        );
+        assert_eq!(short.to_bytes(), [0x00, 0x02, 0x40, 0x00]);
+        assert_eq!(NtpShort::from_duration(SecsDurationU64::secs(65_536)), None);
+        assert_eq!(
+            NtpShort::from_duration(NanosDurationU64::nanos(65_535_999_999_999)),
+            None
+        );
+        assert_eq!(
+            NtpShort::from_duration(NanosDurationU64::nanos(65_535_999_990_000))
+                .map(|s| s.to_bits()),
+            Some(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn ptp_timestamp() {
+        use crate::{MicrosDurationU64, PtpTimestamp};
+
+        let ptp = PtpTimestamp::from_parts(0x0102_0304_0506, 0x0708_090a).unwrap();
+        assert_eq!(
+            ptp.to_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a]
+        );
+        assert_eq!(PtpTimestamp::from_bytes(ptp.to_bytes()), Some(ptp));
+
+        // Invalid parts
+        assert_eq!(PtpTimestamp::from_parts(1 << 48, 0), None);
+        assert_eq!(PtpTimestamp::from_parts(0, 1_000_000_000), None);
+        assert_eq!(
+            PtpTimestamp::from_bytes([0, 0, 0, 0, 0, 0, 0x3b, 0x9a, 0xca, 0x00]),
+            None
+        );
+
+        // Conversions with rounding
+        let d = Duration::<u64, 1, 3>::from_ticks(4);
+        let ptp = PtpTimestamp::from_duration(d).unwrap();
+        assert_eq!((ptp.seconds(), ptp.nanoseconds()), (1, 333_333_333));
+        assert_eq!(ptp.to_duration(), Some(d));
+        assert_eq!(
+            ptp.to_duration(),
+            Some(MicrosDurationU64::micros(1_333_333))
+        );
+        assert_eq!(
+            PtpTimestamp::from_instant(Instant::<u64, 1, 1_000_000_000>::from_ticks(1_500_000_000)),
+            PtpTimestamp::from_parts(1, 500_000_000)
+        );
+        assert_eq!(
+            PtpTimestamp::from_parts(1, 500_000_000)
+                .unwrap()
+                .to_instant(),
+            Some(Instant::<u64, 1, 1_000_000_000>::from_ticks(1_500_000_000))
+        );
+
+        // Out of range
+        assert_eq!(
+            PtpTimestamp::from_duration(Duration::<u64, { 1 << 20 }, 1>::from_ticks(1 << 28)),
+            None
+        );
+        let max = PtpTimestamp::from_parts((1 << 48) - 1, 999_999_999).unwrap();
+        assert_eq!(max.to_duration::<1, 1_000_000_000>(), None);
+        assert_eq!(
+            max.to_duration::<1, 1_000>(),
+            Some(Duration::<u64, 1, 1_000>::from_ticks(
+                ((1 << 48) - 1) * 1_000 + 1_000
+            ))
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Calendar tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[cfg(feature = "calendar")]
+    #[test]
+    fn calendar_dates() {
+        use crate::DateTime;
+
+        // Walk day by day from 1600 to 2400, checking against a naive calendar
+        let (mut year, mut month, mut day) = (1600, 1, 1);
+        let mut secs = DateTime::new(1600, 1, 1, 0, 0, 0, 0).to_unix().0;
+        let mut weekday = DateTime::new(1600, 1, 1, 0, 0, 0, 0).weekday();
+        assert_eq!(weekday, 6); // Saturday
+
+        while year < 2400 {
+            let dt = DateTime::from_unix(secs + 45_296, 7).unwrap();
+            assert_eq!((dt.year(), dt.month(), dt.day()), (year, month, day));
+            assert_eq!((dt.hour(), dt.minute(), dt.second()), (12, 34, 56));
+            assert_eq!(dt.to_unix(), (secs + 45_296, 7));
+            assert_eq!(dt.weekday(), weekday);
+
+            let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+            let days = match month {
+                2 if leap => 29,
+                2 => 28,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            };
+            assert_eq!(DateTime::is_leap_year(year), leap);
+            if month == 12 && day == 31 {
+                assert_eq!(dt.day_of_year(), if leap { 366 } else { 365 });
+            }
+
+            day += 1;
+            if day > days {
+                day = 1;
+                month += 1;
+                if month > 12 {
+                    month = 1;
+                    year += 1;
+                }
+            }
+            secs += 86_400;
+            weekday = weekday % 7 + 1;
+        }
+
+        // Before the UNIX epoch
+        assert_eq!(
+            DateTime::from_unix(-1, 999_999_999),
+            DateTime::try_new(1969, 12, 31, 23, 59, 59, 999_999_999)
+        );
+        assert_eq!(DateTime::from_unix(0, 0), Some(DateTime::UNIX_EPOCH));
+
+        // Extremes
+        for dt in [
+            DateTime::new(i32::MIN, 1, 1, 0, 0, 0, 0),
+            DateTime::new(i32::MAX, 12, 31, 23, 59, 59, 999_999_999),
+        ] {
+            let (secs, nanos) = dt.to_unix();
+            assert_eq!(DateTime::from_unix(secs, nanos), Some(dt));
+        }
+        let (secs, _) = DateTime::new(i32::MAX, 12, 31, 23, 59, 59, 0).to_unix();
+        assert_eq!(DateTime::from_unix(secs + 1, 0), None);
+        assert_eq!(DateTime::from_unix(i64::MAX, 0), None);
+        assert_eq!(DateTime::from_unix(i64::MIN, 0), None);
+        assert_eq!(DateTime::from_unix(0, 1_000_000_000), None);
+
+        // Invalid fields
+        assert!(DateTime::try_new(2000, 2, 29, 0, 0, 0, 0).is_some());
+        assert_eq!(DateTime::try_new(1900, 2, 29, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 0, 1, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 13, 1, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 4, 31, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 1, 0, 0, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 1, 1, 24, 0, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 1, 1, 0, 60, 0, 0), None);
+        assert_eq!(DateTime::try_new(2024, 1, 1, 0, 0, 60, 0), None);
+
+        // Formatting
+        let dt = DateTime::new(987, 6, 5, 4, 3, 2, 0);
+        assert_eq!(format!("{}", dt), "0987-06-05T04:03:02Z");
+        let dt = DateTime::new(2024, 6, 5, 4, 3, 2, 1_000);
+        assert_eq!(format!("{}", dt), "2024-06-05T04:03:02.000001Z");
+        let dt = DateTime::new(2024, 6, 5, 4, 3, 2, 1);
+        assert_eq!(format!("{}", dt), "2024-06-05T04:03:02.000000001Z");
+    }
+
+    #[cfg(feature = "calendar")]
+    #[test]
+    fn calendar_wall_clock() {
+        use crate::{DateTime, WallClock};
+
+        let clock = WallClock::<1, 1_000>::UNIX_EPOCH;
+        assert_eq!(clock.epoch(), Some(DateTime::UNIX_EPOCH));
+        let now = Instant::<u64, 1, 1_000>::from_ticks(1_704_067_200_123);
+        assert_eq!(
+            clock.to_datetime(now),
+            Some(DateTime::new(2024, 1, 1, 0, 0, 0, 123_000_000))
+        );
+
+        // Rounding down in both directions
+        let mut clock = WallClock::<1, 3>::new(DateTime::new(2000, 1, 1, 0, 0, 0, 0));
+        let now = Instant::<u64, 1, 3>::from_ticks(1);
+        assert_eq!(
+            clock.to_datetime(now),
+            Some(DateTime::new(2000, 1, 1, 0, 0, 0, 333_333_333))
+        );
+        assert_eq!(
+            clock.instant_at(clock.to_datetime(now).unwrap()),
+            Some(
+                now.checked_sub_duration(Duration::<u64, 1, 3>::from_ticks(1))
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            clock.instant_at(DateTime::new(2000, 1, 1, 0, 0, 0, 333_333_334)),
+            Some(now)
+        );
+        assert_eq!(clock.instant_at_unix(0, 0), None);
+        assert_eq!(clock.instant_at_unix(i64::MAX, 0), None);
+        assert_eq!(clock.instant_at_unix(946_684_800, 1_000_000_000), None);
+
+        // Setting the time
+        let now = Instant::<u64, 1, 3>::from_ticks(300);
+        clock.sync(now, DateTime::new(2024, 1, 1, 0, 1, 40, 0));
+        assert_eq!(clock.epoch(), Some(DateTime::new(2024, 1, 1, 0, 0, 0, 0)));
+        assert_eq!(clock.to_unix(now), Some((1_704_067_300, 0)));
+
+        // An epoch far before any date
+        clock.sync(
+            Instant::<u64, 1, 3>::from_ticks(u64::MAX),
+            DateTime::new(-5_000, 1, 1, 0, 0, 0, 0),
+        );
+        assert_eq!(clock.epoch(), None);
+        assert_eq!(
+            clock.to_datetime(Instant::<u64, 1, 3>::from_ticks(u64::MAX)),
+            Some(DateTime::new(-5_000, 1, 1, 0, 0, 0, 0))
+        );
+        assert_eq!(clock.to_datetime(Instant::<u64, 1, 3>::from_ticks(0)), None);
+        let (secs, _) = DateTime::new(-5_000, 1, 1, 0, 0, 0, 0).to_unix();
+        assert_eq!(
+            clock.to_unix(Instant::<u64, 1, 3>::from_ticks(0)),
+            Some((secs - (u64::MAX / 3) as i64, 0))
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Timescale tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn timescale_leap_seconds() {
+        use crate::{Gps, LeapSeconds, Tai, Utc};
+
+        let leap = LeapSeconds::BUILTIN;
+        assert_eq!(leap.offset_at_utc(63_071_999), None);
+        assert_eq!(leap.offset_at_utc(63_072_000), Some(10));
+
+        // Step through each leap second in TAI, UTC repeats the first second of the next day
+        for &(unix, offset) in [
+            (78_796_800, 11),
+            (915_148_800, 32),
+            (1_136_073_600, 33),
+            (1_483_228_800, 37),
+        ]
+        .iter()
+        {
+            assert_eq!(leap.offset_at_utc(unix - 1), Some(offset - 1));
+            assert_eq!(leap.offset_at_utc(unix), Some(offset));
+
+            let tai = |secs: u64| Tai::new(Instant::<u64, 1, 2>::from_ticks(secs * 2));
+            let utc = |secs: u64| Utc::new(Instant::<u64, 1, 2>::from_ticks(secs * 2));
+            let start = unix + offset as u64 - 2;
+
+            assert_eq!(tai(start).to_utc(&leap), Some(utc(unix - 1)));
+            assert_eq!(tai(start + 1).to_utc(&leap), Some(utc(unix)));
+            assert_eq!(tai(start + 2).to_utc(&leap), Some(utc(unix)));
+            assert_eq!(tai(start + 3).to_utc(&leap), Some(utc(unix + 1)));
+
+            assert_eq!(utc(unix - 1).to_tai(&leap), Some(tai(start)));
+            assert_eq!(utc(unix).to_tai(&leap), Some(tai(start + 2)));
+        }
+
+        // Before 1972
+        let utc = Utc::<1, 1>::from_unix(63_071_999, 999_999_999).unwrap();
+        assert_eq!(utc.to_tai(&leap), None);
+        let tai = Tai::new(Instant::<u64, 1, 1>::from_ticks(63_072_009));
+        assert_eq!(tai.to_utc(&leap), None);
+
+        // A negative leap second skips a second of UTC
+        static TABLE: [(u64, u8); 2] = [(1_000, 40), (2_000, 39)];
+        let leap = LeapSeconds::new(&TABLE);
+        let tai = Tai::new(Instant::<u64, 1, 1>::from_ticks(2_038));
+        assert_eq!(tai.to_utc(&leap).unwrap().to_unix(), Some((1_998, 0)));
+        let tai = Tai::new(Instant::<u64, 1, 1>::from_ticks(2_039));
+        assert_eq!(tai.to_utc(&leap).unwrap().to_unix(), Some((2_000, 0)));
+
+        // GPS is 18 seconds ahead of UTC since 2017
+        let utc = Utc::<1, 1_000>::from_unix(1_700_000_000, 0).unwrap();
+        let gps = utc.to_gps(&LeapSeconds::BUILTIN).unwrap();
+        assert_eq!(
+            gps.instant().ticks(),
+            (1_700_000_000 - 315_964_800 + 18) * 1_000
+        );
+        assert_eq!(gps.to_utc(&LeapSeconds::BUILTIN), Some(utc));
+        assert_eq!(
+            Gps::new(Instant::<u64, 1, 1_000>::from_ticks(0)).to_utc(&LeapSeconds::BUILTIN),
+            Utc::from_unix(315_964_800, 0)
+        );
+        assert_eq!(
+            Tai::new(Instant::<u64, 1, 1_000>::from_ticks(315_964_818_999)).to_gps(),
+            None
+        );
+        assert_eq!(
+            Gps::new(Instant::<u64, 1, 1>::from_ticks(u64::MAX)).to_tai(),
+            None
+        );
+    }
+
+    #[test]
+    fn timescale_gps_week() {
+        use crate::{Gps, GpsWeekTime, MillisDurationU64};
+
+        // Ticks which are not a whole number of seconds
+        let gps = Gps::new(Instant::<u64, 1, 3>::from_ticks(3 * 604_800 * 1_024 + 5));
+        let week_time = gps.to_week_time().unwrap();
+        assert_eq!(week_time.week(), 1_024);
+        assert_eq!(week_time.time_of_week().ticks(), 5);
+        assert_eq!(Gps::from_week_time(week_time), Some(gps));
+
+        let gps = Gps::new(Instant::<u64, 7, 3>::from_ticks(1_555_199));
+        let week_time = gps.to_week_time().unwrap();
+        assert_eq!(week_time.week(), 5);
+        assert_eq!(Gps::from_week_time(week_time), Some(gps));
+
+        // Time of week must be below a week
+        assert_eq!(
+            GpsWeekTime::try_new(1, MillisDurationU64::millis(604_800_000)),
+            None
+        );
+        assert!(GpsWeekTime::try_new(1, MillisDurationU64::millis(604_799_999)).is_some());
+
+        // Out of range
+        let gps = Gps::new(Instant::<u64, 1, 1>::from_ticks(u64::MAX));
+        assert_eq!(gps.to_week_time(), None);
+        let week_time =
+            GpsWeekTime::new(u32::MAX, Duration::<u64, 1, 1_000_000_000>::from_ticks(0));
+        assert_eq!(Gps::from_week_time(week_time), None);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Run time base tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn dyn_duration() {
+        use crate::{DynDuration, Fraction, MillisDurationU32};
+        use core::convert::TryFrom;
+
+        // Same results as the const generic conversions
+        for ticks in [0u32, 1, 999, 1_000, 123_456, u32::MAX / 1_000] {
+            let d = Duration::<u32, 1, 1_000>::from_ticks(ticks);
+            let dyn_d = DynDuration::from(d);
+            assert_eq!(dyn_d.base(), Fraction::new(1, 1_000));
+
+            let c: Duration<u32, 1, 1_000_000> = d.convert();
+            assert_eq!(
+                dyn_d.convert(Fraction::new(1, 1_000_000)).ticks(),
+                c.ticks()
+            );
+            let c: Duration<u32, 1, 3> = d.convert();
+            assert_eq!(dyn_d.convert(Fraction::new(1, 3)).ticks(), c.ticks());
+            let c: Duration<u32, 1, 32_768> = d.convert();
+            assert_eq!(dyn_d.try_into_duration::<1, 32_768>(), Some(c));
+
+            assert_eq!(dyn_d.to_millis(), ticks);
+            assert_eq!(dyn_d.to_secs(), d.to_secs());
+            assert_eq!(dyn_d, d.into());
+        }
+
+        // Overflow
+        let d = DynDuration::<u32>::from_ticks(u32::MAX, Fraction::new(1, 1));
+        assert_eq!(d.checked_convert(Fraction::new(1, 1_000)), None);
+        assert_eq!(Duration::<u32, 1, 1_000>::try_from(d), Err(Error::Overflow));
+        assert_eq!(
+            d.checked_add(DynDuration::<u32>::millis(1_000, Fraction::new(1, 1_000))),
+            None
+        );
+        assert_eq!(
+            d.checked_add(DynDuration::<u32>::millis(999, Fraction::new(1, 1_000))),
+            Some(d)
+        );
+        let d = DynDuration::<u64>::from_ticks(u64::MAX, Fraction::new(u32::MAX as u64, 1));
+        assert_eq!(
+            d.const_cmp(DynDuration::<u64>::from_ticks(
+                u64::MAX,
+                Fraction::new(u32::MAX as u64 - 1, 1)
+            )),
+            core::cmp::Ordering::Greater
+        );
+
+        // Arithmetic and comparisons across bases
+        let base = Fraction::new(1, 48_000_000);
+        let a = DynDuration::<u64>::micros(10, base);
+        let b = DynDuration::<u64>::from_ticks(3, Fraction::new(1, 1_000_000));
+        assert_eq!((a + b).ticks(), 624);
+        assert_eq!((a - b).ticks(), 336);
+        assert_eq!((a + b).base(), base);
+        assert!(a > b);
+        assert!(b < a);
+        assert_eq!(a / b, 3);
+        assert_eq!((a * 3).to_micros(), 30);
+        assert_eq!((3 * a).to_micros(), 30);
+        assert_eq!((a / 2).to_micros(), 5);
+        let mut c = a;
+        c += b;
+        c -= b;
+        c *= 2;
+        c /= 4;
+        assert_eq!(c.to_micros(), 5);
+        assert!(DynDuration::<u64>::from_ticks(0, base).is_zero());
+        assert_eq!(
+            DynDuration::<u64>::hours(1, Fraction::new(60, 1)).ticks(),
+            60
+        );
+        assert_eq!(DynDuration::<u64>::minutes(1, base).to_secs(), 60);
+        assert_eq!(DynDuration::<u64>::secs(1, base).to_nanos(), 1_000_000_000);
+        assert_eq!(format!("{}", a), "480 ticks @ (1/48000000)");
+
+        let ms: MillisDurationU32 =
+            Duration::try_from(DynDuration::<u32>::secs(2, base).convert(Fraction::new(1, 1_000)))
+                .unwrap();
+        assert_eq!(ms, MillisDurationU32::secs(2));
+    }
+
+    #[test]
+    fn dyn_instant_and_rate() {
+        use crate::{DynDuration, DynInstant, DynRate, Fraction, HertzU32, MicrosDurationU32};
+        use core::convert::TryFrom;
+
+        // Instants wrap and are only comparable within a base
+        let base = Fraction::new(1, 1_000);
+        let i1 = DynInstant::<u32>::from_ticks(u32::MAX - 1, base);
+        let i2 = i1 + DynDuration::<u32>::from_ticks(3, base);
+        assert_eq!(i2.ticks(), 1);
+        assert!(i1 < i2);
+        assert_eq!(i2.checked_duration_since(i1).map(|d| d.ticks()), Some(3));
+        assert_eq!(i1.checked_duration_since(i2), None);
+        assert_eq!(
+            i2 - DynDuration::<u32>::micros(3_000, Fraction::new(1, 1_000_000)),
+            i1
+        );
+
+        let other = DynInstant::<u32>::from_ticks(1, Fraction::new(1, 1_000_000));
+        assert_eq!(i2.partial_cmp(&other), None);
+        assert_ne!(i2, other);
+        assert_eq!(i2.checked_duration_since(other), None);
+
+        let i = DynInstant::<u64>::from_ticks(1_500, base);
+        assert_eq!(
+            Instant::<u64, 1, 1_000_000>::try_from(i),
+            Ok(Instant::<u64, 1, 1_000_000>::from_ticks(1_500_000))
+        );
+        assert_eq!(
+            i.try_into_instant::<1, 1>(),
+            Some(Instant::<u64, 1, 1>::from_ticks(1))
+        );
+        assert_eq!(
+            DynInstant::from(Instant::<u64, 1, 1_000>::from_ticks(1_500)),
+            i
+        );
+        let mut j = i;
+        j += DynDuration::<u64>::secs(1, base);
+        j -= DynDuration::<u64>::millis(500, base);
+        assert_eq!(j.ticks(), 2_000);
+        assert_eq!(j.duration_since_epoch().to_secs(), 2);
+
+        // Rates
+        let base = Fraction::new(1, 16);
+        let r = DynRate::<u32>::Hz(32_768, base);
+        assert_eq!(r.raw(), 524_288);
+        assert_eq!(r, HertzU32::Hz(32_768).into());
+        assert_eq!(r.to_Hz(), 32_768);
+        assert_eq!(r.to_kHz(), 32);
+        assert_eq!(
+            DynRate::<u32>::kHz(2, base) + DynRate::<u32>::MHz(0, base),
+            DynRate::<u32>::Hz(2_000, base)
+        );
+        assert_eq!((r - DynRate::<u32>::Hz(768, base)).to_Hz(), 32_000);
+        assert_eq!((r * 2).to_Hz(), 65_536);
+        assert_eq!((r / 2).to_Hz(), 16_384);
+        assert!(r > DynRate::<u32>::from_raw(32_767, Fraction::new(1, 1)));
+        assert_eq!(HertzU32::try_from(r), Ok(HertzU32::Hz(32_768)));
+        assert_eq!(
+            r.try_into_rate::<1_000, 1>(),
+            Some(HertzU32::kHz(32).convert())
+        );
+        assert_eq!(format!("{}", r), "524288 raw @ (1/16)");
+
+        // Periods
+        let period = r.try_into_duration(Fraction::new(1, 1_000_000)).unwrap();
+        assert_eq!(period.ticks(), 30);
+        let period = DynDuration::<u32>::from(MicrosDurationU32::micros(40));
+        assert_eq!(
+            DynRate::<u32>::try_from_duration(period, Fraction::new(1, 1)).map(|r| r.raw()),
+            Some(25_000)
+        );
+        assert_eq!(
+            DynDuration::<u32>::try_from_rate(DynRate::<u32>::from_raw(0, base), base),
+            None
+        );
+        assert_eq!(
+            DynDuration::<u32>::from_ticks(1, Fraction::new(1, u32::MAX as u64))
+                .try_into_rate(Fraction::new(1, u32::MAX as u64)),
+            None
+        );
+        assert_eq!(
+            DynDuration::<u64>::from_ticks(u64::MAX, Fraction::new(u32::MAX as u64, 1))
+                .try_into_rate(Fraction::new(u32::MAX as u64, 1))
+                .map(|r| r.raw()),
+            Some(0)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Generic backing integer tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    fn generic_sum<T: crate::TickInt, const NOM: u64, const DENOM: u64>(
+        durations: &[Duration<T, NOM, DENOM>],
+    ) -> Option<Duration<T, NOM, DENOM>> {
+        use crate::ExtDuration;
+
+        let mut sum = Duration::<T, NOM, DENOM>::from_ticks(T::ZERO);
+        for d in durations {
+            sum = sum.checked_add(*d)?;
+        }
+        Some(sum)
+    }
+
+    fn generic_deadline<T: crate::TickInt, const NOM: u64, const DENOM: u64>(
+        now: Instant<T, NOM, DENOM>,
+        timeout: Duration<T, 1, 1_000>,
+    ) -> Option<Instant<T, NOM, DENOM>> {
+        use crate::ExtInstant;
+
+        now.checked_add_duration(timeout)
+    }
+
+    #[test]
+    fn tick_int_duration() {
+        use crate::{ExtDuration, ExtU32, ExtU64};
+
+        let d32 = [Duration::<u32, 1, 1_000>::from_ticks(1), 2u32.millis()];
+        let d64 = [Duration::<u64, 1, 1_000>::from_ticks(1), 2u64.millis()];
+        assert_eq!(generic_sum(&d32), Some(3u32.millis()));
+        assert_eq!(generic_sum(&d64), Some(3u64.millis()));
+        assert_eq!(
+            generic_sum(&[
+                Duration::<u32, 1, 1_000>::from_ticks(u32::MAX),
+                1u32.millis()
+            ]),
+            None
+        );
+
+        // Operators and comparisons are implemented once for all backing integers
+        fn ops<T: crate::TickInt>(d: Duration<T, 1, 1_000>) -> Duration<T, 1, 1_000> {
+            let mut x = d + d - d;
+            x *= 4;
+            x /= 2;
+            assert!(x > d);
+            assert!(x == 2 * d);
+            assert_eq!(x / d, T::ONE + T::ONE);
+            assert!(x.const_try_into::<1, 1>().unwrap().is_zero());
+            x.convert()
+        }
+        assert_eq!(ops(Duration::<u32, 1, 1_000>::from_ticks(3)).ticks(), 6);
+        assert_eq!(ops(Duration::<u64, 1, 1_000>::from_ticks(3)).ticks(), 6);
+
+        // Cross base conversions through the generic API
+        let d = Duration::<u32, 1, 1_000>::from_ticks(5);
+        assert_eq!(
+            ExtDuration::const_try_into::<1, 1_000_000>(d).map(|d| d.ticks()),
+            Some(5_000)
+        );
+        assert_eq!(
+            ExtDuration::try_into_rate::<1, 1>(d).map(|r| r.raw()),
+            Some(200)
+        );
+    }
+
+    #[test]
+    fn tick_int_instant_and_rate() {
+        use crate::{ExtInstant, ExtRate, ExtU32, RateExtU32};
+
+        // Wrapping is handled the same as by the const methods
+        let now = Instant::<u32, 1, 1_000_000>::from_ticks(u32::MAX - 999);
+        let deadline = generic_deadline(now, 2.millis()).unwrap();
+        assert_eq!(deadline.ticks(), 1_000);
+        assert!(deadline > now);
+        assert_eq!(deadline - now, 2_000u32.micros::<1, 1_000_000>());
+        assert_eq!(ExtInstant::checked_duration_since(now, deadline), None);
+        assert_eq!(
+            generic_deadline(
+                Instant::<u64, 1, 1_000>::from_ticks(1),
+                Duration::from_ticks(2)
+            )
+            .map(|i| i.ticks()),
+            Some(3)
+        );
+
+        let r = Rate::<u32, 1, 1>::from_raw(1_000);
+        assert_eq!(
+            ExtRate::checked_add(r, 1u32.kHz::<1, 1>()),
+            Some(2u32.kHz())
+        );
+        assert_eq!(ExtRate::checked_sub(r, 2u32.kHz::<1, 1>()), None);
+        assert_eq!(
+            ExtRate::try_into_duration::<1, 1_000_000>(r).map(|d| d.ticks()),
+            Some(1_000)
+        );
+        assert_eq!(ExtRate::convert::<1_000, 1>(r).raw(), 1);
+        assert_eq!(format!("{}", r * 3), "3000 Hz");
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Small backing integer tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn small_duration_and_rate() {
+        use core::convert::TryFrom;
+
+        // Cross base operations use 64-bit intermediates, the base constants never truncate
+        let s = Duration::<u8, 1, 1>::from_ticks(1);
+        let ms = Duration::<u8, 1, 1_000>::from_ticks(250);
+        assert_eq!(s.checked_add(ms), Some(Duration::<u8, 1, 1>::from_ticks(1)));
+        assert_eq!(ms.checked_add(s), None);
+        assert!(s > ms);
+        assert!(
+            Duration::<u16, 1, 1>::from_ticks(1) == Duration::<u16, 1, 1_000>::from_ticks(1_000)
+        );
+        assert_eq!(
+            Duration::<u16, 1, 1_000_000>::from_ticks(60_000).const_try_into::<1, 1_000>(),
+            Some(Duration::<u16, 1, 1_000>::from_ticks(60))
+        );
+        assert_eq!(
+            ms.const_try_into::<1, 10_000>(),
+            None::<Duration<u8, 1, 10_000>>
+        );
+
+        // Shorthands
+        assert_eq!(Duration::<u16, 1, 1_000>::secs(3).ticks(), 3_000);
+        assert_eq!(Duration::<u8, 1, 1_000>::nanos(200).ticks(), 0);
+        assert_eq!(Duration::<u8, 1, 1_000>::nanos_at_least(200).ticks(), 1);
+        assert_eq!(Duration::<u16, 1, 1_000>::from_ticks(2_500).to_secs(), 2);
+        assert_eq!(Rate::<u16, 1, 1>::kHz(40).raw(), 40_000);
+        assert_eq!(Rate::<u16, 1_000, 1>::from_raw(3).to_Hz(), 3_000);
+
+        // Rates and durations
+        assert_eq!(
+            Rate::<u16, 1, 1>::from_raw(50).try_into_duration::<1, 1_000>(),
+            Some(Duration::<u16, 1, 1_000>::from_ticks(20))
+        );
+        assert_eq!(
+            Rate::<u8, 1, 1>::from_raw(1).try_into_duration::<1, 1_000>(),
+            None
+        );
+
+        // Checked widening and narrowing
+        let d = Duration::<u16, 1, 1_000>::from_ticks(40_000);
+        assert_eq!(Duration::<u64, 1, 1_000>::from(d).ticks(), 40_000);
+        assert_eq!(Duration::<u32, 1, 1_000>::from(ms).ticks(), 250);
+        assert_eq!(Duration::<u8, 1, 1_000>::try_from(d), Err(Error::Overflow));
+        assert_eq!(
+            Duration::<u16, 1, 1_000>::try_from(Duration::<u32, 1, 1_000>::from_ticks(7)),
+            Ok(Duration::<u16, 1, 1_000>::from_ticks(7))
+        );
+        assert_eq!(
+            Rate::<u32, 1, 1>::from(Rate::<u8, 1, 1>::from_raw(9)).raw(),
+            9
+        );
+        assert_eq!(
+            Rate::<u16, 1, 1>::try_from(Rate::<u64, 1, 1>::from_raw(1 << 16)),
+            Err(Error::Overflow)
+        );
+
+        // Integer factors wider than the backing integer are not truncated
+        assert_eq!((Duration::<u8, 1, 1>::from_ticks(200) / 256).ticks(), 0);
+        assert_eq!((Rate::<u16, 1, 1>::from_raw(1_000) / 70_000).raw(), 0);
+        assert_eq!((Duration::<u16, 1, 1>::from_ticks(2) * 300).ticks(), 600);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow!")]
+    fn small_mul_wide_factor() {
+        let _ = Duration::<u8, 1, 1>::from_ticks(0) * 300;
+    }
+
+    #[test]
+    fn small_instant() {
+        // Wrap-aware comparison and arithmetic on a 16-bit timer
+        let i1 = Instant::<u16, 1, 1_000>::from_ticks(u16::MAX - 10);
+        let i2 = i1 + Duration::<u16, 1, 1_000>::from_ticks(20);
+        assert_eq!(i2.ticks(), 9);
+        assert!(i2 > i1);
+        assert_eq!(i1.const_cmp(i2), core::cmp::Ordering::Less);
+        assert_eq!(i2 - i1, Duration::<u16, 1, 1_000>::from_ticks(20));
+        assert_eq!(i1.checked_duration_since(i2), None);
+
+        // Durations in a coarser base are moved through a 64-bit intermediate
+        let i3 = Instant::<u16, 1, 1_000>::from_ticks(0)
+            .checked_add_duration(Duration::<u16, 1, 1>::from_ticks(60))
+            .unwrap();
+        assert_eq!(i3.ticks(), 60_000);
+        assert_eq!(
+            Instant::<u8, 1, 1_000>::from_ticks(0)
+                .checked_add_duration(Duration::<u8, 1, 1>::from_ticks(1)),
+            None
+        );
+
+        let i4 = Instant::<u8, 1, 100>::from_ticks(250);
+        let i5 = i4 + Duration::<u8, 1, 100>::from_ticks(10);
+        assert_eq!(i5.ticks(), 4);
+        assert!(i5 > i4);
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Wide backing integer tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn wide_intermediates() {
+        // 15 minutes at 80 MHz in nanoseconds
+        let d = Duration::<u64, 1, 80_000_000>::from_ticks(15 * 60 * 80_000_000);
+        assert_eq!(
+            d.const_try_into::<1, 1_000_000_000>(),
+            Some(Duration::<u64, 1, 1_000_000_000>::from_ticks(
+                900_000_000_000
+            ))
+        );
+
+        // The product overflows 64 bits while the result does not
+        let d = Duration::<u64, 1, 3>::from_ticks(u64::MAX / 2);
+        assert_eq!(
+            d.const_try_into::<1, 2>(),
+            Some(Duration::<u64, 1, 2>::from_ticks(6_148_914_691_236_517_204))
+        );
+        assert_eq!(
+            d.const_partial_cmp(Duration::<u64, 1, 2>::from_ticks(1)),
+            Some(core::cmp::Ordering::Greater)
+        );
+        assert_eq!(
+            Duration::<u64, 1, 2>::from_ticks(1).checked_add(d),
+            Some(Duration::<u64, 1, 2>::from_ticks(6_148_914_691_236_517_205))
+        );
+        assert_eq!(
+            Rate::<u64, 1, 3>::from_raw(u64::MAX / 2).const_try_into::<1, 2>(),
+            Some(Rate::<u64, 1, 2>::from_raw(6_148_914_691_236_517_204))
+        );
+        assert_eq!(
+            Duration::<u64, 1, 2>::from_ticks(u64::MAX).const_try_into::<1, 3>(),
+            None
+        );
+    }
+
+    #[test]
+    fn wide_backing() {
+        use core::convert::TryFrom;
+
+        // A century of nanoseconds
+        let century = Duration::<u128, 1, 1_000_000_000>::secs(100 * 365 * 86_400);
+        assert_eq!(century.ticks(), 3_153_600_000_000_000_000);
+        assert_eq!(century.to_hours(), 876_000);
+        assert_eq!(
+            Duration::<u128, 1, 1>::from_ticks(u128::MAX).const_try_into::<1, 1_000>(),
+            None
+        );
+        assert_eq!(
+            century.convert::<1, 1_000_000>(),
+            Duration::<u128, 1, 1_000_000>::from_ticks(3_153_600_000_000_000)
+        );
+
+        let start = Instant::<u128, 1, 1_000_000_000>::from_ticks(u64::MAX as u128);
+        let end = start + century;
+        assert!(end > start);
+        assert_eq!(end - start, century);
+        assert_eq!(
+            end.checked_add_duration(Duration::<u128, 1, 1>::from_ticks(1))
+                .map(|i| i.ticks() - end.ticks()),
+            Some(1_000_000_000)
+        );
+
+        assert_eq!(
+            Rate::<u128, 1, 1>::from_raw(80_000_000).try_into_duration::<1, 1_000_000_000>(),
+            Some(Duration::<u128, 1, 1_000_000_000>::from_ticks(12))
+        );
+
+        // Widening and narrowing
+        let d = Duration::<u64, 1, 1_000>::from_ticks(u64::MAX);
+        assert_eq!(
+            Duration::<u128, 1, 1_000>::from(d).ticks(),
+            u64::MAX as u128
+        );
+        assert_eq!(
+            Duration::<u64, 1, 1_000>::try_from(Duration::<u128, 1, 1_000>::from(d) * 2),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Rate::<u32, 1, 1>::try_from(Rate::<u128, 1, 1>::from_raw(5)),
+            Ok(Rate::<u32, 1, 1>::from_raw(5))
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Wide timebase tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn picos_and_femtos() {
+        use crate::{ExtU64, FemtosDurationU64, PicosDurationU32, PicosDurationU64};
+
+        let d: PicosDurationU64 = 3u64.nanos();
+        assert_eq!(d.ticks(), 3_000);
+        assert_eq!(d.to_picos(), 3_000);
+        assert_eq!(d.to_femtos(), 3_000_000);
+        assert_eq!(PicosDurationU32::micros(1).ticks(), 1_000_000);
+        assert_eq!(PicosDurationU32::from_ticks(1_500).to_nanos(), 1);
+        assert_eq!(
+            Duration::<u32, 1, 1_000_000_000>::picos_at_least(1_500).ticks(),
+            2
+        );
+        assert_eq!(FemtosDurationU64::picos(2).ticks(), 2_000);
+        assert_eq!(
+            FemtosDurationU64::from_ticks(1),
+            PicosDurationU64::from_ticks(0).convert::<1, 1_000_000_000_000_000>()
+                + FemtosDurationU64::from_ticks(1)
+        );
+        assert!(FemtosDurationU64::from_ticks(1_001) > PicosDurationU64::from_ticks(1));
+
+        assert_eq!(format!("{}", PicosDurationU32::from_ticks(5)), "5 ps");
+        assert_eq!(format!("{}", FemtosDurationU64::from_ticks(5)), "5 fs");
+        assert_eq!(
+            format!("{}", Instant::<u64, 1, 1_000_000_000_000>::from_ticks(5)),
+            "5 ps"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Instant::<u32, 1, 1_000_000_000_000_000>::from_ticks(5)
+            ),
+            "5 fs"
+        );
+        assert_eq!(
+            format!("{}", Instant::<u32, 86_400, 1>::from_ticks(5)),
+            "5 d"
+        );
+        assert_eq!(
+            format!("{}", Instant::<u32, 604_800, 1>::from_ticks(5)),
+            "5 w"
+        );
+
+        // 5 ms does not fit `u32` picoseconds
+        assert_eq!(
+            Duration::<u32, 1, 1_000>::from_ticks(5).const_try_into::<1, 1_000_000_000_000>(),
+            None
+        );
+    }
+
+    #[test]
+    fn wide_bases() {
+        // The native product overflows, the wide intermediate is used
+        assert_eq!(
+            Duration::<u32, 1, 32_768>::from_ticks(u32::MAX).to_millis(),
+            131_071_999
+        );
+        assert_eq!(
+            Duration::<u32, 1, 1_000>::from_ticks(4_000_000).to_secs(),
+            4_000
+        );
+
+        use crate::{DynDuration, Fraction, NanosDurationU32, PicosDurationU32};
+
+        // 10 GHz timer
+        let d = Duration::<u32, 1, 10_000_000_000>::from_ticks(25);
+        assert_eq!(d.to_picos(), 2_500);
+        assert_eq!(Duration::<u32, 1, 10_000_000_000>::nanos(1).ticks(), 10);
+        assert!(d.const_eq(PicosDurationU32::from_ticks(2_500)));
+        assert!(d > NanosDurationU32::from_ticks(2));
+        assert_eq!(
+            Rate::<u64, 1, 1>::from_raw(10_000_000_000).into_duration::<1, 1_000_000_000_000>(),
+            Duration::<u64, 1, 1_000_000_000_000>::from_ticks(100)
+        );
+
+        // 0.5 mHz in micro hertz
+        let r = Rate::<u32, 1, 1_000_000>::from_raw(500);
+        assert_eq!(
+            r.try_into_duration::<1, 1>(),
+            Some(Duration::<u32, 1, 1>::from_ticks(2_000))
+        );
+        assert_eq!(r.to_Hz(), 0);
+
+        // Conversion constants which do not fit the intermediate
+        let far = Duration::<u8, 1, 10_000_000_000>::from_ticks(255);
+        assert_eq!(
+            far.const_try_into::<10_000_000_000, 1>().map(|d| d.ticks()),
+            Some(0)
+        );
+        assert_eq!(
+            Duration::<u8, 10_000_000_000, 1>::from_ticks(1).const_try_into::<1, 10_000_000_000>(),
+            None
+        );
+        assert_eq!(
+            Duration::<u8, 10_000_000_000, 1>::from_ticks(0).const_try_into::<1, 10_000_000_000>(),
+            Some(Duration::<u8, 1, 10_000_000_000>::from_ticks(0))
+        );
+
+        // Run time bases
+        let base = Fraction::new(1, 10_000_000_000);
+        assert_eq!(base.denom(), 10_000_000_000);
+        let d = DynDuration::<u64>::picos(2_500, base);
+        assert_eq!(d.ticks(), 25);
+        assert_eq!(d.to_femtos(), 2_500_000);
+        assert_eq!(
+            Fraction::new(10_000_000_000, 20_000_000_000),
+            Fraction::new(1, 2)
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Error tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn errors() {
+        use crate::{DynDuration, Fraction, MillisDurationU32};
+        use core::convert::TryFrom;
+
+        let ms = MillisDurationU32::from_ticks(u32::MAX);
+        assert_eq!(ms.try_convert::<1, 1_000_000>(), Err(Error::Overflow));
+        assert_eq!(
+            ms.try_add(Duration::<u32, 1, 1>::from_ticks(1)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            MillisDurationU32::from_ticks(1).try_sub(Duration::<u32, 1, 1>::from_ticks(u32::MAX)),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Duration::<u32, 1, 1_000_000_000>::from_ticks(1).try_to_rate::<1, 1_000>(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Rate::<u32, 1, 1>::from_raw(0).try_to_duration::<1, 1>(),
+            Err(Error::DivideByZero)
+        );
+
+        let early = Instant::<u32, 1, 1_000>::from_ticks(10);
+        let late = early + MillisDurationU32::from_ticks(5);
+        assert_eq!(
+            late.try_duration_since(early),
+            Ok(MillisDurationU32::from_ticks(5))
+        );
+        assert_eq!(early.try_duration_since(late), Err(Error::InstantInFuture));
+
+        assert_eq!(
+            Duration::<u32, 1, 1>::try_from(Duration::<u64, 1, 1>::from_ticks(1 << 32)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Duration::<u32, 1, 1>::try_from(DynDuration::<u32>::from_ticks(1, Fraction::new(1, 2))),
+            Ok(Duration::<u32, 1, 1>::from_ticks(0))
+        );
+
+        // Reportable through `core::error::Error`
+        let err: &dyn core::error::Error = &Error::PrecisionLoss;
+        assert_eq!(format!("{}", err), "precision loss");
+        assert_eq!(format!("{}", Error::DivideByZero), "divide-by-zero");
+    }
+
+    #[test]
+    fn exact_conversions() {
+        use crate::{ExactError, MicrosDurationU32, MillisDurationU32};
+
+        let d = MicrosDurationU32::from_ticks(1_500);
+        assert_eq!(
+            d.exact_convert::<1, 1_000>(),
+            Err(ExactError::PrecisionLoss {
+                truncated: MillisDurationU32::from_ticks(1),
+                remainder: 500,
+                denom: 1_000,
+            })
+        );
+        assert_eq!(
+            Error::from(d.exact_convert::<1, 1_000>().unwrap_err()),
+            Error::PrecisionLoss
+        );
+        assert_eq!(
+            MicrosDurationU32::from_ticks(2_000).exact_convert::<1, 1_000>(),
+            Ok(MillisDurationU32::from_ticks(2))
+        );
+        assert_eq!(
+            MillisDurationU32::from_ticks(u32::MAX).exact_convert::<1, 1_000_000>(),
+            Err(ExactError::Overflow)
+        );
+
+        // Bases which are not multiples of each other
+        assert_eq!(
+            Duration::<u32, 1, 3>::from_ticks(3).exact_convert::<1, 2>(),
+            Ok(Duration::<u32, 1, 2>::from_ticks(2))
+        );
+        assert_eq!(
+            Duration::<u32, 1, 3>::from_ticks(1).exact_convert::<1, 2>(),
+            Err(ExactError::PrecisionLoss {
+                truncated: Duration::<u32, 1, 2>::from_ticks(0),
+                remainder: 2,
+                denom: 3,
+            })
+        );
+
+        // A divisor which does not fit the intermediate
+        assert_eq!(
+            Duration::<u8, 1, 10_000_000_000>::from_ticks(5).exact_convert::<10_000_000_000, 1>(),
+            Err(ExactError::PrecisionLoss {
+                truncated: Duration::<u8, 10_000_000_000, 1>::from_ticks(0),
+                remainder: 5,
+                denom: 100_000_000_000_000_000_000,
+            })
+        );
+
+        // Infallible for whole multiples, also in const contexts
+        const MS: MillisDurationU32 = MillisDurationU32::from_ticks(3);
+        const US: MicrosDurationU32 = MS.exact_into();
+        assert_eq!(US.ticks(), 3_000);
+        assert_eq!(
+            Rate::<u32, 1_000, 1>::from_raw(3).exact_into::<1, 1>(),
+            Rate::<u32, 1, 1>::from_raw(3_000)
+        );
+        assert_eq!(
+            Rate::<u32, 1, 1>::from_raw(3_500).exact_convert::<1_000, 1>(),
+            Err(ExactError::PrecisionLoss {
+                truncated: Rate::<u32, 1_000, 1>::from_raw(3),
+                remainder: 500,
+                denom: 1_000,
+            })
+        );
+        assert_eq!(
+            Instant::<u64, 1, 1_000>::from_ticks(7).exact_into::<1, 1_000_000>(),
+            Instant::<u64, 1, 1_000_000>::from_ticks(7_000)
+        );
+        assert!(Instant::<u64, 1, 1_000_000>::from_ticks(7_001)
+            .exact_convert::<1, 1_000>()
+            .is_err());
+
+        assert_eq!(
+            format!(
+                "{}",
+                MicrosDurationU32::from_ticks(1_500)
+                    .exact_convert::<1, 1_000>()
+                    .unwrap_err()
+            ),
+            "precision loss of 500/1000 ticks"
+        );
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Compile time assertion tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn const_assertions() {
+        use crate::{
+            assert_exact_base, assert_same_base, assert_valid_base, ExtU32, MicrosDurationU32,
+        };
+
+        const CLOCK: HertzU32 = HertzU32::MHz(1);
+        const TIMEOUT: MicrosDurationU32 = MicrosDurationU32::micros(65_536);
+        const _: () = assert!(TIMEOUT.fits_timer(CLOCK, 16));
+        const _: () = assert!(HertzU32::kHz(49).is_within_ppm(HertzU32::kHz(50), 20_000));
+        const _: () = assert_exact_base::<1, 1_000, 1, 1_000_000>();
+
+        assert!(!MicrosDurationU32::micros(65_537).fits_timer(CLOCK, 16));
+        // Rounded up to whole cycles
+        assert!(!Duration::<u32, 1, 10_000_000>::from_ticks(655_361).fits_timer(CLOCK, 16));
+        assert!(Duration::<u32, 1, 10_000_000>::from_ticks(655_360).fits_timer(CLOCK, 16));
+        assert!(Duration::<u64, 1, 1>::from_ticks(u64::MAX)
+            .fits_timer(Rate::<u64, 1, 1>::from_raw(u64::MAX), 128));
+        assert!(!Duration::<u128, 1, 1>::from_ticks(u128::MAX)
+            .fits_timer(Rate::<u128, 1, 1>::from_raw(2), 128));
+        assert!(0u32.millis::<1, 1_000>().fits_timer(CLOCK, 0));
+
+        assert!(HertzU32::kHz(51).is_within_ppm(HertzU32::kHz(50), 20_000));
+        assert!(!HertzU32::Hz(51_001).is_within_ppm(HertzU32::kHz(50), 20_000));
+        assert!(!HertzU32::Hz(48_999).is_within_ppm(HertzU32::kHz(50), 20_000));
+        assert!(!HertzU32::Hz(1).is_within_ppm(HertzU32::Hz(0), u32::MAX));
+        assert!(Rate::<u32, 1, 1_000>::from_raw(50_500).is_within_ppm(HertzU32::Hz(50), 10_000));
+
+        assert_valid_base::<1, 32_768>();
+        assert_exact_base::<1, 1, 1, 32_768>();
+        assert_same_base::<3, 6, 1, 2>();
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////
+    //
+    // Literal macro tests
+    //
+    ////////////////////////////////////////////////////////////////////////////////
+
+    #[test]
+    fn literals() {
+        use crate::{
+            duration, rate, HoursDurationU32, KilohertzU32, MegahertzU32, MicrosDurationU32,
+            MillisDurationU32, NanosDurationU64, SecsDurationU32,
+        };
+
+        assert_eq!(duration!(250 ms), MillisDurationU32::from_ticks(250));
+        assert_eq!(duration!(1_000 us), MicrosDurationU32::from_ticks(1_000));
+        assert_eq!(duration!(2 min), Duration::<u32, 60, 1>::from_ticks(2));
+        assert_eq!(duration!(0 s), SecsDurationU32::from_ticks(0));
+        assert_eq!(duration!(1.25 ms).ticks(), 125);
+        assert_eq!(duration!(1.25 ms), MicrosDurationU32::from_ticks(1_250));
+        assert_eq!(duration!(0.5 h), Duration::<u32, 1_800, 1>::from_ticks(1));
+        assert_eq!(duration!(2 w), Duration::<u32, 604_800, 1>::from_ticks(2));
+        assert_eq!(duration!(1.5 d => HoursDurationU32).ticks(), 36);
+
+        const D: Duration<u32, 1, 32_768> = duration!(1.5 s => Duration<u32, 1, 32_768>);
+        assert_eq!(D.ticks(), 49_152);
+        assert_eq!(duration!(5 s => NanosDurationU64).ticks(), 5_000_000_000);
+        assert_eq!(duration!(1 h => MillisDurationU32).ticks(), 3_600_000);
+        assert_eq!(duration!(0 ms => Duration<u8, 1, 1>).ticks(), 0);
+
+        assert_eq!(rate!(32.768 kHz), HertzU32::from_raw(32_768));
+        assert_eq!(rate!(8 MHz), Rate::<u32, 1_000_000, 1>::from_raw(8));
+        assert_eq!(rate!(1.5 Hz), Rate::<u32, 1, 10>::from_raw(15));
+        assert_eq!(rate!(2.4 GHz => MegahertzU32).raw(), 2_400);
+        assert_eq!(rate!(1_500 rpm => HertzU32).raw(), 25);
+        assert_eq!(rate!(500 mHz), Rate::<u32, 1, 1_000>::from_raw(500));
+
+        const R: KilohertzU32 = rate!(8 MHz => KilohertzU32);
+        assert_eq!(R.raw(), 8_000);
+        assert_eq!(rate!(48_000_000 Hz => HertzU32).raw(), 48_000_000);
+    }
 }
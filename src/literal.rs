@@ -0,0 +1,227 @@
+//! Const `Duration` and `Rate` literals, see [`duration!`](crate::duration) and
+//! [`rate!`](crate::rate).
+//!
+//! Decimal literals are parsed as the exact fraction `digits / 10^decimals`, so e.g. `1.25 ms`
+//! converts to exactly 5 ticks of a `1 / 4_000` base without any floating point rounding.
+
+/// Create a const [`Duration`](crate::Duration) from an integer or decimal literal and a unit,
+/// one of `fs`, `ps`, `ns`, `us`, `ms`, `s`, `min`, `h`, `d` or `w`.
+///
+/// Without a target type the duration is `u32` backed, in the unit divided by the decimals of
+/// the literal. With `=> Type` it is converted to any `Duration` type. The value is computed at
+/// compile time, and compilation fails if it is not a whole number of ticks or does not fit.
+///
+/// ```
+/// # use fugit::*;
+/// let d = duration!(250 ms);
+/// assert_eq!(d, MillisDurationU32::from_ticks(250));
+///
+/// let d = duration!(1.25 ms);
+/// assert_eq!(d, Duration::<u32, 1, 100_000>::from_ticks(125));
+///
+/// const D: Duration<u32, 1, 32_768> = duration!(1.5 s => Duration<u32, 1, 32_768>);
+/// assert_eq!(D.ticks(), 49_152);
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// // Fails as 1 ms is 32.768 ticks
+/// let d = duration!(1 ms => Duration<u32, 1, 32_768>);
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// // Fails as 5 s does not fit `u32` nanoseconds
+/// let d = duration!(5 s => NanosDurationU32);
+/// ```
+#[macro_export]
+macro_rules! duration {
+    (@unit fs) => { (1, 1_000_000_000_000_000) };
+    (@unit ps) => { (1, 1_000_000_000_000) };
+    (@unit ns) => { (1, 1_000_000_000) };
+    (@unit us) => { (1, 1_000_000) };
+    (@unit ms) => { (1, 1_000) };
+    (@unit s) => { (1, 1) };
+    (@unit min) => { (60, 1) };
+    (@unit h) => { (3_600, 1) };
+    (@unit d) => { (86_400, 1) };
+    (@unit w) => { (604_800, 1) };
+    (@unit $unit:ident) => {
+        compile_error!(concat!(
+            "Unknown duration unit `",
+            stringify!($unit),
+            "`, expected one of fs, ps, ns, us, ms, s, min, h, d or w"
+        ))
+    };
+    ($val:literal $unit:ident => $t:ty) => {
+        const { <$t>::__from_literal(stringify!($val), $crate::duration!(@unit $unit)) }
+    };
+    ($val:literal $unit:ident) => {
+        $crate::duration!($val $unit => $crate::Duration<
+            u32,
+            { $crate::__literal_base(stringify!($val), $crate::duration!(@unit $unit)).0 },
+            { $crate::__literal_base(stringify!($val), $crate::duration!(@unit $unit)).1 },
+        >)
+    };
+}
+
+/// Create a const [`Rate`](crate::Rate) from an integer or decimal literal and a unit, one of
+/// `mHz`, `Hz`, `kHz`, `MHz`, `GHz` or `rpm`.
+///
+/// Without a target type the rate is `u32` backed, in the unit divided by the decimals of the
+/// literal. With `=> Type` it is converted to any `Rate` type. The value is computed at compile
+/// time, and compilation fails if it is not a whole number of steps of the base or does not fit.
+///
+/// ```
+/// # use fugit::*;
+/// let r = rate!(32.768 kHz);
+/// assert_eq!(r, HertzU32::from_raw(32_768));
+///
+/// const R: KilohertzU32 = rate!(8 MHz => KilohertzU32);
+/// assert_eq!(R.raw(), 8_000);
+/// ```
+///
+/// ```compile_fail
+/// # use fugit::*;
+/// // Fails as 32.768 kHz is not a whole number of kHz
+/// let r = rate!(32.768 kHz => KilohertzU32);
+/// ```
+#[macro_export]
+macro_rules! rate {
+    (@unit mHz) => { (1, 1_000) };
+    (@unit Hz) => { (1, 1) };
+    (@unit kHz) => { (1_000, 1) };
+    (@unit MHz) => { (1_000_000, 1) };
+    (@unit GHz) => { (1_000_000_000, 1) };
+    (@unit rpm) => { (1, 60) };
+    (@unit $unit:ident) => {
+        compile_error!(concat!(
+            "Unknown rate unit `",
+            stringify!($unit),
+            "`, expected one of mHz, Hz, kHz, MHz, GHz or rpm"
+        ))
+    };
+    ($val:literal $unit:ident => $t:ty) => {
+        const { <$t>::__from_literal(stringify!($val), $crate::rate!(@unit $unit)) }
+    };
+    ($val:literal $unit:ident) => {
+        $crate::rate!($val $unit => $crate::Rate<
+            u32,
+            { $crate::__literal_base(stringify!($val), $crate::rate!(@unit $unit)).0 },
+            { $crate::__literal_base(stringify!($val), $crate::rate!(@unit $unit)).1 },
+        >)
+    };
+}
+
+/// A decimal literal as `digits / 10^decimals`.
+const fn parse(lit: &str) -> (u128, u32) {
+    let bytes = lit.as_bytes();
+    let mut digits: u128 = 0;
+    let mut decimals = 0;
+    let mut point = false;
+    let mut any = false;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+
+        if b.is_ascii_digit() {
+            digits = match digits.checked_mul(10) {
+                Some(v) => match v.checked_add((b - b'0') as u128) {
+                    Some(v) => v,
+                    None => panic!("The literal has too many digits"),
+                },
+                None => panic!("The literal has too many digits"),
+            };
+            if point {
+                decimals += 1;
+            }
+            any = true;
+        } else if b == b'.' && !point {
+            point = true;
+        } else if b != b'_' {
+            panic!("Only integer and decimal literals without suffix or exponent are supported");
+        }
+
+        i += 1;
+    }
+
+    if !any {
+        panic!("The literal has no digits");
+    }
+
+    (digits, decimals)
+}
+
+/// The literal as a reduced fraction of seconds or hertz.
+const fn fraction(lit: &str, unit: (u64, u64)) -> (u128, u128) {
+    let (digits, decimals) = parse(lit);
+
+    let num = match digits.checked_mul(unit.0 as u128) {
+        Some(v) => v,
+        None => panic!("The literal has too many digits"),
+    };
+    let den = match 10u128.checked_pow(decimals) {
+        Some(v) => match v.checked_mul(unit.1 as u128) {
+            Some(v) => v,
+            None => panic!("The literal has too many decimals"),
+        },
+        None => panic!("The literal has too many decimals"),
+    };
+
+    let divisor = gcd::binary_u128(num, den);
+    (num / divisor, den / divisor)
+}
+
+/// The base of a literal without a target type: the unit divided by the decimals of the literal.
+#[doc(hidden)]
+pub const fn __literal_base(lit: &str, unit: (u64, u64)) -> (u64, u64) {
+    let (_, decimals) = parse(lit);
+
+    let nom = unit.0 as u128;
+    let denom = match 10u128.checked_pow(decimals) {
+        Some(v) => match v.checked_mul(unit.1 as u128) {
+            Some(v) => v,
+            None => panic!("The literal has too many decimals"),
+        },
+        None => panic!("The literal has too many decimals"),
+    };
+
+    let divisor = gcd::binary_u128(nom, denom);
+    let (nom, denom) = (nom / divisor, denom / divisor);
+
+    if denom > u64::MAX as u128 {
+        panic!("The literal has too many decimals");
+    }
+
+    (nom as u64, denom as u64)
+}
+
+/// Ticks of the `nom / denom` base for a literal in `unit`, panics if it is not a whole number
+/// of ticks.
+pub(crate) const fn ticks(lit: &str, unit: (u64, u64), nom: u64, denom: u64) -> u128 {
+    let (num, den) = fraction(lit, unit);
+    if num == 0 {
+        return 0;
+    }
+
+    // ticks = num * denom / (den * nom), with the common factors removed `num` is coprime to
+    // `den * nom`, so `den * nom` must divide `denom`
+    let g = gcd::binary_u128(num, nom as u128);
+    let (num, nom) = (num / g, nom as u128 / g);
+    let g = gcd::binary_u128(denom as u128, den);
+    let (denom, den) = (denom as u128 / g, den / g);
+
+    let div = match den.checked_mul(nom) {
+        Some(v) => v,
+        None => panic!("The value is not exactly representable in the base"),
+    };
+    if denom % div != 0 {
+        panic!("The value is not exactly representable in the base");
+    }
+
+    match num.checked_mul(denom / div) {
+        Some(v) => v,
+        None => panic!("The value does not fit the backing integer"),
+    }
+}
@@ -0,0 +1,340 @@
+use crate::helpers;
+use crate::{HertzU32, Rate};
+
+/// Ranges of a PLL with an input divider `M`, a feedback multiplier `N` and an output divider
+/// `P`, where `output = input / M * N / P`.
+///
+/// All ranges are inclusive and all frequencies are in Hz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PllLimits {
+    /// Smallest input divider.
+    pub m_min: u32,
+    /// Largest input divider.
+    pub m_max: u32,
+    /// Smallest feedback multiplier.
+    pub n_min: u32,
+    /// Largest feedback multiplier.
+    pub n_max: u32,
+    /// Smallest output divider.
+    pub p_min: u32,
+    /// Largest output divider.
+    pub p_max: u32,
+    /// Step between supported output dividers, e.g. 2 if only even dividers are supported.
+    pub p_step: u32,
+    /// Lowest frequency at the phase detector, i.e. `input / M`.
+    pub ref_min: HertzU32,
+    /// Highest frequency at the phase detector, i.e. `input / M`.
+    pub ref_max: HertzU32,
+    /// Lowest VCO frequency, i.e. `input / M * N`.
+    pub vco_min: HertzU32,
+    /// Highest VCO frequency, i.e. `input / M * N`.
+    pub vco_max: HertzU32,
+}
+
+/// PLL configuration, as solved from an input clock and a target output rate.
+///
+/// Among all configurations within the limits the one closest to the target is selected, ties
+/// are broken in favor of the smallest `M` and then the smallest `P`.
+///
+/// ```
+/// # use fugit::*;
+/// // STM32F4 main PLL
+/// const STM32F4_PLL: PllLimits = PllLimits {
+///     m_min: 2,
+///     m_max: 63,
+///     n_min: 50,
+///     n_max: 432,
+///     p_min: 2,
+///     p_max: 8,
+///     p_step: 2,
+///     ref_min: HertzU32::kHz(950),
+///     ref_max: HertzU32::kHz(2_100),
+///     vco_min: HertzU32::MHz(100),
+///     vco_max: HertzU32::MHz(432),
+/// };
+///
+/// // Solved at compile time, fails compilation if 168 MHz can not be reached exactly
+/// const PLL: PllConfig<1_000_000, 1> =
+///     PllConfig::new(HertzU32::MHz(8), MegahertzU32::MHz(168), STM32F4_PLL, 0);
+///
+/// assert_eq!((PLL.m(), PLL.n(), PLL.p()), (4, 168, 2));
+/// assert_eq!(PLL.vco(), HertzU32::MHz(336));
+/// assert_eq!(PLL.rate(), MegahertzU32::MHz(168));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PllConfig<const NOM: u64, const DENOM: u64> {
+    m: u32,
+    n: u32,
+    p: u32,
+    vco: HertzU32,
+    rate: Rate<u32, NOM, DENOM>,
+    error_ppm: i32,
+}
+
+impl<const NOM: u64, const DENOM: u64> PllConfig<NOM, DENOM> {
+    /// Solve for the configuration closest to `target`, `None` if no configuration within the
+    /// limits is within `max_error_ppm` of the target (10 000 ppm is 1 %).
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let limits = PllLimits {
+    ///     m_min: 1,
+    ///     m_max: 8,
+    ///     n_min: 8,
+    ///     n_max: 64,
+    ///     p_min: 1,
+    ///     p_max: 8,
+    ///     p_step: 1,
+    ///     ref_min: HertzU32::MHz(1),
+    ///     ref_max: HertzU32::MHz(16),
+    ///     vco_min: HertzU32::MHz(64),
+    ///     vco_max: HertzU32::MHz(256),
+    /// };
+    ///
+    /// let pll = PllConfig::try_new(HertzU32::MHz(12), HertzU32::kHz(100_001), limits, 100).unwrap();
+    /// assert_eq!(pll.rate(), HertzU32::MHz(100));
+    /// assert_eq!(pll.error_ppm(), -9);
+    ///
+    /// // 100.001 MHz can not be reached within 1 ppm
+    /// assert_eq!(PllConfig::try_new(HertzU32::MHz(12), HertzU32::kHz(100_001), limits, 1), None);
+    /// ```
+    pub const fn try_new<const I_NOM: u64, const I_DENOM: u64>(
+        input: Rate<u32, I_NOM, I_DENOM>,
+        target: Rate<u32, NOM, DENOM>,
+        limits: PllLimits,
+        max_error_ppm: u32,
+    ) -> Option<Self> {
+        helpers::fits_u32::<I_NOM>();
+        helpers::fits_u32::<I_DENOM>();
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        if limits.m_min == 0 || limits.p_min == 0 || limits.p_step == 0 {
+            return None;
+        }
+
+        // input = a / b Hz
+        let (a, b) = Self::reduce(input.raw as u64 * I_NOM, I_DENOM);
+        // target = c / d Hz
+        let (c, d) = Self::reduce(target.raw as u64 * NOM, DENOM);
+
+        if a == 0 || b == 0 || c == 0 || d == 0 {
+            return None;
+        }
+
+        // Best (m, n, p, error) found, where the error is `|a * n * d - c * b * m * p|` which is
+        // in units of `1 / (b * m * p * d)` Hz
+        let mut best: Option<(u32, u32, u32, u128)> = None;
+
+        let mut m = limits.m_min;
+        while m <= limits.m_max {
+            // ref_min <= a / (b * m) <= ref_max
+            let bm = b * m as u128;
+            if a >= limits.ref_min.raw as u128 * bm && a <= limits.ref_max.raw as u128 * bm {
+                let mut p = limits.p_min;
+                while p <= limits.p_max {
+                    // n = c * b * m * p / (a * d), try both neighbours as the nearest one may
+                    // be out of range
+                    if let Some(cbmp) = c.checked_mul(bm * p as u128) {
+                        let n = cbmp / (a * d);
+
+                        best = Self::better(best, Self::candidate(a, b, c, d, m, n, p, &limits));
+                        best =
+                            Self::better(best, Self::candidate(a, b, c, d, m, n + 1, p, &limits));
+                    }
+
+                    if let Some((_, _, _, 0)) = best {
+                        break;
+                    }
+
+                    p = match p.checked_add(limits.p_step) {
+                        Some(p) => p,
+                        None => break,
+                    };
+                }
+            }
+
+            if let Some((_, _, _, 0)) = best {
+                break;
+            }
+
+            m = match m.checked_add(1) {
+                Some(m) => m,
+                None => break,
+            };
+        }
+
+        let (m, n, p) = match best {
+            Some((m, n, p, _)) => (m, n, p),
+            None => return None,
+        };
+
+        let out_num = a * n as u128;
+        let out_den = b * m as u128 * p as u128;
+
+        let error_ppm = match helpers::error_ppm(out_num, out_den, c, d) {
+            Some(error_ppm) if error_ppm.unsigned_abs() <= max_error_ppm => error_ppm,
+            _ => return None,
+        };
+
+        let vco = Self::round(a * n as u128, b * m as u128);
+        let rate = match (
+            out_num.checked_mul(DENOM as u128),
+            out_den.checked_mul(NOM as u128),
+        ) {
+            (Some(num), Some(den)) => Self::round(num, den),
+            _ => return None,
+        };
+
+        if vco > u32::MAX as u128 || rate > u32::MAX as u128 {
+            return None;
+        }
+
+        Some(PllConfig {
+            m,
+            n,
+            p,
+            vco: HertzU32::from_raw(vco as u32),
+            rate: Rate::<u32, NOM, DENOM>::from_raw(rate as u32),
+            error_ppm,
+        })
+    }
+
+    /// Solve for the configuration closest to `target`.
+    ///
+    /// Panics if no configuration within the limits is within `max_error_ppm` of the target
+    /// (10 000 ppm is 1 %), which fails compilation in const contexts.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// const LIMITS: PllLimits = PllLimits {
+    ///     m_min: 1,
+    ///     m_max: 8,
+    ///     n_min: 8,
+    ///     n_max: 64,
+    ///     p_min: 1,
+    ///     p_max: 8,
+    ///     p_step: 1,
+    ///     ref_min: HertzU32::MHz(1),
+    ///     ref_max: HertzU32::MHz(16),
+    ///     vco_min: HertzU32::MHz(64),
+    ///     vco_max: HertzU32::MHz(256),
+    /// };
+    ///
+    /// // Fails as the VCO can not reach 300 MHz
+    /// const PLL: PllConfig<1, 1> = PllConfig::new(HertzU32::MHz(12), HertzU32::MHz(300), LIMITS, 10_000);
+    /// ```
+    pub const fn new<const I_NOM: u64, const I_DENOM: u64>(
+        input: Rate<u32, I_NOM, I_DENOM>,
+        target: Rate<u32, NOM, DENOM>,
+        limits: PllLimits,
+        max_error_ppm: u32,
+    ) -> Self {
+        if let Some(v) = Self::try_new(input, target, limits, max_error_ppm) {
+            v
+        } else {
+            panic!("PLL configuration failed!");
+        }
+    }
+
+    const fn reduce(num: u64, den: u64) -> (u128, u128) {
+        match (
+            num.checked_div(gcd::binary_u64(num, den)),
+            den.checked_div(gcd::binary_u64(num, den)),
+        ) {
+            (Some(num), Some(den)) => (num as u128, den as u128),
+            _ => (0, 0),
+        }
+    }
+
+    const fn round(num: u128, den: u128) -> u128 {
+        (num + den / 2) / den
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    const fn candidate(
+        a: u128,
+        b: u128,
+        c: u128,
+        d: u128,
+        m: u32,
+        n: u128,
+        p: u32,
+        limits: &PllLimits,
+    ) -> Option<(u32, u32, u32, u128)> {
+        if n < limits.n_min as u128 || n > limits.n_max as u128 {
+            return None;
+        }
+
+        // vco_min <= a * n / (b * m) <= vco_max
+        let vco_num = a * n;
+        let vco_den = b * m as u128;
+        if vco_num < limits.vco_min.raw as u128 * vco_den
+            || vco_num > limits.vco_max.raw as u128 * vco_den
+        {
+            return None;
+        }
+
+        match (vco_num.checked_mul(d), c.checked_mul(vco_den * p as u128)) {
+            (Some(achieved), Some(target)) => Some((m, n as u32, p, achieved.abs_diff(target))),
+            _ => None,
+        }
+    }
+
+    const fn better(
+        best: Option<(u32, u32, u32, u128)>,
+        candidate: Option<(u32, u32, u32, u128)>,
+    ) -> Option<(u32, u32, u32, u128)> {
+        match (best, candidate) {
+            (Some((bm, _, bp, be)), Some((cm, _, cp, ce))) => {
+                // ce / (cm * cp) < be / (bm * bp)
+                let c = ce.checked_mul(bm as u128 * bp as u128);
+                let b = be.checked_mul(cm as u128 * cp as u128);
+
+                match (c, b) {
+                    (Some(c), Some(b)) if c < b => candidate,
+                    _ => best,
+                }
+            }
+            (None, _) => candidate,
+            _ => best,
+        }
+    }
+
+    /// The input divider `M`.
+    #[inline]
+    pub const fn m(&self) -> u32 {
+        self.m
+    }
+
+    /// The feedback multiplier `N`.
+    #[inline]
+    pub const fn n(&self) -> u32 {
+        self.n
+    }
+
+    /// The output divider `P`.
+    #[inline]
+    pub const fn p(&self) -> u32 {
+        self.p
+    }
+
+    /// The VCO frequency, rounded to the nearest Hz.
+    #[inline]
+    pub const fn vco(&self) -> HertzU32 {
+        self.vco
+    }
+
+    /// The achieved output rate, rounded to the nearest value in the target's base.
+    #[inline]
+    pub const fn rate(&self) -> Rate<u32, NOM, DENOM> {
+        self.rate
+    }
+
+    /// The error of the achieved output rate relative to the target, in parts per million.
+    ///
+    /// A positive error means that the output rate is too high.
+    #[inline]
+    pub const fn error_ppm(&self) -> i32 {
+        self.error_ppm
+    }
+}
@@ -0,0 +1,446 @@
+use crate::helpers;
+use crate::{Duration, Rate};
+
+/// Counter mode of a PWM timer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PwmAlignment {
+    /// The counter counts up and restarts, one period is `counts` clock ticks.
+    Edge,
+    /// The counter counts up and then down, one period is `2 * counts` clock ticks and the output
+    /// is symmetric around the center of the period.
+    Center,
+}
+
+/// Rounding mode of conversions which can not be represented exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round to the nearest value, halfway cases away from zero.
+    Nearest,
+    /// Round away from zero.
+    Up,
+}
+
+impl Rounding {
+    const fn div(self, num: u128, den: u128) -> u128 {
+        let q = num / den;
+        let r = num % den;
+
+        match self {
+            Rounding::Down => q,
+            Rounding::Nearest if 2 * r >= den => q + 1,
+            Rounding::Nearest => q,
+            Rounding::Up if r != 0 => q + 1,
+            Rounding::Up => q,
+        }
+    }
+}
+
+/// Timing model of a PWM channel, as derived from a timer clock and a PWM frequency or period.
+///
+/// The compare value for a duty cycle of 100 % is [`counts`](PwmTiming::counts), where the
+/// output is active while the counter is below the compare value. For edge-aligned mode one
+/// count is one clock tick of pulse width, for center-aligned mode it is two as the counter
+/// passes each value twice per period.
+///
+/// ```
+/// # use fugit::*;
+/// // 20 kHz edge-aligned PWM from a 72 MHz timer clock
+/// const PWM: PwmTiming = PwmTiming::from_rate(HertzU32::MHz(72), HertzU32::kHz(20), PwmAlignment::Edge);
+///
+/// assert_eq!(PWM.counts(), 3_600);
+/// assert_eq!(PWM.reload(), 3_599);
+///
+/// // 25 % duty
+/// assert_eq!(PWM.duty_to_counts(1, 4, Rounding::Nearest), Some(900));
+/// // 10 us pulse
+/// assert_eq!(PWM.pulse_to_counts(MicrosDurationU32::micros(10), Rounding::Nearest), Some(720));
+/// assert_eq!(PWM.counts_to_pulse(720, Rounding::Nearest), Some(MicrosDurationU32::micros(10)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PwmTiming {
+    /// Timer clock in Hz, numerator
+    clock_num: u64,
+    /// Timer clock in Hz, denominator
+    clock_den: u32,
+    alignment: PwmAlignment,
+    counts: u32,
+    error_ppm: i32,
+}
+
+impl PwmTiming {
+    /// Create a PWM timing with the given PWM `frequency`, rounded to the nearest number of
+    /// counts. `None` if the frequency is zero, above the clock or too low for the counter.
+    pub const fn try_from_rate<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const F_NOM: u64,
+        const F_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        frequency: Rate<u32, F_NOM, F_DENOM>,
+        alignment: PwmAlignment,
+    ) -> Option<Self> {
+        helpers::fits_u32::<F_NOM>();
+        helpers::fits_u32::<F_DENOM>();
+        // clock ticks per period = clock / frequency
+        Self::solve(
+            clock,
+            F_DENOM as u128,
+            frequency.raw as u128 * F_NOM as u128,
+            alignment,
+        )
+    }
+
+    /// Create a PWM timing with the given PWM `frequency`, rounded to the nearest number of
+    /// counts.
+    ///
+    /// Panics if the frequency can not be reached, which fails compilation in const contexts.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// // Fails as 1 Hz needs more than 2^32 counts at 72 MHz
+    /// const PWM: PwmTiming = PwmTiming::from_rate(HertzU32::MHz(72), Rate::<u32, 1, 100>::from_raw(1), PwmAlignment::Edge);
+    /// ```
+    pub const fn from_rate<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const F_NOM: u64,
+        const F_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        frequency: Rate<u32, F_NOM, F_DENOM>,
+        alignment: PwmAlignment,
+    ) -> Self {
+        if let Some(v) = Self::try_from_rate(clock, frequency, alignment) {
+            v
+        } else {
+            panic!("PWM configuration failed!");
+        }
+    }
+
+    /// Create a PWM timing with the given PWM `period`, rounded to the nearest number of counts.
+    /// `None` if the period is zero, below one clock tick or too long for the counter.
+    ///
+    /// The bases of the clock and the period must fit in 32 bits.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// // Fails as the femtosecond base does not fit in 32 bits
+    /// let pwm = PwmTiming::try_from_period(HertzU32::MHz(72), FemtosDurationU32::from_ticks(1), PwmAlignment::Edge);
+    /// ```
+    pub const fn try_from_period<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const P_NOM: u64,
+        const P_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        period: Duration<u32, P_NOM, P_DENOM>,
+        alignment: PwmAlignment,
+    ) -> Option<Self> {
+        helpers::fits_u32::<P_NOM>();
+        helpers::fits_u32::<P_DENOM>();
+        // clock ticks per period = clock * period
+        Self::solve(
+            clock,
+            period.ticks as u128 * P_NOM as u128,
+            P_DENOM as u128,
+            alignment,
+        )
+    }
+
+    /// Create a PWM timing with the given PWM `period`, rounded to the nearest number of counts.
+    ///
+    /// Panics if the period can not be reached, which fails compilation in const contexts.
+    pub const fn from_period<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const P_NOM: u64,
+        const P_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        period: Duration<u32, P_NOM, P_DENOM>,
+        alignment: PwmAlignment,
+    ) -> Self {
+        if let Some(v) = Self::try_from_period(clock, period, alignment) {
+            v
+        } else {
+            panic!("PWM configuration failed!");
+        }
+    }
+
+    /// Solve for the counts giving a period of `clock * num / den` clock ticks.
+    const fn solve<const C_NOM: u64, const C_DENOM: u64>(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        num: u128,
+        den: u128,
+        alignment: PwmAlignment,
+    ) -> Option<Self> {
+        helpers::fits_u32::<C_NOM>();
+        helpers::fits_u32::<C_DENOM>();
+        let clock_num = clock.raw as u64 * C_NOM;
+        let clock_den = C_DENOM as u32;
+
+        let ticks_num = clock_num as u128 * num;
+        let ticks_den = clock_den as u128 * den;
+
+        if ticks_num == 0 || ticks_den == 0 {
+            return None;
+        }
+
+        let scale = Self::scale(alignment) as u128;
+        let counts = Rounding::Nearest.div(ticks_num, ticks_den * scale);
+
+        if counts == 0 || counts > u32::MAX as u128 {
+            return None;
+        }
+
+        if let Some(error_ppm) = helpers::error_ppm(counts * scale * ticks_den, 1, ticks_num, 1) {
+            Some(PwmTiming {
+                clock_num,
+                clock_den,
+                alignment,
+                counts: counts as u32,
+                error_ppm,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Clock ticks of pulse width per count.
+    const fn scale(alignment: PwmAlignment) -> u32 {
+        match alignment {
+            PwmAlignment::Edge => 1,
+            PwmAlignment::Center => 2,
+        }
+    }
+
+    /// The counter mode.
+    #[inline]
+    pub const fn alignment(&self) -> PwmAlignment {
+        self.alignment
+    }
+
+    /// The compare value for a duty cycle of 100 %.
+    #[inline]
+    pub const fn counts(&self) -> u32 {
+        self.counts
+    }
+
+    /// The value to write to an up-counting auto-reload register (e.g. STM32 `ARR`), this is
+    /// `counts - 1` in edge-aligned mode and `counts` in center-aligned mode.
+    #[inline]
+    pub const fn reload(&self) -> u32 {
+        match self.alignment {
+            PwmAlignment::Edge => self.counts - 1,
+            PwmAlignment::Center => self.counts,
+        }
+    }
+
+    /// Duty cycle resolution in whole bits, i.e. `floor(log2(counts))`.
+    #[inline]
+    pub const fn resolution_bits(&self) -> u32 {
+        self.counts.ilog2()
+    }
+
+    /// The error of the achieved period relative to the requested period, in parts per million.
+    ///
+    /// A positive error means that the period is too long, i.e. the frequency is too low.
+    #[inline]
+    pub const fn error_ppm(&self) -> i32 {
+        self.error_ppm
+    }
+
+    /// The achieved PWM period, `None` if it does not fit the `Duration`.
+    pub const fn period<const NOM: u64, const DENOM: u64>(
+        &self,
+        rounding: Rounding,
+    ) -> Option<Duration<u32, NOM, DENOM>> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        self.counts_to_pulse(self.counts, rounding)
+    }
+
+    /// The achieved PWM frequency, `None` if it does not fit the `Rate`.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let pwm = PwmTiming::try_from_rate(HertzU32::MHz(16), HertzU32::kHz(7), PwmAlignment::Center).unwrap();
+    ///
+    /// assert_eq!(pwm.counts(), 1_143);
+    /// assert_eq!(pwm.frequency::<1, 1>(Rounding::Down), Some(HertzU32::Hz(6_999)));
+    /// assert_eq!(pwm.frequency::<1, 1>(Rounding::Nearest), Some(HertzU32::Hz(6_999)));
+    /// assert_eq!(pwm.frequency::<1, 1>(Rounding::Up), Some(HertzU32::Hz(7_000)));
+    /// assert_eq!(pwm.error_ppm(), 125);
+    /// ```
+    pub const fn frequency<const NOM: u64, const DENOM: u64>(
+        &self,
+        rounding: Rounding,
+    ) -> Option<Rate<u32, NOM, DENOM>> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        // frequency = clock / (scale * counts)
+        let num = self.clock_num as u128 * DENOM as u128;
+        let den = self.clock_den as u128
+            * Self::scale(self.alignment) as u128
+            * self.counts as u128
+            * NOM as u128;
+
+        let raw = rounding.div(num, den);
+        if raw > u32::MAX as u128 {
+            None
+        } else {
+            Some(Rate::<u32, NOM, DENOM>::from_raw(raw as u32))
+        }
+    }
+
+    /// The pulse width change of one count, `None` if it does not fit the `Duration`.
+    pub const fn resolution<const NOM: u64, const DENOM: u64>(
+        &self,
+        rounding: Rounding,
+    ) -> Option<Duration<u32, NOM, DENOM>> {
+        self.counts_to_pulse(1, rounding)
+    }
+
+    /// Compare value for a duty cycle of `num / den`, `None` if `den` is zero or the duty cycle
+    /// is above 100 %.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let pwm = PwmTiming::try_from_rate(HertzU32::MHz(1), HertzU32::kHz(3), PwmAlignment::Edge).unwrap();
+    ///
+    /// assert_eq!(pwm.counts(), 333);
+    /// assert_eq!(pwm.duty_to_counts(1, 2, Rounding::Down), Some(166));
+    /// assert_eq!(pwm.duty_to_counts(1, 2, Rounding::Nearest), Some(167));
+    /// assert_eq!(pwm.duty_to_counts(1, 1, Rounding::Nearest), Some(333));
+    /// assert_eq!(pwm.duty_to_counts(3, 2, Rounding::Nearest), None);
+    /// ```
+    pub const fn duty_to_counts(&self, num: u32, den: u32, rounding: Rounding) -> Option<u32> {
+        if den == 0 || num > den {
+            return None;
+        }
+
+        Some(rounding.div(self.counts as u128 * num as u128, den as u128) as u32)
+    }
+
+    /// Duty cycle of a compare value in units of `1 / den`, `None` if `den` is zero or the
+    /// compare value is above 100 %.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let pwm = PwmTiming::try_from_rate(HertzU32::MHz(1), HertzU32::kHz(3), PwmAlignment::Edge).unwrap();
+    ///
+    /// // In per mille
+    /// assert_eq!(pwm.counts_to_duty(100, 1_000, Rounding::Nearest), Some(300));
+    /// assert_eq!(pwm.counts_to_duty(334, 1_000, Rounding::Nearest), None);
+    /// ```
+    pub const fn counts_to_duty(&self, counts: u32, den: u32, rounding: Rounding) -> Option<u32> {
+        if den == 0 || counts > self.counts {
+            return None;
+        }
+
+        Some(rounding.div(counts as u128 * den as u128, self.counts as u128) as u32)
+    }
+
+    /// Compare value for a pulse width of `width`, `None` if it is longer than the period.
+    pub const fn pulse_to_counts<const NOM: u64, const DENOM: u64>(
+        &self,
+        width: Duration<u32, NOM, DENOM>,
+        rounding: Rounding,
+    ) -> Option<u32> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        // counts = width * clock / scale
+        let counts = rounding.div(
+            width.ticks as u128 * NOM as u128 * self.clock_num as u128,
+            DENOM as u128 * self.clock_den as u128 * Self::scale(self.alignment) as u128,
+        );
+
+        if counts > self.counts as u128 {
+            None
+        } else {
+            Some(counts as u32)
+        }
+    }
+
+    /// Pulse width of a compare value, `None` if the compare value is above 100 % or the pulse
+    /// width does not fit the `Duration`.
+    pub const fn counts_to_pulse<const NOM: u64, const DENOM: u64>(
+        &self,
+        counts: u32,
+        rounding: Rounding,
+    ) -> Option<Duration<u32, NOM, DENOM>> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        if counts > self.counts {
+            return None;
+        }
+
+        // width = counts * scale / clock
+        let ticks = rounding.div(
+            counts as u128
+                * Self::scale(self.alignment) as u128
+                * self.clock_den as u128
+                * DENOM as u128,
+            self.clock_num as u128 * NOM as u128,
+        );
+
+        if ticks > u32::MAX as u128 {
+            None
+        } else {
+            Some(Duration::<u32, NOM, DENOM>::from_ticks(ticks as u32))
+        }
+    }
+
+    /// Dead-time of `dead_time` in timer clock ticks, as used by dead-time generators. Unlike
+    /// compare values this does not depend on the alignment. `None` if it does not fit in `u32`.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let pwm = PwmTiming::try_from_rate(HertzU32::MHz(72), HertzU32::kHz(20), PwmAlignment::Center).unwrap();
+    ///
+    /// assert_eq!(pwm.dead_time_to_ticks(NanosDurationU32::nanos(500), Rounding::Up), Some(36));
+    /// assert_eq!(pwm.dead_time_to_ticks(NanosDurationU32::nanos(510), Rounding::Up), Some(37));
+    /// ```
+    pub const fn dead_time_to_ticks<const NOM: u64, const DENOM: u64>(
+        &self,
+        dead_time: Duration<u32, NOM, DENOM>,
+        rounding: Rounding,
+    ) -> Option<u32> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        let ticks = rounding.div(
+            dead_time.ticks as u128 * NOM as u128 * self.clock_num as u128,
+            DENOM as u128 * self.clock_den as u128,
+        );
+
+        if ticks > u32::MAX as u128 {
+            None
+        } else {
+            Some(ticks as u32)
+        }
+    }
+
+    /// Dead-time of `ticks` timer clock ticks, `None` if it does not fit the `Duration`.
+    pub const fn ticks_to_dead_time<const NOM: u64, const DENOM: u64>(
+        &self,
+        ticks: u32,
+        rounding: Rounding,
+    ) -> Option<Duration<u32, NOM, DENOM>> {
+        helpers::fits_u32::<NOM>();
+        helpers::fits_u32::<DENOM>();
+        let ticks = rounding.div(
+            ticks as u128 * self.clock_den as u128 * DENOM as u128,
+            self.clock_num as u128 * NOM as u128,
+        );
+
+        if ticks > u32::MAX as u128 {
+            None
+        } else {
+            Some(Duration::<u32, NOM, DENOM>::from_ticks(ticks as u32))
+        }
+    }
+}
@@ -1,12 +1,14 @@
 use crate::helpers::{self, Helpers};
-use crate::Duration;
+use crate::literal;
+use crate::tick_int::{factor, ExtRate, TickInt};
+use crate::{Duration, Error, ExactError};
 use core::cmp::Ordering;
 use core::convert;
 use core::ops;
 
 /// Represents a frequency.
 ///
-/// The generic `T` can either be `u32` or `u64`, and the const generics represent the ratio of the
+/// The generic `T` is a [`TickInt`](crate::TickInt), and the const generics represent the ratio of the
 /// raw contained within the rate: `rate in Hz = NOM / DENOM * raw`
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(
@@ -14,40 +16,13 @@ use core::ops;
     derive(postcard::experimental::max_size::MaxSize)
 )]
 #[derive(Clone, Copy, Debug)]
-pub struct Rate<T, const NOM: u32, const DENOM: u32> {
+pub struct Rate<T, const NOM: u64, const DENOM: u64> {
     pub(crate) raw: T,
 }
 
 macro_rules! impl_rate_for_integer {
-    ($i:ty) => {
-        impl<const NOM: u32, const DENOM: u32> Rate<$i, NOM, DENOM> {
-            /// Create a `Rate` from a raw value.
-            ///
-            /// ```
-            /// # use fugit::*;
-            #[doc = concat!("let _d = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(1);")]
-            /// ```
-            #[inline]
-            pub const fn from_raw(raw: $i) -> Self {
-                helpers::greater_than_0::<NOM>();
-                helpers::greater_than_0::<DENOM>();
-
-                Rate { raw }
-            }
-
-            /// Extract the raw value from a `Rate`.
-            ///
-            /// ```
-            /// # use fugit::*;
-            #[doc = concat!("let d = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(234);")]
-            ///
-            /// assert_eq!(d.raw(), 234);
-            /// ```
-            #[inline]
-            pub const fn raw(&self) -> $i {
-                self.raw
-            }
-
+    ($i:ty, $w:ty) => {
+        impl<const NOM: u64, const DENOM: u64> Rate<$i, NOM, DENOM> {
             /// Add two rates while checking for overflow.
             ///
             /// ```
@@ -59,31 +34,18 @@ macro_rules! impl_rate_for_integer {
             /// assert_eq!(r1.checked_add(r2).unwrap().raw(), 3);
             /// assert_eq!(r1.checked_add(r3), None);
             /// ```
-            pub const fn checked_add<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Rate<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Self::const_try_from(other) {
                     if let Some(raw) = self.raw.checked_add(other.raw) {
                         Some(Rate::<$i, NOM, DENOM>::from_raw(raw))
                     } else {
                         None
                     }
                 } else {
-                    if let Some(lh) = other
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let raw = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        if let Some(raw) = self.raw.checked_add(raw) {
-                            Some(Rate::<$i, NOM, DENOM>::from_raw(raw))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
 
@@ -98,37 +60,24 @@ macro_rules! impl_rate_for_integer {
             /// assert_eq!(r2.checked_sub(r1).unwrap().raw(), 1);
             /// assert_eq!(r1.checked_sub(r3), None);
             /// ```
-            pub const fn checked_sub<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
                 self,
                 other: Rate<$i, O_NOM, O_DENOM>,
             ) -> Option<Self> {
-                if Helpers::<NOM, DENOM, O_NOM, O_DENOM>::SAME_BASE {
+                if let Some(other) = Self::const_try_from(other) {
                     if let Some(raw) = self.raw.checked_sub(other.raw) {
                         Some(Rate::<$i, NOM, DENOM>::from_raw(raw))
                     } else {
                         None
                     }
                 } else {
-                    if let Some(lh) = other
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, O_NOM, O_DENOM>::LD_TIMES_RN as $i)
-                    {
-                        let raw = lh / Helpers::<NOM, DENOM, O_NOM, O_DENOM>::RD_TIMES_LN as $i;
-
-                        if let Some(raw) = self.raw.checked_sub(raw) {
-                            Some(Rate::<$i, NOM, DENOM>::from_raw(raw))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
+                    None
                 }
             }
 
-            #[doc = concat!("Const `cmp` for ", stringify!($i))]
+            #[doc = concat!("Const `cmp` for ", stringify!($w))]
             #[inline(always)]
-            const fn _const_cmp(a: $i, b: $i) -> Ordering {
+            const fn _const_cmp(a: $w, b: $w) -> Ordering {
                 if a < b {
                     Ordering::Less
                 } else if a > b {
@@ -138,6 +87,41 @@ macro_rules! impl_rate_for_integer {
                 }
             }
 
+            /// Returns true if this `Rate` is within `ppm` parts per million of `target`
+            /// (10 000 ppm is 1 %).
+            ///
+            /// Asserting it in a const item turns a configuration which is off target into a
+            /// compile error.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("const TARGET: Rate<", stringify!($i), ", 1, 1> = Rate::<", stringify!($i), ", 1, 1>::from_raw(100);")]
+            #[doc = concat!("const ACTUAL: Rate<", stringify!($i), ", 1, 2> = Rate::<", stringify!($i), ", 1, 2>::from_raw(203);")]
+            ///
+            /// // 1.5 % off
+            /// const _: () = assert!(ACTUAL.is_within_ppm(TARGET, 20_000));
+            /// assert!(!ACTUAL.is_within_ppm(TARGET, 10_000));
+            /// ```
+            #[inline]
+            pub const fn is_within_ppm<const T_NOM: u64, const T_DENOM: u64>(
+                &self,
+                target: Rate<$i, T_NOM, T_DENOM>,
+                ppm: u32,
+            ) -> bool {
+                let (actual, target) = match (
+                    (self.raw as u128).checked_mul(NOM as u128),
+                    (target.raw as u128).checked_mul(T_NOM as u128),
+                ) {
+                    (Some(actual), Some(target)) => (actual, target),
+                    _ => return false,
+                };
+
+                match helpers::error_ppm(actual, DENOM as u128, target, T_DENOM as u128) {
+                    Some(error_ppm) => error_ppm.unsigned_abs() <= ppm,
+                    None => false,
+                }
+            }
+
             /// Const partial comparison.
             ///
             /// ```
@@ -148,20 +132,26 @@ macro_rules! impl_rate_for_integer {
             /// assert_eq!(r1.const_partial_cmp(r2), Some(core::cmp::Ordering::Greater));
             /// ```
             #[inline]
-            pub const fn const_partial_cmp<const R_NOM: u32, const R_DENOM: u32>(
+            pub const fn const_partial_cmp<const R_NOM: u64, const R_DENOM: u64>(
                 self,
                 other: Rate<$i, R_NOM, R_DENOM>
             ) -> Option<Ordering> {
                 if Helpers::<NOM, DENOM, R_NOM, R_DENOM>::SAME_BASE {
                     // If we are in the same base, comparison in trivial
-                    Some(Self::_const_cmp(self.raw, other.raw))
+                    Some(Self::_const_cmp(self.raw as $w, other.raw as $w))
                 } else {
-                    let lh = self
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN as $i);
-                    let rh = other
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN as $i);
+                    let lh = Self::_scale(
+                        self.raw as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN,
+                        1,
+                        false,
+                    );
+                    let rh = Self::_scale(
+                        other.raw as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN,
+                        1,
+                        false,
+                    );
 
                     if let (Some(lh), Some(rh)) = (lh, rh) {
                         Some(Self::_const_cmp(lh, rh))
@@ -181,7 +171,7 @@ macro_rules! impl_rate_for_integer {
             /// assert!(r1.const_eq(r2));
             /// ```
             #[inline]
-            pub const fn const_eq<const R_NOM: u32, const R_DENOM: u32>(
+            pub const fn const_eq<const R_NOM: u64, const R_DENOM: u64>(
                 self,
                 other: Rate<$i, R_NOM, R_DENOM>
             ) -> bool {
@@ -189,12 +179,18 @@ macro_rules! impl_rate_for_integer {
                     // If we are in the same base, comparison in trivial
                     self.raw == other.raw
                 } else {
-                    let lh = self
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN as $i);
-                    let rh = other
-                        .raw
-                        .checked_mul(Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN as $i);
+                    let lh = Self::_scale(
+                        self.raw as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::RD_TIMES_LN,
+                        1,
+                        false,
+                    );
+                    let rh = Self::_scale(
+                        other.raw as $w,
+                        Helpers::<NOM, DENOM, R_NOM, R_DENOM>::LD_TIMES_RN,
+                        1,
+                        false,
+                    );
 
                     if let (Some(lh), Some(rh)) = (lh, rh) {
                         lh == rh
@@ -213,18 +209,20 @@ macro_rules! impl_rate_for_integer {
             ///
             /// assert_eq!(r2.unwrap().raw(), 10);
             /// ```
-            pub const fn const_try_from<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn const_try_from<const I_NOM: u64, const I_DENOM: u64>(
                 rate: Rate<$i, I_NOM, I_DENOM>,
             ) -> Option<Self> {
                 if Helpers::<I_NOM, I_DENOM, NOM, DENOM>::SAME_BASE {
                     Some(Self::from_raw(rate.raw))
                 } else {
-                    if let Some(lh) = (rate.raw as u64)
-                        .checked_mul(Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN)
-                    {
-                        let raw = lh / Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN;
-
-                        if raw <= <$i>::MAX as u64 {
+                    if let Some(raw) = Self::_scale(
+                        rate.raw as $w,
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN,
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN,
+                        false,
+                    ) {
+
+                        if raw <= <$i>::MAX as $w {
                             Some(Self::from_raw(raw as $i))
                         } else {
                             None
@@ -245,7 +243,7 @@ macro_rules! impl_rate_for_integer {
             /// assert_eq!(r2.unwrap().raw(), 10);
             /// ```
             #[inline]
-            pub const fn const_try_into<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Option<Rate<$i, O_NOM, O_DENOM>> {
                 Rate::<$i, O_NOM, O_DENOM>::const_try_from(self)
@@ -256,18 +254,18 @@ macro_rules! impl_rate_for_integer {
             /// ```
             /// # use fugit::*;
             #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1>::from_raw(1);")]
-            #[doc = concat!("let d1: Option<Duration::<", stringify!($i), ", 1, 1_000>> = r1.try_into_duration();")]
+            #[doc = concat!("let d1: Option<Duration::<", stringify!($i), ", 1, 100>> = r1.try_into_duration();")]
             ///
-            /// assert_eq!(d1.unwrap().ticks(), 1_000);
+            /// assert_eq!(d1.unwrap().ticks(), 100);
             /// ```
-            pub const fn try_into_duration<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn try_into_duration<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Option<Duration<$i, O_NOM, O_DENOM>> {
                 Duration::<$i, O_NOM, O_DENOM>::try_from_rate(self)
             }
 
             /// Convert from rate to duration.
-            pub const fn into_duration<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn into_duration<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Duration<$i, O_NOM, O_DENOM> {
                 if let Some(v) = self.try_into_duration() {
@@ -281,20 +279,26 @@ macro_rules! impl_rate_for_integer {
             ///
             /// ```
             /// # use fugit::*;
-            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 1_000>::from_ticks(2);")]
+            #[doc = concat!("let d1 = Duration::<", stringify!($i), ", 1, 100>::from_ticks(2);")]
             #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1>::try_from_duration(d1);")]
             ///
-            /// assert_eq!(r1.unwrap().raw(), 500);
+            /// assert_eq!(r1.unwrap().raw(), 50);
             /// ```
             #[inline]
-            pub const fn try_from_duration<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn try_from_duration<const I_NOM: u64, const I_DENOM: u64>(
                 duration: Duration<$i, I_NOM, I_DENOM>,
             ) -> Option<Self> {
                 if duration.ticks > 0 {
-                    Some(Self::from_raw(
-                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RATE_TO_DURATION_NUMERATOR as $i
-                        / duration.ticks
-                    ))
+                    let raw = Self::_inverse(
+                        Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RATE_TO_DURATION_NUMERATOR,
+                        duration.ticks as $w,
+                    );
+
+                    if raw <= <$i>::MAX as u128 {
+                        Some(Self::from_raw(raw as $i))
+                    } else {
+                        None
+                    }
                 } else {
                     None
                 }
@@ -302,7 +306,7 @@ macro_rules! impl_rate_for_integer {
 
             /// Convert from duration to rate.
             #[inline]
-            pub const fn from_duration<const I_NOM: u32, const I_DENOM: u32>(
+            pub const fn from_duration<const I_NOM: u64, const I_DENOM: u64>(
                 duration: Duration<$i, I_NOM, I_DENOM>,
             ) -> Self {
                 if let Some(v) = Self::try_from_duration(duration) {
@@ -333,7 +337,7 @@ macro_rules! impl_rate_for_integer {
             /// // Fails conversion due to overflow
             #[doc = concat!("const R2: Rate::<", stringify!($i), ", 1, 200> = R1.convert();")]
             /// ```
-            pub const fn convert<const O_NOM: u32, const O_DENOM: u32>(
+            pub const fn convert<const O_NOM: u64, const O_DENOM: u64>(
                 self,
             ) -> Rate<$i, O_NOM, O_DENOM> {
                 if let Some(v) = self.const_try_into() {
@@ -343,282 +347,759 @@ macro_rules! impl_rate_for_integer {
                 }
             }
 
+            /// Convert between bases for a rate, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 100>::from_raw(1);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 100>::from_raw(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(r1.try_convert::<1, 1_000>().unwrap().raw(), 10);
+            /// assert_eq!(r2.try_convert::<1, 1_000>(), Err(Error::Overflow));
+            /// ```
+            #[inline]
+            pub const fn try_convert<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Rate<$i, O_NOM, O_DENOM>, Error> {
+                if let Some(v) = self.const_try_into() {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
+            }
+
+            /// Const try from, succeeding only if the base change is exact.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(250);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 100>::exact_try_from(r1);")]
+            #[doc = concat!("let r3 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(255);")]
+            #[doc = concat!("let r4 = Rate::<", stringify!($i), ", 1, 100>::exact_try_from(r3);")]
+            ///
+            /// assert_eq!(r2.unwrap().raw(), 25);
+            /// assert_eq!(
+            ///     r4,
+            ///     Err(ExactError::PrecisionLoss {
+            #[doc = concat!("        truncated: Rate::<", stringify!($i), ", 1, 100>::from_raw(25),")]
+            ///         remainder: 5,
+            ///         denom: 10,
+            ///     })
+            /// );
+            /// ```
+            pub const fn exact_try_from<const I_NOM: u64, const I_DENOM: u64>(
+                rate: Rate<$i, I_NOM, I_DENOM>,
+            ) -> Result<Self, ExactError<Self>> {
+                let prod = match Self::_scale(
+                    rate.raw as $w,
+                    Helpers::<I_NOM, I_DENOM, NOM, DENOM>::RD_TIMES_LN,
+                    1,
+                    false,
+                ) {
+                    Some(prod) => prod,
+                    None => return Err(ExactError::Overflow),
+                };
+                let denom = Helpers::<I_NOM, I_DENOM, NOM, DENOM>::LD_TIMES_RN;
+
+                // `_scale` can not fail when dividing, and gives zero for a divisor which does not
+                // fit the intermediate
+                let quotient = match Self::_scale(prod, 1, denom, false) {
+                    Some(quotient) => quotient,
+                    None => 0,
+                };
+                let remainder = prod - quotient * denom as $w;
+
+                if quotient > <$i>::MAX as $w {
+                    Err(ExactError::Overflow)
+                } else if remainder != 0 {
+                    Err(ExactError::PrecisionLoss {
+                        truncated: Self::from_raw(quotient as $i),
+                        remainder: remainder as u128,
+                        denom,
+                    })
+                } else {
+                    Ok(Self::from_raw(quotient as $i))
+                }
+            }
+
+            /// Convert between bases, succeeding only if the base change is exact.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(250);")]
+            ///
+            /// assert_eq!(r1.exact_convert::<1, 100>().unwrap().raw(), 25);
+            /// assert!(r1.exact_convert::<1, 1>().is_err());
+            /// ```
+            #[inline]
+            pub const fn exact_convert<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Rate<$i, O_NOM, O_DENOM>, ExactError<Rate<$i, O_NOM, O_DENOM>>> {
+                Rate::<$i, O_NOM, O_DENOM>::exact_try_from(self)
+            }
+
+            /// Convert from a base where a step is a whole number of steps in this base, which
+            /// can not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 100>::from_raw(25);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 1_000>::exact_from(r1);")]
+            ///
+            /// assert_eq!(r2.raw(), 250);
+            /// ```
+            ///
+            /// ```compile_fail
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(250);")]
+            /// // Fails as a step of 1 mHz is not a whole number of steps of 10 mHz
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 100>::exact_from(r1);")]
+            /// ```
+            #[inline]
+            pub const fn exact_from<const I_NOM: u64, const I_DENOM: u64>(
+                rate: Rate<$i, I_NOM, I_DENOM>,
+            ) -> Self {
+                helpers::exact::<I_NOM, I_DENOM, NOM, DENOM>();
+
+                if let Some(v) = Self::const_try_from(rate) {
+                    v
+                } else {
+                    panic!("Exact conversion failed, overflow!");
+                }
+            }
+
+            /// Convert to a base where a step of this base is a whole number of steps, which can
+            /// not lose precision. Other bases fail compilation.
+            ///
+            /// Panics on overflow, which fails compilation in const contexts.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 100>::from_raw(25);")]
+            ///
+            /// assert_eq!(r1.exact_into::<1, 1_000>().raw(), 250);
+            /// ```
+            #[inline]
+            pub const fn exact_into<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Rate<$i, O_NOM, O_DENOM> {
+                Rate::<$i, O_NOM, O_DENOM>::exact_from(self)
+            }
+
+            /// Add two rates, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(1);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(", stringify!($i), "::MAX);")]
+            ///
+            /// assert_eq!(r1.try_add(r1).unwrap().raw(), 2);
+            /// assert_eq!(r1.try_add(r2), Err(Error::Overflow));
+            /// ```
+            #[inline]
+            pub const fn try_add<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Rate<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
+                if let Some(v) = self.checked_add(other) {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
+            }
+
+            /// Subtract two rates, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(1);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 1_000>::from_raw(2);")]
+            ///
+            /// assert_eq!(r2.try_sub(r1).unwrap().raw(), 1);
+            /// assert_eq!(r1.try_sub(r2), Err(Error::Underflow));
+            /// ```
+            #[inline]
+            pub const fn try_sub<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+                other: Rate<$i, O_NOM, O_DENOM>,
+            ) -> Result<Self, Error> {
+                // A subtrahend which does not fit this base is larger than any rate in it
+                if let Some(v) = self.checked_sub(other) {
+                    Ok(v)
+                } else {
+                    Err(Error::Underflow)
+                }
+            }
+
+            /// Convert from rate to duration, with the reason on failure.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let r1 = Rate::<", stringify!($i), ", 1, 1>::from_raw(50);")]
+            #[doc = concat!("let r2 = Rate::<", stringify!($i), ", 1, 1>::from_raw(0);")]
+            ///
+            /// assert_eq!(r1.try_to_duration::<1, 100>().unwrap().ticks(), 2);
+            /// assert_eq!(r2.try_to_duration::<1, 100>(), Err(Error::DivideByZero));
+            /// ```
+            #[inline]
+            pub const fn try_to_duration<const O_NOM: u64, const O_DENOM: u64>(
+                self,
+            ) -> Result<Duration<$i, O_NOM, O_DENOM>, Error> {
+                if self.raw == 0 {
+                    Err(Error::DivideByZero)
+                } else if let Some(v) = self.try_into_duration() {
+                    Ok(v)
+                } else {
+                    Err(Error::Overflow)
+                }
+            }
+
+            #[doc = concat!("Narrow a `", stringify!($w), "` intermediate, panics on overflow")]
+            #[inline(always)]
+            const fn _narrow(val: Option<$w>) -> $i {
+                match val {
+                    Some(val) if val <= <$i>::MAX as $w => val as $i,
+                    _ => panic!("Overflow!"),
+                }
+            }
+
+            /// `val * mul / div` in the intermediate, rounded up if `ceil` and `None` on overflow.
+            /// The `u128` constants of [`Helpers`] do not need to fit the intermediate.
+            ///
+            /// When the constants fit the backing integer the product is first tried at its native
+            /// width, so the wider (and on 32-bit targets much slower) intermediate arithmetic is
+            /// only used if the native product overflows. As `mul` and `div` are constants this
+            /// is folded at compile time, and a `mul` of 1 never reaches the wide path.
+            #[inline(always)]
+            const fn _scale(val: $w, mul: u128, div: u128, ceil: bool) -> Option<$w> {
+                if val <= <$i>::MAX as $w
+                    && mul <= <$i>::MAX as u128
+                    && div != 0
+                    && div <= <$i>::MAX as u128
+                {
+                    if let Some(prod) = (val as $i).checked_mul(mul as $i) {
+                        let div = div as $i;
+                        let quotient = if ceil && prod % div != 0 {
+                            prod / div + 1
+                        } else {
+                            prod / div
+                        };
+                        return Some(quotient as $w);
+                    }
+                }
+
+                let prod = if mul as $w as u128 == mul {
+                    match val.checked_mul(mul as $w) {
+                        Some(prod) => prod,
+                        None => return None,
+                    }
+                } else if val == 0 {
+                    0
+                } else {
+                    return None;
+                };
+
+                if div as $w as u128 != div {
+                    // The divisor is larger than any product
+                    Some((ceil && prod != 0) as $w)
+                } else if ceil && prod % div as $w != 0 {
+                    Some(prod / div as $w + 1)
+                } else {
+                    Some(prod / div as $w)
+                }
+            }
+
+            /// `num / val` for a `u128` constant `num`, in the intermediate if `num` fits.
+            #[inline(always)]
+            const fn _inverse(num: u128, val: $w) -> u128 {
+                if num as $w as u128 == num {
+                    (num as $w / val) as u128
+                } else {
+                    num / val as u128
+                }
+            }
+
+            /// Used by the literal macros, panics if the literal is not exact in this base.
+            #[doc(hidden)]
+            pub const fn __from_literal(lit: &str, unit: (u64, u64)) -> Self {
+                let val = literal::ticks(lit, unit, NOM, DENOM);
+
+                if val > <$i>::MAX as u128 {
+                    panic!("The value does not fit the backing integer");
+                }
+
+                Self::from_raw(val as $i)
+            }
+
             /// Convert the Rate to an interger number of Hz.
             #[inline]
             #[allow(non_snake_case)]
             pub const fn to_Hz(&self) -> $i {
-                    (Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN as $i * self.raw)
-                        / Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN as $i
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
+                )
             }
 
             /// Convert the Rate to an interger number of kHz.
             #[inline]
             #[allow(non_snake_case)]
             pub const fn to_kHz(&self) -> $i {
-                    (Helpers::<1_000, 1, NOM, DENOM>::LD_TIMES_RN as $i * self.raw)
-                        / Helpers::<1_000, 1, NOM, DENOM>::RD_TIMES_LN as $i
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
+                )
             }
 
             /// Convert the Rate to an interger number of MHz.
             #[inline]
             #[allow(non_snake_case)]
             pub const fn to_MHz(&self) -> $i {
-                    (Helpers::<1_000_000, 1, NOM, DENOM>::LD_TIMES_RN as $i * self.raw)
-                        / Helpers::<1_000_000, 1, NOM, DENOM>::RD_TIMES_LN as $i
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1_000_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1_000_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
+                )
             }
 
-            /// Shorthand for creating a rate which represents hertz.
+            /// Convert the Rate to an integer number of GHz.
             #[inline]
             #[allow(non_snake_case)]
-            pub const fn Hz(val: $i) -> Self {
-                Self::from_raw(
-                    (Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN as $i * val)
-                        / Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN as $i,
+            pub const fn to_GHz(&self) -> $i {
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1_000_000_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1_000_000_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
                 )
             }
 
-            /// Shorthand for creating a rate which represents kilohertz.
+            /// Convert the Rate to an integer number of mHz.
             #[inline]
             #[allow(non_snake_case)]
-            pub const fn kHz(val: $i) -> Self {
-                Self::from_raw(
-                    (Helpers::<1_000, 1, NOM, DENOM>::RD_TIMES_LN as $i * val)
-                        / Helpers::<1_000, 1, NOM, DENOM>::LD_TIMES_RN as $i,
+            pub const fn to_mHz(&self) -> $i {
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1, 1_000, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1, 1_000, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
                 )
             }
 
-            /// Shorthand for creating a rate which represents megahertz.
+            /// Convert the Rate to an integer number of revolutions per minute.
             #[inline]
-            #[allow(non_snake_case)]
-            pub const fn MHz(val: $i) -> Self {
-                Self::from_raw(
-                    (Helpers::<1_000_000, 1, NOM, DENOM>::RD_TIMES_LN as $i * val)
-                        / Helpers::<1_000_000, 1, NOM, DENOM>::LD_TIMES_RN as $i,
+            pub const fn to_rpm(&self) -> $i {
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1, 60, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1, 60, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
                 )
             }
 
-            /// Shorthand for creating a rate which represents nanoseconds.
+            /// Convert the Rate to an integer number of bits per second.
             #[inline]
-            pub const fn nanos(val: $i) -> Self {
-                Self::from_duration(crate::Duration::<$i, 1, 1_000_000_000>::from_ticks(val))
+            pub const fn to_bps(&self) -> $i {
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
+                )
             }
 
-            /// Shorthand for creating a rate which represents microseconds.
+            /// Convert the Rate to an integer number of baud.
             #[inline]
-            pub const fn micros(val: $i) -> Self {
-                Self::from_duration(crate::Duration::<$i, 1, 1_000_000>::from_ticks(val))
+            pub const fn to_baud(&self) -> $i {
+                Self::_narrow(
+                    Self::_scale(
+                        self.raw as $w,
+                        Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                        Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                        false,
+                    ),
+                )
             }
 
-            /// Shorthand for creating a rate which represents milliseconds.
+            /// Shorthand for creating a rate which represents hertz.
             #[inline]
-            pub const fn millis(val: $i) -> Self {
-                Self::from_duration(crate::Duration::<$i, 1, 1_000>::from_ticks(val))
+            #[allow(non_snake_case)]
+            pub const fn Hz(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
 
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
-            PartialOrd<Rate<$i, R_NOM, R_DENOM>> for Rate<$i, L_NOM, L_DENOM>
-        {
+            /// Shorthand for creating a rate which represents kilohertz.
             #[inline]
-            fn partial_cmp(&self, other: &Rate<$i, R_NOM, R_DENOM>) -> Option<Ordering> {
-                self.const_partial_cmp(*other)
+            #[allow(non_snake_case)]
+            pub const fn kHz(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
 
-        impl<const NOM: u32, const DENOM: u32> Ord for Rate<$i, NOM, DENOM> {
+            /// Shorthand for creating a rate which represents megahertz.
             #[inline]
-            fn cmp(&self, other: &Self) -> Ordering {
-                Self::_const_cmp(self.raw, other.raw)
+            #[allow(non_snake_case)]
+            pub const fn MHz(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1_000_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1_000_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
 
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
-            PartialEq<Rate<$i, R_NOM, R_DENOM>> for Rate<$i, L_NOM, L_DENOM>
-        {
+            /// Shorthand for creating a rate which represents gigahertz.
             #[inline]
-            fn eq(&self, other: &Rate<$i, R_NOM, R_DENOM>) -> bool {
-                self.const_eq(*other)
+            #[allow(non_snake_case)]
+            pub const fn GHz(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1_000_000_000, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1_000_000_000, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
-
-        impl<const NOM: u32, const DENOM: u32> Eq for Rate<$i, NOM, DENOM> {}
-
-        // Rate - Rate = Rate (only same base until const_generics_defaults is
-        // stabilized)
-        impl<const NOM: u32, const DENOM: u32> ops::Sub<Rate<$i, NOM, DENOM>>
-            for Rate<$i, NOM, DENOM>
-        {
-            type Output = Rate<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a rate which represents millihertz.
             #[inline]
-            fn sub(self, other: Rate<$i, NOM, DENOM>) -> Self::Output {
-                if let Some(v) = self.checked_sub(other) {
-                    v
-                } else {
-                    panic!("Sub failed!");
-                }
+            #[allow(non_snake_case)]
+            pub const fn mHz(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1, 1_000, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1, 1_000, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
-
-        // Rate + Rate = Rate (only same base until const_generics_defaults is
-        // stabilized)
-        impl<const NOM: u32, const DENOM: u32> ops::Add<Rate<$i, NOM, DENOM>>
-            for Rate<$i, NOM, DENOM>
-        {
-            type Output = Rate<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a rate which represents revolutions per minute.
             #[inline]
-            fn add(self, other: Rate<$i, NOM, DENOM>) -> Self::Output {
-                if let Some(v) = self.checked_add(other) {
-                    v
-                } else {
-                    panic!("Add failed!");
-                }
+            pub const fn rpm(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1, 60, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1, 60, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
 
-        // Rate += Rate
-        impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Rate<$i, NOM, DENOM>>
-            for Rate<$i, NOM, DENOM>
-        {
+            /// Shorthand for creating a rate which represents bits per second.
             #[inline]
-            fn add_assign(&mut self, other: Self) {
-                *self = *self + other;
+            pub const fn bps(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
-
-        // integer * Rate = Rate
-        impl<const NOM: u32, const DENOM: u32> ops::Mul<Rate<$i, NOM, DENOM>> for u32 {
-            type Output = Rate<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a rate which represents baud.
             #[inline]
-            fn mul(self, mut other: Rate<$i, NOM, DENOM>) -> Self::Output {
-                other.raw *= self as $i;
-                other
+            pub const fn baud(val: $i) -> Self {
+                Self::from_raw(Self::_narrow(Self::_scale(
+                    val as $w,
+                    Helpers::<1, 1, NOM, DENOM>::RD_TIMES_LN,
+                    Helpers::<1, 1, NOM, DENOM>::LD_TIMES_RN,
+                    false,
+                )))
             }
-        }
-
-        // Rate * integer = Rate
-        impl<const NOM: u32, const DENOM: u32> ops::Mul<u32> for Rate<$i, NOM, DENOM> {
-            type Output = Rate<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a rate which represents nanoseconds.
             #[inline]
-            fn mul(mut self, other: u32) -> Self::Output {
-                self.raw *= other as $i;
-                self
+            pub const fn nanos(val: $i) -> Self {
+                Self::from_duration(crate::Duration::<$i, 1, 1_000_000_000>::from_ticks(val))
             }
-        }
 
-        // Rate *= integer
-        impl<const NOM: u32, const DENOM: u32> ops::MulAssign<u32>
-            for Rate<$i, NOM, DENOM>
-        {
+            /// Shorthand for creating a rate which represents microseconds.
             #[inline]
-            fn mul_assign(&mut self, other: u32) {
-                *self = *self * other;
+            pub const fn micros(val: $i) -> Self {
+                Self::from_duration(crate::Duration::<$i, 1, 1_000_000>::from_ticks(val))
             }
-        }
-
-        // Rate / integer = Rate
-        impl<const NOM: u32, const DENOM: u32> ops::Div<u32> for Rate<$i, NOM, DENOM> {
-            type Output = Rate<$i, NOM, DENOM>;
 
+            /// Shorthand for creating a rate which represents milliseconds.
             #[inline]
-            fn div(mut self, other: u32) -> Self::Output {
-                self.raw /= other as $i;
-                self
+            pub const fn millis(val: $i) -> Self {
+                Self::from_duration(crate::Duration::<$i, 1, 1_000>::from_ticks(val))
             }
         }
+    };
+}
 
-        // Rate / Rate = integer
-        impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32> ops::Div<Rate<$i, R_NOM, R_DENOM>>
-            for Rate<$i, L_NOM, L_DENOM>
-        {
-            type Output = $i;
+impl_rate_for_integer!(u8, u64);
+impl_rate_for_integer!(u16, u64);
+impl_rate_for_integer!(u32, u64);
+impl_rate_for_integer!(u64, u128);
+impl_rate_for_integer!(u128, u128);
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Rate<T, NOM, DENOM> {
+    /// Create a `Rate` from a raw value.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let _d = Rate::<u32, 1, 1_000>::from_raw(1);
+    /// ```
+    #[inline]
+    pub const fn from_raw(raw: T) -> Self {
+        helpers::greater_than_0::<NOM>();
+        helpers::greater_than_0::<DENOM>();
 
-            #[inline]
-            fn div(self, other: Rate<$i, R_NOM, R_DENOM>) -> Self::Output {
-                let conv: Rate<$i, R_NOM, R_DENOM> = self.convert();
-                conv.raw / other.raw
-            }
-        }
+        Rate { raw }
+    }
 
-        // Rate /= integer
-        impl<const NOM: u32, const DENOM: u32> ops::DivAssign<u32>
-            for Rate<$i, NOM, DENOM>
-        {
-            #[inline]
-            fn div_assign(&mut self, other: u32) {
-                *self = *self / other;
-            }
+    /// Extract the raw value from a `Rate`.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let d = Rate::<u32, 1, 1_000>::from_raw(234);
+    ///
+    /// assert_eq!(d.raw(), 234);
+    /// ```
+    #[inline]
+    pub const fn raw(&self) -> T {
+        self.raw
+    }
+}
+
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    PartialOrd<Rate<T, R_NOM, R_DENOM>> for Rate<T, L_NOM, L_DENOM>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Rate<T, R_NOM, R_DENOM>) -> Option<Ordering> {
+        T::rate_partial_cmp(*self, *other)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Ord for Rate<T, NOM, DENOM> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    PartialEq<Rate<T, R_NOM, R_DENOM>> for Rate<T, L_NOM, L_DENOM>
+{
+    #[inline]
+    fn eq(&self, other: &Rate<T, R_NOM, R_DENOM>) -> bool {
+        T::rate_eq(*self, *other)
+    }
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> Eq for Rate<T, NOM, DENOM> {}
+
+// Rate - Rate = Rate (only same base until const_generics_defaults is
+// stabilized)
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Sub<Rate<T, NOM, DENOM>>
+    for Rate<T, NOM, DENOM>
+{
+    type Output = Rate<T, NOM, DENOM>;
+
+    #[inline]
+    fn sub(self, other: Rate<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_sub(other) {
+            v
+        } else {
+            panic!("Sub failed!");
         }
+    }
+}
 
-        #[cfg(feature = "defmt")]
-        impl<const NOM: u32, const DENOM: u32> defmt::Format for Rate<$i, NOM, DENOM>
-        {
-            fn format(&self, f: defmt::Formatter) {
-                if NOM == 1 && DENOM == 1 {
-                    defmt::write!(f, "{} Hz", self.raw)
-                } else if NOM == 1_000 && DENOM == 1 {
-                    defmt::write!(f, "{} kHz", self.raw)
-                } else if NOM == 1_000_000 && DENOM == 1 {
-                    defmt::write!(f, "{} MHz", self.raw)
-                } else if NOM == 1_000_000_000 && DENOM == 1 {
-                    defmt::write!(f, "{} GHz", self.raw)
-                } else {
-                    defmt::write!(f, "{} raw @ ({}/{})", self.raw, NOM, DENOM)
-                }
-            }
+// Rate + Rate = Rate (only same base until const_generics_defaults is
+// stabilized)
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Add<Rate<T, NOM, DENOM>>
+    for Rate<T, NOM, DENOM>
+{
+    type Output = Rate<T, NOM, DENOM>;
+
+    #[inline]
+    fn add(self, other: Rate<T, NOM, DENOM>) -> Self::Output {
+        if let Some(v) = self.checked_add(other) {
+            v
+        } else {
+            panic!("Add failed!");
         }
+    }
+}
 
-        impl<const NOM: u32, const DENOM: u32> core::fmt::Display for Rate<$i, NOM, DENOM> {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if NOM == 1 && DENOM == 1 {
-                    write!(f, "{} Hz", self.raw)
-                } else if NOM == 1_000 && DENOM == 1 {
-                    write!(f, "{} kHz", self.raw)
-                } else if NOM == 1_000_000 && DENOM == 1 {
-                    write!(f, "{} MHz", self.raw)
-                } else if NOM == 1_000_000_000 && DENOM == 1 {
-                    write!(f, "{} GHz", self.raw)
-                } else {
-                    write!(f, "{} raw @ ({}/{})", self.raw, NOM, DENOM)
-                }
-            }
+// Rate += Rate
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::AddAssign<Rate<T, NOM, DENOM>>
+    for Rate<T, NOM, DENOM>
+{
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+// integer * Rate = Rate
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Mul<Rate<T, NOM, DENOM>> for u32 {
+    type Output = Rate<T, NOM, DENOM>;
+
+    #[inline]
+    fn mul(self, mut other: Rate<T, NOM, DENOM>) -> Self::Output {
+        other.raw = other.raw * factor::<T>(self);
+        other
+    }
+}
+
+// Rate * integer = Rate
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Mul<u32> for Rate<T, NOM, DENOM> {
+    type Output = Rate<T, NOM, DENOM>;
+
+    #[inline]
+    fn mul(mut self, other: u32) -> Self::Output {
+        self.raw = self.raw * factor::<T>(other);
+        self
+    }
+}
+
+// Rate *= integer
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::MulAssign<u32> for Rate<T, NOM, DENOM> {
+    #[inline]
+    fn mul_assign(&mut self, other: u32) {
+        *self = *self * other;
+    }
+}
+
+// Rate / integer = Rate
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::Div<u32> for Rate<T, NOM, DENOM> {
+    type Output = Rate<T, NOM, DENOM>;
+
+    #[inline]
+    fn div(mut self, other: u32) -> Self::Output {
+        // A divisor larger than the backing integer always gives 0
+        self.raw = match T::from_u128(other as u128) {
+            Some(other) => self.raw / other,
+            None => T::ZERO,
+        };
+        self
+    }
+}
+
+// Rate / Rate = integer
+impl<T: TickInt, const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
+    ops::Div<Rate<T, R_NOM, R_DENOM>> for Rate<T, L_NOM, L_DENOM>
+{
+    type Output = T;
+
+    #[inline]
+    fn div(self, other: Rate<T, R_NOM, R_DENOM>) -> Self::Output {
+        let conv: Rate<T, R_NOM, R_DENOM> = self.convert();
+        conv.raw / other.raw
+    }
+}
+
+// Rate /= integer
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ops::DivAssign<u32> for Rate<T, NOM, DENOM> {
+    #[inline]
+    fn div_assign(&mut self, other: u32) {
+        *self = *self / other;
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: TickInt + defmt::Format, const NOM: u64, const DENOM: u64> defmt::Format
+    for Rate<T, NOM, DENOM>
+{
+    // `defmt::write!` interns the format string, which clippy cannot see
+    #[allow(clippy::if_same_then_else)]
+    fn format(&self, f: defmt::Formatter) {
+        if NOM == 1 && DENOM == 1 {
+            defmt::write!(f, "{} Hz", self.raw)
+        } else if NOM == 1_000 && DENOM == 1 {
+            defmt::write!(f, "{} kHz", self.raw)
+        } else if NOM == 1_000_000 && DENOM == 1 {
+            defmt::write!(f, "{} MHz", self.raw)
+        } else if NOM == 1_000_000_000 && DENOM == 1 {
+            defmt::write!(f, "{} GHz", self.raw)
+        } else if NOM == 1 && DENOM == 1_000 {
+            defmt::write!(f, "{} mHz", self.raw)
+        } else if NOM == 1 && DENOM == 60 {
+            defmt::write!(f, "{} rpm", self.raw)
+        } else {
+            defmt::write!(f, "{} raw @ ({}/{})", self.raw, NOM, DENOM)
         }
-    };
+    }
 }
 
-impl_rate_for_integer!(u32);
-impl_rate_for_integer!(u64);
+impl<T: TickInt, const NOM: u64, const DENOM: u64> core::fmt::Display for Rate<T, NOM, DENOM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if NOM == 1 && DENOM == 1 {
+            write!(f, "{} Hz", self.raw)
+        } else if NOM == 1_000 && DENOM == 1 {
+            write!(f, "{} kHz", self.raw)
+        } else if NOM == 1_000_000 && DENOM == 1 {
+            write!(f, "{} MHz", self.raw)
+        } else if NOM == 1_000_000_000 && DENOM == 1 {
+            write!(f, "{} GHz", self.raw)
+        } else if NOM == 1 && DENOM == 1_000 {
+            write!(f, "{} mHz", self.raw)
+        } else if NOM == 1 && DENOM == 60 {
+            write!(f, "{} rpm", self.raw)
+        } else {
+            write!(f, "{} raw @ ({}/{})", self.raw, NOM, DENOM)
+        }
+    }
+}
 
 //
 // Operations between u32 and u64 Rate
 //
 
-impl<const NOM: u32, const DENOM: u32> From<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
+impl<const NOM: u64, const DENOM: u64> From<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
     #[inline]
     fn from(val: Rate<u32, NOM, DENOM>) -> Rate<u64, NOM, DENOM> {
         Rate::<u64, NOM, DENOM>::from_raw(val.raw() as u64)
     }
 }
 
-impl<const NOM: u32, const DENOM: u32> convert::TryFrom<Rate<u64, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> convert::TryFrom<Rate<u64, NOM, DENOM>>
     for Rate<u32, NOM, DENOM>
 {
-    type Error = ();
+    type Error = Error;
 
     #[inline]
-    fn try_from(val: Rate<u64, NOM, DENOM>) -> Result<Rate<u32, NOM, DENOM>, ()> {
+    fn try_from(val: Rate<u64, NOM, DENOM>) -> Result<Rate<u32, NOM, DENOM>, Error> {
         Ok(Rate::<u32, NOM, DENOM>::from_raw(
-            val.raw().try_into().map_err(|_| ())?,
+            val.raw().try_into().map_err(|_| Error::Overflow)?,
         ))
     }
 }
 
 // Rate - Rate = Rate (to make shorthands work, until const_generics_defaults is
 // stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::Sub<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
+impl<const NOM: u64, const DENOM: u64> ops::Sub<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
     type Output = Rate<u64, NOM, DENOM>;
 
     #[inline]
@@ -632,7 +1113,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Sub<Rate<u32, NOM, DENOM>> for Rate<
 }
 
 // Rate -= Rate (to make shorthands work, until const_generics_defaults is stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Rate<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::SubAssign<Rate<u32, NOM, DENOM>>
     for Rate<u64, NOM, DENOM>
 {
     #[inline]
@@ -643,7 +1124,7 @@ impl<const NOM: u32, const DENOM: u32> ops::SubAssign<Rate<u32, NOM, DENOM>>
 
 // Rate + Rate = Rate (to make shorthands work, until const_generics_defaults is
 // stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::Add<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
+impl<const NOM: u64, const DENOM: u64> ops::Add<Rate<u32, NOM, DENOM>> for Rate<u64, NOM, DENOM> {
     type Output = Rate<u64, NOM, DENOM>;
 
     #[inline]
@@ -657,7 +1138,7 @@ impl<const NOM: u32, const DENOM: u32> ops::Add<Rate<u32, NOM, DENOM>> for Rate<
 }
 
 // Rate += Rate (to make shorthands work, until const_generics_defaults is stabilized)
-impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Rate<u32, NOM, DENOM>>
+impl<const NOM: u64, const DENOM: u64> ops::AddAssign<Rate<u32, NOM, DENOM>>
     for Rate<u64, NOM, DENOM>
 {
     #[inline]
@@ -666,7 +1147,7 @@ impl<const NOM: u32, const DENOM: u32> ops::AddAssign<Rate<u32, NOM, DENOM>>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialOrd<Rate<u32, R_NOM, R_DENOM>> for Rate<u64, L_NOM, L_DENOM>
 {
     #[inline]
@@ -675,7 +1156,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialEq<Rate<u32, R_NOM, R_DENOM>> for Rate<u64, L_NOM, L_DENOM>
 {
     #[inline]
@@ -684,7 +1165,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialOrd<Rate<u64, R_NOM, R_DENOM>> for Rate<u32, L_NOM, L_DENOM>
 {
     #[inline]
@@ -693,7 +1174,7 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
-impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
+impl<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>
     PartialEq<Rate<u64, R_NOM, R_DENOM>> for Rate<u32, L_NOM, L_DENOM>
 {
     #[inline]
@@ -702,72 +1183,197 @@ impl<const L_NOM: u32, const L_DENOM: u32, const R_NOM: u32, const R_DENOM: u32>
     }
 }
 
+//
+// Widening and narrowing between the other backing integers
+//
+
+macro_rules! impl_rate_widening {
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl<const NOM: u64, const DENOM: u64> From<Rate<$from, NOM, DENOM>>
+                for Rate<$to, NOM, DENOM>
+            {
+                #[inline]
+                fn from(val: Rate<$from, NOM, DENOM>) -> Rate<$to, NOM, DENOM> {
+                    Rate::<$to, NOM, DENOM>::from_raw(val.raw() as $to)
+                }
+            }
+
+            impl<const NOM: u64, const DENOM: u64> convert::TryFrom<Rate<$to, NOM, DENOM>>
+                for Rate<$from, NOM, DENOM>
+            {
+                type Error = Error;
+
+                #[inline]
+                fn try_from(val: Rate<$to, NOM, DENOM>) -> Result<Rate<$from, NOM, DENOM>, Error> {
+                    Ok(Rate::<$from, NOM, DENOM>::from_raw(
+                        val.raw().try_into().map_err(|_| Error::Overflow)?,
+                    ))
+                }
+            }
+        )+
+    };
+}
+
+impl_rate_widening!(u8 => u16, u32, u64, u128);
+impl_rate_widening!(u16 => u32, u64, u128);
+impl_rate_widening!(u32 => u128);
+impl_rate_widening!(u64 => u128);
+
 /// Extension trait for simple short-hands for u32 Rate
 pub trait ExtU32 {
     /// Shorthand for creating a rate which represents hertz.
     #[allow(non_snake_case)]
-    fn Hz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM>;
+    fn Hz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
 
     /// Shorthand for creating a rate which represents kilohertz.
     #[allow(non_snake_case)]
-    fn kHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM>;
+    fn kHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
 
     /// Shorthand for creating a rate which represents megahertz.
     #[allow(non_snake_case)]
-    fn MHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM>;
+    fn MHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents gigahertz.
+    #[allow(non_snake_case)]
+    fn GHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents millihertz.
+    #[allow(non_snake_case)]
+    fn mHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents revolutions per minute.
+    fn rpm<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents bits per second.
+    fn bps<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents baud.
+    fn baud<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM>;
 }
 
 impl ExtU32 for u32 {
     #[inline]
     #[allow(non_snake_case)]
-    fn Hz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM> {
+    fn Hz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
         Rate::<u32, NOM, DENOM>::Hz(self)
     }
 
     #[inline]
     #[allow(non_snake_case)]
-    fn kHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM> {
+    fn kHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
         Rate::<u32, NOM, DENOM>::kHz(self)
     }
 
     #[inline]
     #[allow(non_snake_case)]
-    fn MHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u32, NOM, DENOM> {
+    fn MHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
         Rate::<u32, NOM, DENOM>::MHz(self)
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn GHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
+        Rate::<u32, NOM, DENOM>::GHz(self)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn mHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
+        Rate::<u32, NOM, DENOM>::mHz(self)
+    }
+
+    #[inline]
+    fn rpm<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
+        Rate::<u32, NOM, DENOM>::rpm(self)
+    }
+
+    #[inline]
+    fn bps<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
+        Rate::<u32, NOM, DENOM>::bps(self)
+    }
+
+    #[inline]
+    fn baud<const NOM: u64, const DENOM: u64>(self) -> Rate<u32, NOM, DENOM> {
+        Rate::<u32, NOM, DENOM>::baud(self)
+    }
 }
 
 /// Extension trait for simple short-hands for u64 Rate
 pub trait ExtU64 {
     /// Shorthand for creating a rate which represents hertz.
     #[allow(non_snake_case)]
-    fn Hz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM>;
+    fn Hz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
 
     /// Shorthand for creating a rate which represents kilohertz.
     #[allow(non_snake_case)]
-    fn kHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM>;
+    fn kHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
 
     /// Shorthand for creating a rate which represents megahertz.
     #[allow(non_snake_case)]
-    fn MHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM>;
+    fn MHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents gigahertz.
+    #[allow(non_snake_case)]
+    fn GHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents millihertz.
+    #[allow(non_snake_case)]
+    fn mHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents revolutions per minute.
+    fn rpm<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents bits per second.
+    fn bps<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
+
+    /// Shorthand for creating a rate which represents baud.
+    fn baud<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM>;
 }
 
 impl ExtU64 for u64 {
     #[inline]
     #[allow(non_snake_case)]
-    fn Hz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM> {
+    fn Hz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
         Rate::<u64, NOM, DENOM>::Hz(self)
     }
 
     #[inline]
     #[allow(non_snake_case)]
-    fn kHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM> {
+    fn kHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
         Rate::<u64, NOM, DENOM>::kHz(self)
     }
 
     #[inline]
     #[allow(non_snake_case)]
-    fn MHz<const NOM: u32, const DENOM: u32>(self) -> Rate<u64, NOM, DENOM> {
+    fn MHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
         Rate::<u64, NOM, DENOM>::MHz(self)
     }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn GHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
+        Rate::<u64, NOM, DENOM>::GHz(self)
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    fn mHz<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
+        Rate::<u64, NOM, DENOM>::mHz(self)
+    }
+
+    #[inline]
+    fn rpm<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
+        Rate::<u64, NOM, DENOM>::rpm(self)
+    }
+
+    #[inline]
+    fn bps<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
+        Rate::<u64, NOM, DENOM>::bps(self)
+    }
+
+    #[inline]
+    fn baud<const NOM: u64, const DENOM: u64>(self) -> Rate<u64, NOM, DENOM> {
+        Rate::<u64, NOM, DENOM>::baud(self)
+    }
 }
@@ -0,0 +1,276 @@
+//! Token and leaky bucket rate limiters.
+//!
+//! Both limiters account for elapsed time in the `Instant` base with an exact fractional carry,
+//! so no tokens are lost to rounding regardless of how often they are polled.
+
+use crate::helpers::Helpers;
+use crate::{Duration, Instant, Rate};
+
+/// Exact conversion from elapsed ticks to whole units with a fractional carry.
+#[derive(Clone, Copy, Debug)]
+struct Accumulator {
+    /// Units per tick, numerator
+    num: u128,
+    /// Units per tick, denominator
+    den: u128,
+    /// Fractional units, in units of `1 / den`
+    carry: u128,
+}
+
+impl Accumulator {
+    const fn new(num: u128, den: u128) -> Self {
+        Accumulator { num, den, carry: 0 }
+    }
+
+    /// Advance by `ticks`, returning the number of whole units produced.
+    fn advance(&mut self, ticks: u64) -> u64 {
+        let acc = (ticks as u128)
+            .saturating_mul(self.num)
+            .saturating_add(self.carry);
+
+        self.carry = acc % self.den;
+
+        let units = acc / self.den;
+        if units > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            units as u64
+        }
+    }
+
+    /// Number of ticks until `units` whole units have been produced, `None` if never.
+    fn ticks_until(&self, units: u64) -> Option<u128> {
+        if units == 0 {
+            Some(0)
+        } else if self.num == 0 {
+            None
+        } else {
+            let needed = (units as u128)
+                .saturating_mul(self.den)
+                .saturating_sub(self.carry);
+
+            Some(needed.div_ceil(self.num))
+        }
+    }
+}
+
+/// Token bucket rate limiter.
+///
+/// Tokens are added at a fixed `Rate` up to the burst capacity, and each operation consumes one
+/// or more tokens. The bucket starts full.
+#[derive(Clone, Copy, Debug)]
+pub struct TokenBucket<T, const NOM: u64, const DENOM: u64> {
+    refill: Accumulator,
+    tokens: u32,
+    capacity: u32,
+    last: Instant<T, NOM, DENOM>,
+}
+
+/// Leaky bucket rate limiter (used as a meter).
+///
+/// Each operation adds to the bucket, which drains at a fixed `Rate`. Operations that would
+/// overflow the capacity are rejected. The bucket starts empty.
+#[derive(Clone, Copy, Debug)]
+pub struct LeakyBucket<T, const NOM: u64, const DENOM: u64> {
+    drain: Accumulator,
+    level: u32,
+    capacity: u32,
+    last: Instant<T, NOM, DENOM>,
+}
+
+macro_rules! impl_rate_limit_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64> TokenBucket<$i, NOM, DENOM> {
+            /// Create a full token bucket which refills at `rate` tokens per second and holds at
+            /// most `burst` tokens.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let now = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(0);")]
+            #[doc = concat!("let mut bucket = TokenBucket::<", stringify!($i), ", 1, 1_000>::new(Rate::<", stringify!($i), ", 1, 1>::from_raw(3), 2, now);")]
+            ///
+            /// assert!(bucket.try_acquire(now, 2));
+            /// assert!(!bucket.try_acquire(now, 1));
+            /// assert_eq!(bucket.time_until_available(now).ticks(), 334);
+            /// ```
+            pub const fn new<const R_NOM: u64, const R_DENOM: u64>(
+                rate: Rate<$i, R_NOM, R_DENOM>,
+                burst: u32,
+                now: Instant<$i, NOM, DENOM>,
+            ) -> Self {
+                TokenBucket {
+                    refill: Accumulator::new(
+                        (rate.raw as u128)
+                            .saturating_mul(Helpers::<R_NOM, R_DENOM, NOM, DENOM>::LN_TIMES_RN),
+                        Helpers::<R_NOM, R_DENOM, NOM, DENOM>::RD_TIMES_LD,
+                    ),
+                    tokens: burst,
+                    capacity: burst,
+                    last: now,
+                }
+            }
+
+            /// The burst capacity of the bucket.
+            #[inline]
+            pub const fn capacity(&self) -> u32 {
+                self.capacity
+            }
+
+            fn update(&mut self, now: Instant<$i, NOM, DENOM>) {
+                // An instant before the last update is treated as no time having passed
+                if let Some(elapsed) = now.checked_duration_since(self.last) {
+                    self.last = now;
+
+                    let added = self.refill.advance(elapsed.ticks() as u64);
+                    let tokens = (self.tokens as u64).saturating_add(added);
+
+                    if tokens >= self.capacity as u64 {
+                        self.tokens = self.capacity;
+                        self.refill.carry = 0;
+                    } else {
+                        self.tokens = tokens as u32;
+                    }
+                }
+            }
+
+            /// Number of tokens available at `now`.
+            pub fn available(&mut self, now: Instant<$i, NOM, DENOM>) -> u32 {
+                self.update(now);
+                self.tokens
+            }
+
+            /// Try to take `tokens` tokens from the bucket at `now`. Nothing is taken if not
+            /// enough tokens are available.
+            pub fn try_acquire(&mut self, now: Instant<$i, NOM, DENOM>, tokens: u32) -> bool {
+                self.update(now);
+
+                if self.tokens >= tokens {
+                    self.tokens -= tokens;
+                    true
+                } else {
+                    false
+                }
+            }
+
+            /// Time from `now` until at least one token is available. Saturates if the bucket
+            /// never refills (a rate of zero or a burst capacity of zero).
+            pub fn time_until_available(
+                &mut self,
+                now: Instant<$i, NOM, DENOM>,
+            ) -> Duration<$i, NOM, DENOM> {
+                self.update(now);
+
+                let ticks = if self.tokens > 0 {
+                    Some(0)
+                } else if self.capacity == 0 {
+                    None
+                } else {
+                    self.refill.ticks_until(1)
+                };
+
+                Duration::<$i, NOM, DENOM>::from_ticks(match ticks {
+                    Some(ticks) if ticks <= <$i>::MAX as u128 => ticks as $i,
+                    _ => <$i>::MAX,
+                })
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> LeakyBucket<$i, NOM, DENOM> {
+            /// Create an empty leaky bucket which drains at `rate` per second and holds at most
+            /// `capacity`.
+            ///
+            /// ```
+            /// # use fugit::*;
+            #[doc = concat!("let now = Instant::<", stringify!($i), ", 1, 1_000>::from_ticks(0);")]
+            #[doc = concat!("let mut bucket = LeakyBucket::<", stringify!($i), ", 1, 1_000>::new(Rate::<", stringify!($i), ", 1, 1>::from_raw(4), 2, now);")]
+            ///
+            /// assert!(bucket.try_add(now, 2));
+            /// assert!(!bucket.try_add(now, 1));
+            /// assert_eq!(bucket.time_until_available(now).ticks(), 250);
+            /// ```
+            pub const fn new<const R_NOM: u64, const R_DENOM: u64>(
+                rate: Rate<$i, R_NOM, R_DENOM>,
+                capacity: u32,
+                now: Instant<$i, NOM, DENOM>,
+            ) -> Self {
+                LeakyBucket {
+                    drain: Accumulator::new(
+                        (rate.raw as u128)
+                            .saturating_mul(Helpers::<R_NOM, R_DENOM, NOM, DENOM>::LN_TIMES_RN),
+                        Helpers::<R_NOM, R_DENOM, NOM, DENOM>::RD_TIMES_LD,
+                    ),
+                    level: 0,
+                    capacity,
+                    last: now,
+                }
+            }
+
+            /// The capacity of the bucket.
+            #[inline]
+            pub const fn capacity(&self) -> u32 {
+                self.capacity
+            }
+
+            fn update(&mut self, now: Instant<$i, NOM, DENOM>) {
+                // An instant before the last update is treated as no time having passed
+                if let Some(elapsed) = now.checked_duration_since(self.last) {
+                    self.last = now;
+
+                    let drained = self.drain.advance(elapsed.ticks() as u64);
+
+                    if drained >= self.level as u64 {
+                        self.level = 0;
+                        self.drain.carry = 0;
+                    } else {
+                        self.level -= drained as u32;
+                    }
+                }
+            }
+
+            /// Fill level of the bucket at `now`.
+            pub fn level(&mut self, now: Instant<$i, NOM, DENOM>) -> u32 {
+                self.update(now);
+                self.level
+            }
+
+            /// Try to add `amount` to the bucket at `now`. Nothing is added if it would overflow
+            /// the capacity.
+            pub fn try_add(&mut self, now: Instant<$i, NOM, DENOM>, amount: u32) -> bool {
+                self.update(now);
+
+                match self.level.checked_add(amount) {
+                    Some(level) if level <= self.capacity => {
+                        self.level = level;
+                        true
+                    }
+                    _ => false,
+                }
+            }
+
+            /// Time from `now` until at least one unit can be added. Saturates if the bucket
+            /// never drains (a rate of zero or a capacity of zero).
+            pub fn time_until_available(
+                &mut self,
+                now: Instant<$i, NOM, DENOM>,
+            ) -> Duration<$i, NOM, DENOM> {
+                self.update(now);
+
+                let ticks = if self.level < self.capacity {
+                    Some(0)
+                } else if self.capacity == 0 {
+                    None
+                } else {
+                    self.drain.ticks_until((self.level - self.capacity + 1) as u64)
+                };
+
+                Duration::<$i, NOM, DENOM>::from_ticks(match ticks {
+                    Some(ticks) if ticks <= <$i>::MAX as u128 => ticks as $i,
+                    _ => <$i>::MAX,
+                })
+            }
+        }
+    };
+}
+
+impl_rate_limit_for_integer!(u32);
+impl_rate_limit_for_integer!(u64);
@@ -0,0 +1,347 @@
+//! Running statistics over `Duration`s and `Rate`s.
+//!
+//! The moments are accumulated relative to the first sample (the shifted data algorithm) in
+//! integer arithmetic, which is exact and does not suffer from the cancellation of the naive sum
+//! of squares. Unlike Welford's algorithm it does not divide per sample, but it is only exact while
+//! the sum of the squared deviations from the first sample fits in 128 bits: once it overflows the
+//! standard deviation is `None`. The mean, minimum, maximum and jitter are not affected.
+
+use crate::{Duration, Rate};
+
+/// Integer moments of the samples, relative to the first sample.
+#[derive(Clone, Copy, Debug)]
+struct Moments {
+    count: u64,
+    /// The first sample
+    shift: u64,
+    /// Sum of the samples minus `shift`
+    sum: i128,
+    /// Sum of the squares of the samples minus `shift`
+    sum_sq: u128,
+    /// Set once `sum_sq` overflowed
+    overflow: bool,
+}
+
+impl Moments {
+    const fn new() -> Self {
+        Moments {
+            count: 0,
+            shift: 0,
+            sum: 0,
+            sum_sq: 0,
+            overflow: false,
+        }
+    }
+
+    /// Constant time and division free, the divisions are deferred to `mean` and `std_dev`.
+    fn push(&mut self, value: u64) {
+        if self.count == 0 {
+            self.shift = value;
+        }
+
+        let (magnitude, negative) = if value >= self.shift {
+            (value - self.shift, false)
+        } else {
+            (self.shift - value, true)
+        };
+
+        // Deviations of 32 bit samples fit 32 bits, so the square is one 32 x 32 -> 64 bit multiply
+        // instead of a 128 bit multiply
+        let square = if magnitude <= u32::MAX as u64 {
+            (magnitude * magnitude) as u128
+        } else {
+            magnitude as u128 * magnitude as u128
+        };
+
+        self.count = self.count.saturating_add(1);
+        // Can not saturate before `count` does, as each deviation is below 2^64
+        self.sum = if negative {
+            self.sum.saturating_sub(magnitude as i128)
+        } else {
+            self.sum.saturating_add(magnitude as i128)
+        };
+        match self.sum_sq.checked_add(square) {
+            Some(sum_sq) => self.sum_sq = sum_sq,
+            None => self.overflow = true,
+        }
+    }
+
+    /// Mean, rounded to the nearest value.
+    const fn mean(&self) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let count = self.count as i128;
+        let offset = if self.sum >= 0 {
+            (self.sum + count / 2) / count
+        } else {
+            (self.sum - count / 2) / count
+        };
+
+        Some((self.shift as i128 + offset) as u64)
+    }
+
+    /// Population standard deviation, rounded down. `None` if the sums overflowed.
+    const fn std_dev(&self) -> Option<u64> {
+        if self.count == 0 || self.overflow {
+            return None;
+        }
+
+        let count = self.count as u128;
+        let sum = self.sum.unsigned_abs();
+        let sum_sq = match sum.checked_mul(sum) {
+            Some(v) => v,
+            None => return None,
+        };
+
+        // variance = (sum_sq - sum^2 / count) / count
+        let variance = self.sum_sq.saturating_sub(sum_sq / count) / count;
+
+        Some(variance.isqrt() as u64)
+    }
+}
+
+/// Running mean, standard deviation, minimum, maximum and peak-to-peak jitter of `Duration`
+/// samples.
+///
+/// ```
+/// # use fugit::*;
+/// let mut stats = DurationStats::<u32, 1, 1_000_000>::new();
+///
+/// for us in [998, 1_002, 1_001, 999] {
+///     stats.push(MicrosDurationU32::micros(us));
+/// }
+///
+/// assert_eq!(stats.count(), 4);
+/// assert_eq!(stats.mean(), Some(MicrosDurationU32::micros(1_000)));
+/// assert_eq!(stats.std_dev(), Some(MicrosDurationU32::micros(1)));
+/// assert_eq!(stats.jitter(), Some(MicrosDurationU32::micros(4)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DurationStats<T, const NOM: u64, const DENOM: u64> {
+    moments: Moments,
+    min: Duration<T, NOM, DENOM>,
+    max: Duration<T, NOM, DENOM>,
+}
+
+/// Running mean, standard deviation, minimum, maximum and peak-to-peak jitter of `Rate` samples.
+///
+/// ```
+/// # use fugit::*;
+/// let mut stats = RateStats::<u32, 1, 1>::new();
+///
+/// for hz in [32_767, 32_768, 32_770] {
+///     stats.push(HertzU32::Hz(hz));
+/// }
+///
+/// assert_eq!(stats.mean(), Some(HertzU32::Hz(32_768)));
+/// assert_eq!(stats.min(), Some(HertzU32::Hz(32_767)));
+/// assert_eq!(stats.max(), Some(HertzU32::Hz(32_770)));
+/// assert_eq!(stats.jitter(), Some(HertzU32::Hz(3)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RateStats<T, const NOM: u64, const DENOM: u64> {
+    moments: Moments,
+    min: Rate<T, NOM, DENOM>,
+    max: Rate<T, NOM, DENOM>,
+}
+
+impl<T, const NOM: u64, const DENOM: u64> DurationStats<T, NOM, DENOM> {
+    /// Number of samples.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.moments.count
+    }
+}
+
+impl<T, const NOM: u64, const DENOM: u64> RateStats<T, NOM, DENOM> {
+    /// Number of samples.
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.moments.count
+    }
+}
+
+macro_rules! impl_stats_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64> DurationStats<$i, NOM, DENOM> {
+            /// Create an empty accumulator.
+            pub const fn new() -> Self {
+                DurationStats {
+                    moments: Moments::new(),
+                    min: Duration::<$i, NOM, DENOM>::from_ticks(<$i>::MAX),
+                    max: Duration::<$i, NOM, DENOM>::from_ticks(0),
+                }
+            }
+
+            /// Add a sample.
+            ///
+            /// Constant time and without divisions, so it can be called from an interrupt. For
+            /// `u32` backings it costs one 32 x 32 -> 64 bit multiply plus a few additions, for
+            /// `u64` backings the multiply is 64 x 64 -> 128 bit.
+            pub fn push(&mut self, sample: Duration<$i, NOM, DENOM>) {
+                self.moments.push(sample.ticks() as u64);
+
+                if sample.ticks() < self.min.ticks() {
+                    self.min = sample;
+                }
+
+                if sample.ticks() > self.max.ticks() {
+                    self.max = sample;
+                }
+            }
+
+            /// Remove all samples.
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            /// The mean, rounded to the nearest tick.
+            pub const fn mean(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if let Some(mean) = self.moments.mean() {
+                    Some(Duration::<$i, NOM, DENOM>::from_ticks(mean as $i))
+                } else {
+                    None
+                }
+            }
+
+            /// The population standard deviation, rounded down to whole ticks. `None` if there are
+            /// no samples or the spread of the samples overflowed the accumulator.
+            pub const fn std_dev(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if let Some(std_dev) = self.moments.std_dev() {
+                    Some(Duration::<$i, NOM, DENOM>::from_ticks(std_dev as $i))
+                } else {
+                    None
+                }
+            }
+
+            /// The smallest sample.
+            pub const fn min(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(self.min)
+                }
+            }
+
+            /// The largest sample.
+            pub const fn max(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(self.max)
+                }
+            }
+
+            /// The peak-to-peak jitter, i.e. the difference between the largest and the smallest
+            /// sample.
+            pub const fn jitter(&self) -> Option<Duration<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(Duration::<$i, NOM, DENOM>::from_ticks(
+                        self.max.ticks() - self.min.ticks(),
+                    ))
+                }
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> Default for DurationStats<$i, NOM, DENOM> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> RateStats<$i, NOM, DENOM> {
+            /// Create an empty accumulator.
+            pub const fn new() -> Self {
+                RateStats {
+                    moments: Moments::new(),
+                    min: Rate::<$i, NOM, DENOM>::from_raw(<$i>::MAX),
+                    max: Rate::<$i, NOM, DENOM>::from_raw(0),
+                }
+            }
+
+            /// Add a sample.
+            ///
+            /// Constant time and without divisions, so it can be called from an interrupt. For
+            /// `u32` backings it costs one 32 x 32 -> 64 bit multiply plus a few additions, for
+            /// `u64` backings the multiply is 64 x 64 -> 128 bit.
+            pub fn push(&mut self, sample: Rate<$i, NOM, DENOM>) {
+                self.moments.push(sample.raw() as u64);
+
+                if sample.raw() < self.min.raw() {
+                    self.min = sample;
+                }
+
+                if sample.raw() > self.max.raw() {
+                    self.max = sample;
+                }
+            }
+
+            /// Remove all samples.
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            /// The mean, rounded to the nearest raw value.
+            pub const fn mean(&self) -> Option<Rate<$i, NOM, DENOM>> {
+                if let Some(mean) = self.moments.mean() {
+                    Some(Rate::<$i, NOM, DENOM>::from_raw(mean as $i))
+                } else {
+                    None
+                }
+            }
+
+            /// The population standard deviation, rounded down to whole raw values. `None` if there
+            /// are no samples or the spread of the samples overflowed the accumulator.
+            pub const fn std_dev(&self) -> Option<Rate<$i, NOM, DENOM>> {
+                if let Some(std_dev) = self.moments.std_dev() {
+                    Some(Rate::<$i, NOM, DENOM>::from_raw(std_dev as $i))
+                } else {
+                    None
+                }
+            }
+
+            /// The smallest sample.
+            pub const fn min(&self) -> Option<Rate<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(self.min)
+                }
+            }
+
+            /// The largest sample.
+            pub const fn max(&self) -> Option<Rate<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(self.max)
+                }
+            }
+
+            /// The peak-to-peak jitter, i.e. the difference between the largest and the smallest
+            /// sample.
+            pub const fn jitter(&self) -> Option<Rate<$i, NOM, DENOM>> {
+                if self.moments.count == 0 {
+                    None
+                } else {
+                    Some(Rate::<$i, NOM, DENOM>::from_raw(
+                        self.max.raw() - self.min.raw(),
+                    ))
+                }
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64> Default for RateStats<$i, NOM, DENOM> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_stats_for_integer!(u32);
+impl_stats_for_integer!(u64);
@@ -0,0 +1,195 @@
+//! Stopwatch and scope timer on top of any source of `Instant`s.
+//!
+//! The clock is any `FnMut() -> Instant`, e.g. `|| Mono::now()` for a monotonic timer. Elapsed
+//! time is computed with `checked_duration_since`, so the timer may wrap around as long as each
+//! measured interval is within half its range. An `Instant` seemingly before the start counts as
+//! no time having passed.
+
+use crate::{Duration, Instant};
+
+/// Stopwatch with laps and splits.
+///
+/// Laps and splits only count the time the stopwatch has been running.
+///
+/// ```
+/// # use fugit::*;
+/// # use core::cell::Cell;
+/// let ticks = Cell::new(0);
+/// let mut sw = Stopwatch::<u32, 1, 1_000, _>::new(|| Instant::<u32, 1, 1_000>::from_ticks(ticks.get()));
+///
+/// sw.start();
+/// ticks.set(10);
+/// assert_eq!(sw.lap().ticks(), 10);
+/// ticks.set(25);
+/// assert_eq!(sw.lap().ticks(), 15);
+/// assert_eq!(sw.split().ticks(), 25);
+///
+/// // Time while stopped is not counted
+/// assert_eq!(sw.stop().ticks(), 25);
+/// ticks.set(100);
+/// sw.start();
+/// ticks.set(105);
+/// assert_eq!(sw.split().ticks(), 30);
+/// assert_eq!(sw.lap().ticks(), 5);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Stopwatch<T, const NOM: u64, const DENOM: u64, C> {
+    clock: C,
+    /// Start of the current run, `None` if stopped
+    started: Option<Instant<T, NOM, DENOM>>,
+    /// Time of all previous runs
+    accumulated: Duration<T, NOM, DENOM>,
+    /// Split at the last lap
+    lap: Duration<T, NOM, DENOM>,
+}
+
+/// Guard measuring the time until it is dropped, which is reported to a callback.
+///
+/// ```
+/// # use fugit::*;
+/// # use core::cell::Cell;
+/// let ticks = Cell::new(0);
+/// let clock = || Instant::<u32, 1, 1_000_000>::from_ticks(ticks.get());
+/// let mut stats = DurationStats::<u32, 1, 1_000_000>::new();
+///
+/// for us in [10, 12] {
+///     let _t = ScopeTimer::<u32, 1, 1_000_000, _, _>::new(clock, |d| stats.push(d));
+///     ticks.set(ticks.get() + us);
+/// }
+///
+/// assert_eq!(stats.mean(), Some(MicrosDurationU32::micros(11)));
+/// ```
+pub struct ScopeTimer<T, const NOM: u64, const DENOM: u64, C, F>
+where
+    T: Copy,
+    C: FnMut() -> Instant<T, NOM, DENOM>,
+    F: FnMut(Duration<T, NOM, DENOM>),
+{
+    clock: C,
+    report: F,
+    start: Instant<T, NOM, DENOM>,
+    /// Elapsed time between two instants, as `Drop` can not be implemented per backing integer
+    since: fn(Instant<T, NOM, DENOM>, Instant<T, NOM, DENOM>) -> Duration<T, NOM, DENOM>,
+}
+
+impl<T, const NOM: u64, const DENOM: u64, C, F> ScopeTimer<T, NOM, DENOM, C, F>
+where
+    T: Copy,
+    C: FnMut() -> Instant<T, NOM, DENOM>,
+    F: FnMut(Duration<T, NOM, DENOM>),
+{
+    /// Elapsed time since the guard was created.
+    pub fn elapsed(&mut self) -> Duration<T, NOM, DENOM> {
+        (self.since)((self.clock)(), self.start)
+    }
+}
+
+impl<T, const NOM: u64, const DENOM: u64, C, F> Drop for ScopeTimer<T, NOM, DENOM, C, F>
+where
+    T: Copy,
+    C: FnMut() -> Instant<T, NOM, DENOM>,
+    F: FnMut(Duration<T, NOM, DENOM>),
+{
+    fn drop(&mut self) {
+        let elapsed = self.elapsed();
+        (self.report)(elapsed);
+    }
+}
+
+impl<T, const NOM: u64, const DENOM: u64, C> Stopwatch<T, NOM, DENOM, C> {
+    /// Returns true if the stopwatch is running.
+    #[inline]
+    pub const fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+}
+
+macro_rules! impl_stopwatch_for_integer {
+    ($i:ty) => {
+        impl<const NOM: u64, const DENOM: u64, C: FnMut() -> Instant<$i, NOM, DENOM>>
+            Stopwatch<$i, NOM, DENOM, C>
+        {
+            /// Create a stopped stopwatch reading time from `clock`.
+            pub const fn new(clock: C) -> Self {
+                Stopwatch {
+                    clock,
+                    started: None,
+                    accumulated: Duration::<$i, NOM, DENOM>::from_ticks(0),
+                    lap: Duration::<$i, NOM, DENOM>::from_ticks(0),
+                }
+            }
+
+            /// Start the stopwatch, does nothing if it is already running.
+            pub fn start(&mut self) {
+                if self.started.is_none() {
+                    self.started = Some((self.clock)());
+                }
+            }
+
+            /// Stop the stopwatch and return the total elapsed time.
+            pub fn stop(&mut self) -> Duration<$i, NOM, DENOM> {
+                self.accumulated = self.split();
+                self.started = None;
+
+                self.accumulated
+            }
+
+            /// Stop the stopwatch and clear the elapsed time.
+            pub fn reset(&mut self) {
+                self.started = None;
+                self.accumulated = Duration::<$i, NOM, DENOM>::from_ticks(0);
+                self.lap = Duration::<$i, NOM, DENOM>::from_ticks(0);
+            }
+
+            /// Total elapsed time, without stopping. Saturates at the largest `Duration`.
+            pub fn split(&mut self) -> Duration<$i, NOM, DENOM> {
+                let running = match self.started {
+                    Some(started) => (self.clock)()
+                        .checked_duration_since(started)
+                        .unwrap_or(Duration::<$i, NOM, DENOM>::from_ticks(0)),
+                    None => Duration::<$i, NOM, DENOM>::from_ticks(0),
+                };
+
+                self.accumulated
+                    .checked_add(running)
+                    .unwrap_or(Duration::<$i, NOM, DENOM>::from_ticks(<$i>::MAX))
+            }
+
+            /// Elapsed time since the previous lap, or since the first start for the first lap.
+            pub fn lap(&mut self) -> Duration<$i, NOM, DENOM> {
+                let split = self.split();
+                let lap = split
+                    .checked_sub(self.lap)
+                    .unwrap_or(Duration::<$i, NOM, DENOM>::from_ticks(0));
+
+                self.lap = split;
+                lap
+            }
+        }
+
+        impl<const NOM: u64, const DENOM: u64, C, F> ScopeTimer<$i, NOM, DENOM, C, F>
+        where
+            C: FnMut() -> Instant<$i, NOM, DENOM>,
+            F: FnMut(Duration<$i, NOM, DENOM>),
+        {
+            /// Start measuring with `clock`, calling `report` with the elapsed time when the
+            /// guard is dropped.
+            pub fn new(mut clock: C, report: F) -> Self {
+                let start = clock();
+
+                ScopeTimer {
+                    clock,
+                    report,
+                    start,
+                    since: |now, start| {
+                        now.checked_duration_since(start)
+                            .unwrap_or(Duration::<$i, NOM, DENOM>::from_ticks(0))
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_stopwatch_for_integer!(u32);
+impl_stopwatch_for_integer!(u64);
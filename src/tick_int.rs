@@ -0,0 +1,719 @@
+//! The integer types which can back a `Duration`, `Instant` or `Rate`.
+//!
+//! The inherent methods of the time types are `const fn` and are generated per integer, as trait
+//! methods cannot be `const` on stable. `TickInt` ties the integer types together so the trait
+//! impls (comparisons, operators, formatting) are written once, and so downstream crates can be
+//! generic over the backing integer through the `ExtDuration`, `ExtInstant` and `ExtRate`
+//! extension traits.
+
+use crate::{Duration, Instant, Rate};
+use core::cmp::Ordering;
+use core::{fmt, hash, ops};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An integer which can back a [`Duration`], [`Instant`] or [`Rate`].
+///
+/// This trait is sealed and implemented for `u8`, `u16`, `u32`, `u64` and `u128`. The inherent
+/// `const fn` methods of the time types are not part of the trait, so a new backing integer still
+/// needs an expansion of each `impl_*_for_integer!` macro besides its `TickInt` impl.
+///
+/// ```
+/// # use fugit::*;
+/// fn double<T: TickInt, const NOM: u64, const DENOM: u64>(
+///     d: Duration<T, NOM, DENOM>,
+/// ) -> Option<Duration<T, 1, 1_000>> {
+///     d.checked_add(d)?.const_try_into()
+/// }
+///
+/// assert_eq!(double(Duration::<u32, 1, 100>::from_ticks(3)), Some(60u32.millis()));
+/// assert_eq!(double(Duration::<u64, 1, 100>::from_ticks(3)), Some(60u64.millis()));
+/// assert_eq!(double(Duration::<u32, 1, 1_000>::from_ticks(u32::MAX)), None);
+/// ```
+pub trait TickInt:
+    sealed::Sealed
+    + Copy
+    + Ord
+    + hash::Hash
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + Send
+    + Sync
+    + 'static
+{
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// The value `1`.
+    const ONE: Self;
+
+    /// The largest value of the integer.
+    const MAX: Self;
+
+    /// The size of the integer in bits.
+    const BITS: u32;
+
+    /// Convert from a `u128`, returns `None` if the value does not fit.
+    fn from_u128(val: u128) -> Option<Self>;
+
+    /// Widen to a `u128`.
+    fn to_u128(self) -> u128;
+
+    /// Checked integer addition.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Checked integer subtraction.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Checked integer multiplication.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Checked integer division, returns `None` if `other` is `0`.
+    fn checked_div(self, other: Self) -> Option<Self>;
+
+    /// Wrapping integer addition.
+    fn wrapping_add(self, other: Self) -> Self;
+
+    /// Wrapping integer subtraction.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    #[doc(hidden)]
+    fn duration_checked_add<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Duration<Self, L_NOM, L_DENOM>,
+        rhs: Duration<Self, R_NOM, R_DENOM>,
+    ) -> Option<Duration<Self, L_NOM, L_DENOM>>;
+
+    #[doc(hidden)]
+    fn duration_checked_sub<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Duration<Self, L_NOM, L_DENOM>,
+        rhs: Duration<Self, R_NOM, R_DENOM>,
+    ) -> Option<Duration<Self, L_NOM, L_DENOM>>;
+
+    #[doc(hidden)]
+    fn duration_partial_cmp<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Duration<Self, L_NOM, L_DENOM>,
+        rhs: Duration<Self, R_NOM, R_DENOM>,
+    ) -> Option<Ordering>;
+
+    #[doc(hidden)]
+    fn duration_eq<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>(
+        lhs: Duration<Self, L_NOM, L_DENOM>,
+        rhs: Duration<Self, R_NOM, R_DENOM>,
+    ) -> bool;
+
+    #[doc(hidden)]
+    fn duration_try_convert<
+        const I_NOM: u64,
+        const I_DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        duration: Duration<Self, I_NOM, I_DENOM>,
+    ) -> Option<Duration<Self, O_NOM, O_DENOM>>;
+
+    #[doc(hidden)]
+    fn duration_try_into_rate<
+        const I_NOM: u64,
+        const I_DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        duration: Duration<Self, I_NOM, I_DENOM>,
+    ) -> Option<Rate<Self, O_NOM, O_DENOM>>;
+
+    #[doc(hidden)]
+    fn instant_cmp<const NOM: u64, const DENOM: u64>(
+        lhs: Instant<Self, NOM, DENOM>,
+        rhs: Instant<Self, NOM, DENOM>,
+    ) -> Ordering;
+
+    #[doc(hidden)]
+    fn instant_checked_duration_since<const NOM: u64, const DENOM: u64>(
+        lhs: Instant<Self, NOM, DENOM>,
+        rhs: Instant<Self, NOM, DENOM>,
+    ) -> Option<Duration<Self, NOM, DENOM>>;
+
+    #[doc(hidden)]
+    fn instant_checked_add_duration<
+        const NOM: u64,
+        const DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        lhs: Instant<Self, NOM, DENOM>,
+        rhs: Duration<Self, O_NOM, O_DENOM>,
+    ) -> Option<Instant<Self, NOM, DENOM>>;
+
+    #[doc(hidden)]
+    fn instant_checked_sub_duration<
+        const NOM: u64,
+        const DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        lhs: Instant<Self, NOM, DENOM>,
+        rhs: Duration<Self, O_NOM, O_DENOM>,
+    ) -> Option<Instant<Self, NOM, DENOM>>;
+
+    #[doc(hidden)]
+    fn rate_checked_add<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Rate<Self, L_NOM, L_DENOM>,
+        rhs: Rate<Self, R_NOM, R_DENOM>,
+    ) -> Option<Rate<Self, L_NOM, L_DENOM>>;
+
+    #[doc(hidden)]
+    fn rate_checked_sub<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Rate<Self, L_NOM, L_DENOM>,
+        rhs: Rate<Self, R_NOM, R_DENOM>,
+    ) -> Option<Rate<Self, L_NOM, L_DENOM>>;
+
+    #[doc(hidden)]
+    fn rate_partial_cmp<
+        const L_NOM: u64,
+        const L_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        lhs: Rate<Self, L_NOM, L_DENOM>,
+        rhs: Rate<Self, R_NOM, R_DENOM>,
+    ) -> Option<Ordering>;
+
+    #[doc(hidden)]
+    fn rate_eq<const L_NOM: u64, const L_DENOM: u64, const R_NOM: u64, const R_DENOM: u64>(
+        lhs: Rate<Self, L_NOM, L_DENOM>,
+        rhs: Rate<Self, R_NOM, R_DENOM>,
+    ) -> bool;
+
+    #[doc(hidden)]
+    fn rate_try_convert<
+        const I_NOM: u64,
+        const I_DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        rate: Rate<Self, I_NOM, I_DENOM>,
+    ) -> Option<Rate<Self, O_NOM, O_DENOM>>;
+
+    #[doc(hidden)]
+    fn rate_try_into_duration<
+        const I_NOM: u64,
+        const I_DENOM: u64,
+        const O_NOM: u64,
+        const O_DENOM: u64,
+    >(
+        rate: Rate<Self, I_NOM, I_DENOM>,
+    ) -> Option<Duration<Self, O_NOM, O_DENOM>>;
+}
+
+/// A `u32` factor of the integer operators as the backing integer, panics if it does not fit.
+#[inline]
+pub(crate) fn factor<T: TickInt>(val: u32) -> T {
+    if let Some(v) = T::from_u128(val as u128) {
+        v
+    } else {
+        panic!("Overflow!");
+    }
+}
+
+macro_rules! impl_tick_int_for_integer {
+    ($i:ty) => {
+        impl sealed::Sealed for $i {}
+
+        impl TickInt for $i {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MAX: Self = <$i>::MAX;
+            const BITS: u32 = <$i>::BITS;
+
+            #[inline]
+            fn from_u128(val: u128) -> Option<Self> {
+                if val <= <$i>::MAX as u128 {
+                    Some(val as $i)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$i>::checked_add(self, other)
+            }
+
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$i>::checked_sub(self, other)
+            }
+
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$i>::checked_mul(self, other)
+            }
+
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> {
+                <$i>::checked_div(self, other)
+            }
+
+            #[inline]
+            fn wrapping_add(self, other: Self) -> Self {
+                <$i>::wrapping_add(self, other)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$i>::wrapping_sub(self, other)
+            }
+
+            #[inline]
+            fn duration_checked_add<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Duration<Self, L_NOM, L_DENOM>,
+                rhs: Duration<Self, R_NOM, R_DENOM>,
+            ) -> Option<Duration<Self, L_NOM, L_DENOM>> {
+                lhs.checked_add(rhs)
+            }
+
+            #[inline]
+            fn duration_checked_sub<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Duration<Self, L_NOM, L_DENOM>,
+                rhs: Duration<Self, R_NOM, R_DENOM>,
+            ) -> Option<Duration<Self, L_NOM, L_DENOM>> {
+                lhs.checked_sub(rhs)
+            }
+
+            #[inline]
+            fn duration_partial_cmp<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Duration<Self, L_NOM, L_DENOM>,
+                rhs: Duration<Self, R_NOM, R_DENOM>,
+            ) -> Option<Ordering> {
+                lhs.const_partial_cmp(rhs)
+            }
+
+            #[inline]
+            fn duration_eq<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Duration<Self, L_NOM, L_DENOM>,
+                rhs: Duration<Self, R_NOM, R_DENOM>,
+            ) -> bool {
+                lhs.const_eq(rhs)
+            }
+
+            #[inline]
+            fn duration_try_convert<
+                const I_NOM: u64,
+                const I_DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                duration: Duration<Self, I_NOM, I_DENOM>,
+            ) -> Option<Duration<Self, O_NOM, O_DENOM>> {
+                duration.const_try_into()
+            }
+
+            #[inline]
+            fn duration_try_into_rate<
+                const I_NOM: u64,
+                const I_DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                duration: Duration<Self, I_NOM, I_DENOM>,
+            ) -> Option<Rate<Self, O_NOM, O_DENOM>> {
+                duration.try_into_rate()
+            }
+
+            #[inline]
+            fn instant_cmp<const NOM: u64, const DENOM: u64>(
+                lhs: Instant<Self, NOM, DENOM>,
+                rhs: Instant<Self, NOM, DENOM>,
+            ) -> Ordering {
+                lhs.const_cmp(rhs)
+            }
+
+            #[inline]
+            fn instant_checked_duration_since<const NOM: u64, const DENOM: u64>(
+                lhs: Instant<Self, NOM, DENOM>,
+                rhs: Instant<Self, NOM, DENOM>,
+            ) -> Option<Duration<Self, NOM, DENOM>> {
+                lhs.checked_duration_since(rhs)
+            }
+
+            #[inline]
+            fn instant_checked_add_duration<
+                const NOM: u64,
+                const DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                lhs: Instant<Self, NOM, DENOM>,
+                rhs: Duration<Self, O_NOM, O_DENOM>,
+            ) -> Option<Instant<Self, NOM, DENOM>> {
+                lhs.checked_add_duration(rhs)
+            }
+
+            #[inline]
+            fn instant_checked_sub_duration<
+                const NOM: u64,
+                const DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                lhs: Instant<Self, NOM, DENOM>,
+                rhs: Duration<Self, O_NOM, O_DENOM>,
+            ) -> Option<Instant<Self, NOM, DENOM>> {
+                lhs.checked_sub_duration(rhs)
+            }
+
+            #[inline]
+            fn rate_checked_add<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Rate<Self, L_NOM, L_DENOM>,
+                rhs: Rate<Self, R_NOM, R_DENOM>,
+            ) -> Option<Rate<Self, L_NOM, L_DENOM>> {
+                lhs.checked_add(rhs)
+            }
+
+            #[inline]
+            fn rate_checked_sub<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Rate<Self, L_NOM, L_DENOM>,
+                rhs: Rate<Self, R_NOM, R_DENOM>,
+            ) -> Option<Rate<Self, L_NOM, L_DENOM>> {
+                lhs.checked_sub(rhs)
+            }
+
+            #[inline]
+            fn rate_partial_cmp<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Rate<Self, L_NOM, L_DENOM>,
+                rhs: Rate<Self, R_NOM, R_DENOM>,
+            ) -> Option<Ordering> {
+                lhs.const_partial_cmp(rhs)
+            }
+
+            #[inline]
+            fn rate_eq<
+                const L_NOM: u64,
+                const L_DENOM: u64,
+                const R_NOM: u64,
+                const R_DENOM: u64,
+            >(
+                lhs: Rate<Self, L_NOM, L_DENOM>,
+                rhs: Rate<Self, R_NOM, R_DENOM>,
+            ) -> bool {
+                lhs.const_eq(rhs)
+            }
+
+            #[inline]
+            fn rate_try_convert<
+                const I_NOM: u64,
+                const I_DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                rate: Rate<Self, I_NOM, I_DENOM>,
+            ) -> Option<Rate<Self, O_NOM, O_DENOM>> {
+                rate.const_try_into()
+            }
+
+            #[inline]
+            fn rate_try_into_duration<
+                const I_NOM: u64,
+                const I_DENOM: u64,
+                const O_NOM: u64,
+                const O_DENOM: u64,
+            >(
+                rate: Rate<Self, I_NOM, I_DENOM>,
+            ) -> Option<Duration<Self, O_NOM, O_DENOM>> {
+                rate.try_into_duration()
+            }
+        }
+    };
+}
+
+impl_tick_int_for_integer!(u8);
+impl_tick_int_for_integer!(u16);
+impl_tick_int_for_integer!(u32);
+impl_tick_int_for_integer!(u64);
+impl_tick_int_for_integer!(u128);
+
+/// Checked operations and conversions on a [`Duration`] which is generic over its [`TickInt`].
+///
+/// For a concrete integer the inherent `const fn` of the same name is used.
+pub trait ExtDuration: Sized {
+    /// The backing integer.
+    type Int: TickInt;
+
+    /// Returns true if this `Duration` spans no time.
+    fn is_zero(&self) -> bool;
+
+    /// Add two durations while checking for overflow.
+    fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+
+    /// Subtract two durations while checking for overflow.
+    fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+
+    /// Try into another base, checking for overflow.
+    fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Duration<Self::Int, O_NOM, O_DENOM>>;
+
+    /// Convert between bases for a duration, panics on overflow.
+    fn convert<const O_NOM: u64, const O_DENOM: u64>(self) -> Duration<Self::Int, O_NOM, O_DENOM> {
+        if let Some(v) = self.const_try_into() {
+            v
+        } else {
+            panic!("Convert failed!");
+        }
+    }
+
+    /// Try into a rate, checking for divide-by-zero.
+    fn try_into_rate<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Rate<Self::Int, O_NOM, O_DENOM>>;
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ExtDuration for Duration<T, NOM, DENOM> {
+    type Int = T;
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.ticks == T::ZERO
+    }
+
+    #[inline]
+    fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::duration_checked_add(self, other)
+    }
+
+    #[inline]
+    fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::duration_checked_sub(self, other)
+    }
+
+    #[inline]
+    fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Duration<T, O_NOM, O_DENOM>> {
+        T::duration_try_convert(self)
+    }
+
+    #[inline]
+    fn try_into_rate<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Rate<T, O_NOM, O_DENOM>> {
+        T::duration_try_into_rate(self)
+    }
+}
+
+/// Checked operations on an [`Instant`] which is generic over its [`TickInt`].
+///
+/// For a concrete integer the inherent `const fn` of the same name is used.
+pub trait ExtInstant: Sized {
+    /// The backing integer.
+    type Int: TickInt;
+
+    /// The base of the instant, as a duration type.
+    type Duration;
+
+    /// Duration since the start of the `Instant`.
+    fn duration_since_epoch(self) -> Self::Duration;
+
+    /// Duration between `Instant`s, `None` if `other` is later than `self`.
+    fn checked_duration_since(self, other: Self) -> Option<Self::Duration>;
+
+    /// Add a `Duration` to an `Instant` while checking for overflow.
+    fn checked_add_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+
+    /// Subtract a `Duration` from an `Instant` while checking for overflow.
+    fn checked_sub_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ExtInstant for Instant<T, NOM, DENOM> {
+    type Int = T;
+    type Duration = Duration<T, NOM, DENOM>;
+
+    #[inline]
+    fn duration_since_epoch(self) -> Duration<T, NOM, DENOM> {
+        Duration::<T, NOM, DENOM>::from_ticks(self.ticks())
+    }
+
+    #[inline]
+    fn checked_duration_since(self, other: Self) -> Option<Duration<T, NOM, DENOM>> {
+        T::instant_checked_duration_since(self, other)
+    }
+
+    #[inline]
+    fn checked_add_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::instant_checked_add_duration(self, other)
+    }
+
+    #[inline]
+    fn checked_sub_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Duration<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::instant_checked_sub_duration(self, other)
+    }
+}
+
+/// Checked operations and conversions on a [`Rate`] which is generic over its [`TickInt`].
+///
+/// For a concrete integer the inherent `const fn` of the same name is used.
+pub trait ExtRate: Sized {
+    /// The backing integer.
+    type Int: TickInt;
+
+    /// Add two rates while checking for overflow.
+    fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Rate<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+
+    /// Subtract two rates while checking for overflow.
+    fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Rate<Self::Int, O_NOM, O_DENOM>,
+    ) -> Option<Self>;
+
+    /// Try into another base, checking for overflow.
+    fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Rate<Self::Int, O_NOM, O_DENOM>>;
+
+    /// Convert between bases for a rate, panics on overflow.
+    fn convert<const O_NOM: u64, const O_DENOM: u64>(self) -> Rate<Self::Int, O_NOM, O_DENOM> {
+        if let Some(v) = self.const_try_into() {
+            v
+        } else {
+            panic!("Convert failed!");
+        }
+    }
+
+    /// Try into a duration, checking for divide-by-zero.
+    fn try_into_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Duration<Self::Int, O_NOM, O_DENOM>>;
+}
+
+impl<T: TickInt, const NOM: u64, const DENOM: u64> ExtRate for Rate<T, NOM, DENOM> {
+    type Int = T;
+
+    #[inline]
+    fn checked_add<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Rate<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::rate_checked_add(self, other)
+    }
+
+    #[inline]
+    fn checked_sub<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+        other: Rate<T, O_NOM, O_DENOM>,
+    ) -> Option<Self> {
+        T::rate_checked_sub(self, other)
+    }
+
+    #[inline]
+    fn const_try_into<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Rate<T, O_NOM, O_DENOM>> {
+        T::rate_try_convert(self)
+    }
+
+    #[inline]
+    fn try_into_duration<const O_NOM: u64, const O_DENOM: u64>(
+        self,
+    ) -> Option<Duration<T, O_NOM, O_DENOM>> {
+        T::rate_try_into_duration(self)
+    }
+}
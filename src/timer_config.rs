@@ -0,0 +1,323 @@
+use crate::helpers;
+use crate::{Duration, Rate};
+
+/// How a prescaler register encodes the division factor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrescalerEncoding {
+    /// The register holds the division factor.
+    Direct,
+    /// The register holds the division factor minus one (e.g. STM32).
+    MinusOne,
+    /// The division factor is a power of two and the register holds its exponent (e.g. nRF).
+    PowerOfTwo,
+}
+
+/// Hardware constraints of a timer peripheral.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerConstraints {
+    /// Smallest supported division factor of the prescaler.
+    pub min_divider: u32,
+    /// Largest supported division factor of the prescaler.
+    pub max_divider: u32,
+    /// Width of the counter in bits, at most 32.
+    pub counter_bits: u32,
+    /// Encoding of the prescaler register.
+    pub encoding: PrescalerEncoding,
+}
+
+/// Prescaler and auto-reload configuration of a timer, as solved from a clock and a desired
+/// period or rate.
+///
+/// Among all configurations the one with the smallest error is selected, ties are broken in favor
+/// of the smallest prescaler (i.e. the highest resolution).
+///
+/// ```
+/// # use fugit::*;
+/// const STM32_TIM: TimerConstraints = TimerConstraints {
+///     min_divider: 1,
+///     max_divider: 65_536,
+///     counter_bits: 16,
+///     encoding: PrescalerEncoding::MinusOne,
+/// };
+///
+/// // Solved at compile time, fails compilation if the period can not be reached
+/// const CFG: TimerConfig =
+///     TimerConfig::from_period(HertzU32::MHz(72), MillisDurationU32::millis(500), STM32_TIM);
+///
+/// assert_eq!(CFG.divider() as u64 * CFG.counts(), 36_000_000);
+/// assert_eq!(CFG.prescaler(), CFG.divider() - 1);
+/// assert_eq!(CFG.reload() as u64, CFG.counts() - 1);
+/// assert_eq!(CFG.error_ppm(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerConfig {
+    divider: u32,
+    prescaler: u32,
+    counts: u64,
+    error_ppm: i32,
+}
+
+impl TimerConfig {
+    /// Solve for a timer with the given `period`, `None` if it can not be reached.
+    pub const fn try_from_period<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const P_NOM: u64,
+        const P_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        period: Duration<u32, P_NOM, P_DENOM>,
+        constraints: TimerConstraints,
+    ) -> Option<Self> {
+        helpers::fits_u32::<C_NOM>();
+        helpers::fits_u32::<C_DENOM>();
+        helpers::fits_u32::<P_NOM>();
+        helpers::fits_u32::<P_DENOM>();
+        // clock cycles per period = clock * period
+        Self::solve(
+            clock.raw as u128 * C_NOM as u128 * period.ticks as u128 * P_NOM as u128,
+            C_DENOM as u128 * P_DENOM as u128,
+            constraints,
+        )
+    }
+
+    /// Solve for a timer with the given `period`.
+    ///
+    /// Panics if the period can not be reached, which fails compilation in const contexts.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// const STM32_TIM: TimerConstraints = TimerConstraints {
+    ///     min_divider: 1,
+    ///     max_divider: 65_536,
+    ///     counter_bits: 16,
+    ///     encoding: PrescalerEncoding::MinusOne,
+    /// };
+    ///
+    /// // Fails as one hour is too long for the timer
+    /// const CFG: TimerConfig =
+    ///     TimerConfig::from_period(HertzU32::MHz(72), SecsDurationU32::secs(3_600), STM32_TIM);
+    /// ```
+    pub const fn from_period<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const P_NOM: u64,
+        const P_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        period: Duration<u32, P_NOM, P_DENOM>,
+        constraints: TimerConstraints,
+    ) -> Self {
+        if let Some(v) = Self::try_from_period(clock, period, constraints) {
+            v
+        } else {
+            panic!("Timer configuration failed!");
+        }
+    }
+
+    /// Solve for a timer with the given output `rate`, `None` if it can not be reached.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let nrf_timer = TimerConstraints {
+    ///     min_divider: 1,
+    ///     max_divider: 512,
+    ///     counter_bits: 16,
+    ///     encoding: PrescalerEncoding::PowerOfTwo,
+    /// };
+    ///
+    /// let cfg = TimerConfig::try_from_rate(HertzU32::MHz(16), HertzU32::Hz(50), nrf_timer).unwrap();
+    ///
+    /// assert_eq!(cfg.divider(), 8);
+    /// assert_eq!(cfg.prescaler(), 3);
+    /// assert_eq!(cfg.counts(), 40_000);
+    ///
+    /// // Too slow for the counter even at the largest prescaler
+    /// assert_eq!(TimerConfig::try_from_rate(HertzU32::MHz(16), HertzU32::Hz(0), nrf_timer), None);
+    /// assert_eq!(TimerConfig::try_from_rate(HertzU32::MHz(16), Rate::<u32, 1, 10>::from_raw(1), nrf_timer), None);
+    /// ```
+    pub const fn try_from_rate<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        rate: Rate<u32, R_NOM, R_DENOM>,
+        constraints: TimerConstraints,
+    ) -> Option<Self> {
+        helpers::fits_u32::<C_NOM>();
+        helpers::fits_u32::<C_DENOM>();
+        helpers::fits_u32::<R_NOM>();
+        helpers::fits_u32::<R_DENOM>();
+        // clock cycles per period = clock / rate
+        Self::solve(
+            clock.raw as u128 * C_NOM as u128 * R_DENOM as u128,
+            C_DENOM as u128 * rate.raw as u128 * R_NOM as u128,
+            constraints,
+        )
+    }
+
+    /// Solve for a timer with the given output `rate`.
+    ///
+    /// Panics if the rate can not be reached, which fails compilation in const contexts.
+    pub const fn from_rate<
+        const C_NOM: u64,
+        const C_DENOM: u64,
+        const R_NOM: u64,
+        const R_DENOM: u64,
+    >(
+        clock: Rate<u32, C_NOM, C_DENOM>,
+        rate: Rate<u32, R_NOM, R_DENOM>,
+        constraints: TimerConstraints,
+    ) -> Self {
+        if let Some(v) = Self::try_from_rate(clock, rate, constraints) {
+            v
+        } else {
+            panic!("Timer configuration failed!");
+        }
+    }
+
+    /// Search for the best configuration giving `num / den` clock cycles per period.
+    const fn solve(num: u128, den: u128, constraints: TimerConstraints) -> Option<Self> {
+        if num == 0 || den == 0 || constraints.counter_bits == 0 || constraints.counter_bits > 32 {
+            return None;
+        }
+
+        let max_counts = 1u128 << constraints.counter_bits;
+
+        // Best (divider, counts, error) found, where the error is `|divider * counts * den - num|`
+        // which is comparable between candidates as the target is the same
+        let mut best: Option<(u32, u128, u128)> = None;
+
+        match constraints.encoding {
+            PrescalerEncoding::PowerOfTwo => {
+                let mut exp = 0;
+
+                while exp < 32 {
+                    let divider = 1u32 << exp;
+
+                    if divider >= constraints.min_divider && divider <= constraints.max_divider {
+                        best = Self::better(best, Self::candidate(num, den, divider, max_counts));
+                    }
+
+                    exp += 1;
+                }
+            }
+            PrescalerEncoding::Direct | PrescalerEncoding::MinusOne => {
+                // Smaller dividers can not reach the period with the counter
+                let first = num.div_ceil(den * max_counts);
+                // Larger dividers only increase the error
+                let last = num.div_ceil(den);
+
+                let mut divider = if first > constraints.min_divider as u128 {
+                    first
+                } else {
+                    constraints.min_divider as u128
+                };
+
+                while divider <= constraints.max_divider as u128 && divider <= last {
+                    best =
+                        Self::better(best, Self::candidate(num, den, divider as u32, max_counts));
+
+                    if let Some((_, _, 0)) = best {
+                        break;
+                    }
+
+                    divider += 1;
+                }
+            }
+        }
+
+        let (divider, counts) = match best {
+            Some((divider, counts, _)) => (divider, counts),
+            None => return None,
+        };
+
+        if let Some(error_ppm) = helpers::error_ppm(divider as u128 * counts * den, 1, num, 1) {
+            Some(TimerConfig {
+                divider,
+                prescaler: match constraints.encoding {
+                    PrescalerEncoding::Direct => divider,
+                    PrescalerEncoding::MinusOne => divider - 1,
+                    PrescalerEncoding::PowerOfTwo => divider.trailing_zeros(),
+                },
+                counts: counts as u64,
+                error_ppm,
+            })
+        } else {
+            None
+        }
+    }
+
+    const fn candidate(
+        num: u128,
+        den: u128,
+        divider: u32,
+        max_counts: u128,
+    ) -> Option<(u32, u128, u128)> {
+        if divider == 0 {
+            return None;
+        }
+
+        // Round to nearest number of counts
+        let div_den = den * divider as u128;
+        let counts = num / div_den;
+        let counts = if 2 * (num % div_den) >= div_den {
+            counts + 1
+        } else if counts == 0 {
+            1
+        } else {
+            counts
+        };
+
+        if counts > max_counts {
+            return None;
+        }
+
+        Some((divider, counts, (div_den * counts).abs_diff(num)))
+    }
+
+    const fn better(
+        best: Option<(u32, u128, u128)>,
+        candidate: Option<(u32, u128, u128)>,
+    ) -> Option<(u32, u128, u128)> {
+        match (best, candidate) {
+            (Some((_, _, b)), Some((_, _, c))) if c < b => candidate,
+            (None, _) => candidate,
+            _ => best,
+        }
+    }
+
+    /// The division factor of the prescaler.
+    #[inline]
+    pub const fn divider(&self) -> u32 {
+        self.divider
+    }
+
+    /// The value to write to the prescaler register, encoded as given by the constraints.
+    #[inline]
+    pub const fn prescaler(&self) -> u32 {
+        self.prescaler
+    }
+
+    /// The number of counter ticks per period.
+    #[inline]
+    pub const fn counts(&self) -> u64 {
+        self.counts
+    }
+
+    /// The value to write to the auto-reload register, i.e. the number of counts minus one.
+    #[inline]
+    pub const fn reload(&self) -> u32 {
+        (self.counts - 1) as u32
+    }
+
+    /// The error of the achieved period relative to the requested period, in parts per million.
+    ///
+    /// A positive error means that the period is too long, i.e. the rate is too low.
+    #[inline]
+    pub const fn error_ppm(&self) -> i32 {
+        self.error_ppm
+    }
+}
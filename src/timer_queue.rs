@@ -0,0 +1,208 @@
+//! Software timer queue on top of one hardware compare channel.
+//!
+//! Timers are kept in a fixed capacity binary heap ordered by deadline: inserting and popping are
+//! `O(log N)`, cancelling is `O(N)` and nothing allocates.
+
+use crate::Instant;
+
+/// Handle to a timer in a [`TimerQueue`], used to cancel it.
+///
+/// Handles are issued from a 64 bit counter, so a handle is only reused after 2^64 inserts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerHandle(u64);
+
+#[derive(Clone, Copy, Debug)]
+struct Entry<T, const NOM: u64, const DENOM: u64, P> {
+    deadline: Instant<T, NOM, DENOM>,
+    handle: TimerHandle,
+    payload: P,
+}
+
+/// Allocation free software timer queue, holding up to `N` timers keyed by their deadline.
+///
+/// This allows for multiplexing many software timers onto one hardware compare channel: program
+/// the compare channel with [`next_deadline`](TimerQueue::next_deadline) and call
+/// [`pop_expired`](TimerQueue::pop_expired) from its interrupt.
+///
+/// Ordering follows the wrap-around aware ordering of `Instant`, so all deadlines in the queue
+/// must be within half the range of the tick counter of each other.
+///
+/// ```
+/// # use fugit::*;
+/// let mut queue = TimerQueue::<u32, 1, 1_000, &str, 4>::new();
+///
+/// queue.insert(Instant::<u32, 1, 1_000>::from_ticks(20), "b").unwrap();
+/// queue.insert(Instant::<u32, 1, 1_000>::from_ticks(10), "a").unwrap();
+///
+/// let now = Instant::<u32, 1, 1_000>::from_ticks(15);
+/// assert_eq!(queue.pop_expired(now).map(|(_, p)| p), Some("a"));
+/// assert_eq!(queue.pop_expired(now), None);
+/// assert_eq!(queue.next_deadline().unwrap().ticks(), 20);
+/// ```
+#[derive(Debug)]
+pub struct TimerQueue<T, const NOM: u64, const DENOM: u64, P, const N: usize> {
+    entries: [Option<Entry<T, NOM, DENOM, P>>; N],
+    len: usize,
+    next_handle: u64,
+}
+
+impl<T, const NOM: u64, const DENOM: u64, P, const N: usize> TimerQueue<T, NOM, DENOM, P, N>
+where
+    T: Copy,
+    Instant<T, NOM, DENOM>: Ord,
+{
+    const EMPTY: Option<Entry<T, NOM, DENOM, P>> = None;
+
+    /// Create an empty timer queue.
+    pub const fn new() -> Self {
+        TimerQueue {
+            entries: [Self::EMPTY; N],
+            len: 0,
+            next_handle: 0,
+        }
+    }
+
+    /// Number of pending timers.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no pending timers.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if no more timers can be inserted.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Insert a timer expiring at `deadline`. The payload is given back if the queue is full.
+    pub fn insert(
+        &mut self,
+        deadline: Instant<T, NOM, DENOM>,
+        payload: P,
+    ) -> Result<TimerHandle, P> {
+        if self.is_full() {
+            return Err(payload);
+        }
+
+        let handle = TimerHandle(self.next_handle);
+        self.next_handle = self.next_handle.wrapping_add(1);
+
+        self.entries[self.len] = Some(Entry {
+            deadline,
+            handle,
+            payload,
+        });
+        self.len += 1;
+        self.sift_up(self.len - 1);
+
+        Ok(handle)
+    }
+
+    /// Cancel a pending timer, returning its payload. Returns `None` if the timer has already
+    /// expired or been cancelled.
+    ///
+    /// A stale handle never cancels a newer timer, unless the queue has issued 2^64 handles
+    /// since and the handle counter wrapped around to it.
+    pub fn cancel(&mut self, handle: TimerHandle) -> Option<P> {
+        let index = self.entries[..self.len]
+            .iter()
+            .position(|e| matches!(e, Some(e) if e.handle == handle))?;
+
+        self.remove(index).map(|e| e.payload)
+    }
+
+    /// The earliest deadline in the queue.
+    pub fn next_deadline(&self) -> Option<Instant<T, NOM, DENOM>> {
+        if let Some(Some(e)) = self.entries[..self.len].first() {
+            Some(e.deadline)
+        } else {
+            None
+        }
+    }
+
+    /// Remove and return the earliest timer if it has expired at `now`. Call repeatedly until
+    /// `None` to handle all expired timers.
+    pub fn pop_expired(
+        &mut self,
+        now: Instant<T, NOM, DENOM>,
+    ) -> Option<(Instant<T, NOM, DENOM>, P)> {
+        if self.next_deadline()? <= now {
+            self.remove(0).map(|e| (e.deadline, e.payload))
+        } else {
+            None
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<Entry<T, NOM, DENOM, P>> {
+        self.len -= 1;
+        self.entries.swap(index, self.len);
+        let entry = self.entries[self.len].take();
+
+        if index < self.len {
+            self.sift_down(index);
+            self.sift_up(index);
+        }
+
+        entry
+    }
+
+    fn less(&self, a: usize, b: usize) -> bool {
+        match (&self.entries[a], &self.entries[b]) {
+            (Some(a), Some(b)) => a.deadline < b.deadline,
+            _ => false,
+        }
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+
+            if !self.less(index, parent) {
+                break;
+            }
+
+            self.entries.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut smallest = index;
+
+            if left < self.len && self.less(left, smallest) {
+                smallest = left;
+            }
+
+            if right < self.len && self.less(right, smallest) {
+                smallest = right;
+            }
+
+            if smallest == index {
+                break;
+            }
+
+            self.entries.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
+
+impl<T, const NOM: u64, const DENOM: u64, P, const N: usize> Default
+    for TimerQueue<T, NOM, DENOM, P, N>
+where
+    T: Copy,
+    Instant<T, NOM, DENOM>: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
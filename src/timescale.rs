@@ -0,0 +1,465 @@
+//! GPS, TAI and UTC timescales with leap second handling.
+//!
+//! All timescales are `Instant<u64, NOM, DENOM>` counting from their own epoch:
+//!
+//! * [`Tai`] from 1970-01-01 00:00:00 TAI, as e.g. `CLOCK_TAI` on Linux
+//! * [`Gps`] from 1980-01-06 00:00:00 UTC, 19 seconds behind TAI
+//! * [`Utc`] from 1970-01-01 00:00:00 UTC, counted as Unix time without leap seconds
+//!
+//! Leap second offsets are whole seconds, so conversions are exact when a second is a whole
+//! number of ticks and round down to the tick otherwise. Conversions involving UTC are only
+//! defined from 1972-01-01, when the offset to TAI became whole seconds.
+
+use crate::{Duration, Instant};
+
+/// Seconds from the TAI epoch to the GPS epoch.
+const GPS_EPOCH_TAI: u64 = 315_964_800 + 19;
+
+/// Seconds in a week.
+const SECS_PER_WEEK: u64 = 604_800;
+
+/// Whole seconds of ticks, rounded down.
+const fn to_secs<const NOM: u64, const DENOM: u64>(ticks: u64) -> u128 {
+    ticks as u128 * NOM as u128 / DENOM as u128
+}
+
+/// Ticks of whole seconds, rounded down. `None` if it does not fit.
+const fn to_ticks<const NOM: u64, const DENOM: u64>(secs: u64) -> Option<u64> {
+    let ticks = secs as u128 * DENOM as u128 / NOM as u128;
+
+    if ticks > u64::MAX as u128 {
+        None
+    } else {
+        Some(ticks as u64)
+    }
+}
+
+/// Instant moved by a number of whole seconds. `None` if out of range.
+const fn shift<const NOM: u64, const DENOM: u64>(
+    instant: Instant<u64, NOM, DENOM>,
+    secs: u64,
+    forward: bool,
+) -> Option<Instant<u64, NOM, DENOM>> {
+    let offset = match to_ticks::<NOM, DENOM>(secs) {
+        Some(offset) => offset,
+        None => return None,
+    };
+
+    let ticks = if forward {
+        instant.ticks().checked_add(offset)
+    } else {
+        instant.ticks().checked_sub(offset)
+    };
+
+    if let Some(ticks) = ticks {
+        Some(Instant::<u64, NOM, DENOM>::from_ticks(ticks))
+    } else {
+        None
+    }
+}
+
+/// Table of the offsets between TAI and UTC.
+///
+/// Each entry is the Unix time at which an offset takes effect, and the offset `TAI - UTC` in
+/// seconds from then on. The built-in table is valid until the next leap second is announced by
+/// the IERS, a newer table can be provided with [`LeapSeconds::new`].
+///
+/// ```
+/// # use fugit::*;
+/// let leap = LeapSeconds::BUILTIN;
+///
+/// // 2017-01-01 00:00:00 UTC
+/// assert_eq!(leap.offset_at_utc(1_483_228_799), Some(36));
+/// assert_eq!(leap.offset_at_utc(1_483_228_800), Some(37));
+///
+/// // Not defined before 1972
+/// assert_eq!(leap.offset_at_utc(0), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeapSeconds {
+    table: &'static [(u64, u8)],
+}
+
+impl LeapSeconds {
+    /// Leap seconds up to and including 2017-01-01, as published in IERS Bulletin C.
+    pub const BUILTIN: Self = LeapSeconds::new(&[
+        (63_072_000, 10),
+        (78_796_800, 11),
+        (94_694_400, 12),
+        (126_230_400, 13),
+        (157_766_400, 14),
+        (189_302_400, 15),
+        (220_924_800, 16),
+        (252_460_800, 17),
+        (283_996_800, 18),
+        (315_532_800, 19),
+        (362_793_600, 20),
+        (394_329_600, 21),
+        (425_865_600, 22),
+        (489_024_000, 23),
+        (567_993_600, 24),
+        (631_152_000, 25),
+        (662_688_000, 26),
+        (709_948_800, 27),
+        (741_484_800, 28),
+        (773_020_800, 29),
+        (820_454_400, 30),
+        (867_715_200, 31),
+        (915_148_800, 32),
+        (1_136_073_600, 33),
+        (1_230_768_000, 34),
+        (1_341_100_800, 35),
+        (1_435_708_800, 36),
+        (1_483_228_800, 37),
+    ]);
+
+    /// Create a table from `(unix_secs, tai_minus_utc)` entries.
+    ///
+    /// Panics if the table is empty, is not sorted by time or an entry changes the offset by
+    /// more than one second.
+    pub const fn new(table: &'static [(u64, u8)]) -> Self {
+        if table.is_empty() {
+            panic!("Invalid leap second table!");
+        }
+
+        let mut i = 1;
+        while i < table.len() {
+            let (prev_time, prev_offset) = table[i - 1];
+            let (time, offset) = table[i];
+
+            if time <= prev_time || offset.abs_diff(prev_offset) != 1 {
+                panic!("Invalid leap second table!");
+            }
+
+            i += 1;
+        }
+
+        LeapSeconds { table }
+    }
+
+    /// `TAI - UTC` in seconds at a Unix time, `None` before the first entry.
+    ///
+    /// An inserted leap second belongs to the next day, so it is not possible to refer to it in
+    /// UTC.
+    pub const fn offset_at_utc(&self, unix_secs: u64) -> Option<u8> {
+        let mut i = self.table.len();
+        while i > 0 {
+            i -= 1;
+
+            let (time, offset) = self.table[i];
+            if time <= unix_secs {
+                return Some(offset);
+            }
+        }
+
+        None
+    }
+
+    /// `TAI - UTC` in seconds at a TAI time, `None` before the first entry.
+    ///
+    /// During an inserted leap second this is the offset before the leap second.
+    pub const fn offset_at_tai(&self, tai_secs: u64) -> Option<u8> {
+        let mut i = self.table.len();
+        while i > 0 {
+            i -= 1;
+
+            let (time, offset) = self.table[i];
+            if time + offset as u64 <= tai_secs {
+                return Some(offset);
+            }
+        }
+
+        None
+    }
+}
+
+/// International Atomic Time, counting from 1970-01-01 00:00:00 TAI.
+///
+/// ```
+/// # use fugit::*;
+/// let tai = Tai::new(Instant::<u64, 1, 1_000>::from_ticks(1_700_000_037_000));
+///
+/// let utc = tai.to_utc(&LeapSeconds::BUILTIN).unwrap();
+/// assert_eq!(utc.to_unix(), Some((1_700_000_000, 0)));
+/// assert_eq!(utc.to_tai(&LeapSeconds::BUILTIN), Some(tai));
+///
+/// let gps = tai.to_gps().unwrap();
+/// assert_eq!(gps.to_tai(), Some(tai));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tai<const NOM: u64, const DENOM: u64> {
+    instant: Instant<u64, NOM, DENOM>,
+}
+
+/// GPS time, counting from 1980-01-06 00:00:00 UTC.
+///
+/// ```
+/// # use fugit::*;
+/// // Week 2300, Wednesday 12:00:00
+/// let tow = MillisDurationU64::secs(3 * 86_400 + 12 * 3_600);
+/// let gps = Gps::from_week_time(GpsWeekTime::new(2300, tow)).unwrap();
+///
+/// let utc = gps.to_utc(&LeapSeconds::BUILTIN).unwrap();
+/// assert_eq!(utc.to_unix(), Some((1_707_307_182, 0)));
+///
+/// let week_time = gps.to_week_time().unwrap();
+/// assert_eq!(week_time.week(), 2300);
+/// assert_eq!(week_time.time_of_week(), tow);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Gps<const NOM: u64, const DENOM: u64> {
+    instant: Instant<u64, NOM, DENOM>,
+}
+
+/// Coordinated Universal Time, counting from 1970-01-01 00:00:00 UTC as Unix time, i.e. without
+/// leap seconds.
+///
+/// ```
+/// # use fugit::*;
+/// let utc = Utc::<1, 1_000_000>::from_unix(1_483_228_800, 250_000).unwrap();
+/// let leap = LeapSeconds::BUILTIN;
+///
+/// // The leap second at the end of 2016 maps to the first second of 2017
+/// let tai = utc.to_tai(&leap).unwrap();
+/// let before = Tai::new(tai.instant() - MicrosDurationU64::secs(1));
+/// assert_eq!(before.to_utc(&leap), Some(utc));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Utc<const NOM: u64, const DENOM: u64> {
+    instant: Instant<u64, NOM, DENOM>,
+}
+
+/// GPS week number and time of week.
+///
+/// The week number is the full week number since the GPS epoch, not the 10 or 13 bit number
+/// broadcast by the satellites.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GpsWeekTime<const NOM: u64, const DENOM: u64> {
+    week: u32,
+    tow: Duration<u64, NOM, DENOM>,
+}
+
+impl<const NOM: u64, const DENOM: u64> Tai<NOM, DENOM> {
+    /// TAI at an instant counting from 1970-01-01 00:00:00 TAI.
+    #[inline]
+    pub const fn new(instant: Instant<u64, NOM, DENOM>) -> Self {
+        Tai { instant }
+    }
+
+    /// The instant counting from 1970-01-01 00:00:00 TAI.
+    #[inline]
+    pub const fn instant(&self) -> Instant<u64, NOM, DENOM> {
+        self.instant
+    }
+
+    /// Convert to GPS time, `None` before the GPS epoch.
+    pub const fn to_gps(&self) -> Option<Gps<NOM, DENOM>> {
+        if let Some(instant) = shift(self.instant, GPS_EPOCH_TAI, false) {
+            Some(Gps { instant })
+        } else {
+            None
+        }
+    }
+
+    /// Convert to UTC, `None` if not covered by the leap second table.
+    ///
+    /// An inserted leap second maps to the first second of the next day.
+    pub const fn to_utc(&self, leap: &LeapSeconds) -> Option<Utc<NOM, DENOM>> {
+        let secs = to_secs::<NOM, DENOM>(self.instant.ticks());
+        if secs > u64::MAX as u128 {
+            return None;
+        }
+
+        let offset = match leap.offset_at_tai(secs as u64) {
+            Some(offset) => offset,
+            None => return None,
+        };
+
+        if let Some(instant) = shift(self.instant, offset as u64, false) {
+            Some(Utc { instant })
+        } else {
+            None
+        }
+    }
+}
+
+impl<const NOM: u64, const DENOM: u64> Gps<NOM, DENOM> {
+    /// GPS time at an instant counting from 1980-01-06 00:00:00 UTC.
+    #[inline]
+    pub const fn new(instant: Instant<u64, NOM, DENOM>) -> Self {
+        Gps { instant }
+    }
+
+    /// The instant counting from 1980-01-06 00:00:00 UTC.
+    #[inline]
+    pub const fn instant(&self) -> Instant<u64, NOM, DENOM> {
+        self.instant
+    }
+
+    /// Convert to TAI, `None` if out of range.
+    pub const fn to_tai(&self) -> Option<Tai<NOM, DENOM>> {
+        if let Some(instant) = shift(self.instant, GPS_EPOCH_TAI, true) {
+            Some(Tai { instant })
+        } else {
+            None
+        }
+    }
+
+    /// Convert to UTC, `None` if out of range.
+    pub const fn to_utc(&self, leap: &LeapSeconds) -> Option<Utc<NOM, DENOM>> {
+        if let Some(tai) = self.to_tai() {
+            tai.to_utc(leap)
+        } else {
+            None
+        }
+    }
+
+    /// GPS time at a week number and time of week, `None` if out of range.
+    pub const fn from_week_time(week_time: GpsWeekTime<NOM, DENOM>) -> Option<Self> {
+        let week = match to_ticks::<NOM, DENOM>(week_time.week as u64 * SECS_PER_WEEK) {
+            Some(week) => week,
+            None => return None,
+        };
+
+        if let Some(ticks) = week.checked_add(week_time.tow.ticks()) {
+            Some(Gps {
+                instant: Instant::<u64, NOM, DENOM>::from_ticks(ticks),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The week number and time of week, `None` if the week number does not fit.
+    pub const fn to_week_time(&self) -> Option<GpsWeekTime<NOM, DENOM>> {
+        let week = to_secs::<NOM, DENOM>(self.instant.ticks()) / SECS_PER_WEEK as u128;
+        if week > u32::MAX as u128 {
+            return None;
+        }
+
+        // The start of the week is before the instant, so it always fits
+        let start = week * SECS_PER_WEEK as u128 * DENOM as u128 / NOM as u128;
+
+        Some(GpsWeekTime {
+            week: week as u32,
+            tow: Duration::<u64, NOM, DENOM>::from_ticks(self.instant.ticks() - start as u64),
+        })
+    }
+}
+
+impl<const NOM: u64, const DENOM: u64> Utc<NOM, DENOM> {
+    /// UTC at an instant counting from 1970-01-01 00:00:00 UTC without leap seconds.
+    #[inline]
+    pub const fn new(instant: Instant<u64, NOM, DENOM>) -> Self {
+        Utc { instant }
+    }
+
+    /// The instant counting from 1970-01-01 00:00:00 UTC without leap seconds.
+    #[inline]
+    pub const fn instant(&self) -> Instant<u64, NOM, DENOM> {
+        self.instant
+    }
+
+    /// UTC at a Unix timestamp, rounded down to the tick. `None` if `nanos` is not below one
+    /// second or out of range.
+    pub const fn from_unix(secs: u64, nanos: u32) -> Option<Self> {
+        if nanos >= 1_000_000_000 {
+            return None;
+        }
+
+        // Split off the whole ticks of the seconds so the 64-bit bases can not overflow
+        let whole = secs as u128 * DENOM as u128;
+        let ticks = whole / NOM as u128
+            + ((whole % NOM as u128) * 1_000_000_000 + nanos as u128 * DENOM as u128)
+                / (NOM as u128 * 1_000_000_000);
+
+        if ticks > u64::MAX as u128 {
+            None
+        } else {
+            Some(Utc {
+                instant: Instant::<u64, NOM, DENOM>::from_ticks(ticks as u64),
+            })
+        }
+    }
+
+    /// Unix timestamp, as seconds and nanoseconds rounded down. `None` if the seconds do not
+    /// fit.
+    pub const fn to_unix(&self) -> Option<(u64, u32)> {
+        let num = self.instant.ticks() as u128 * NOM as u128;
+        let secs = num / DENOM as u128;
+        let nanos = (num % DENOM as u128) * 1_000_000_000 / DENOM as u128;
+
+        if secs > u64::MAX as u128 {
+            None
+        } else {
+            Some((secs as u64, nanos as u32))
+        }
+    }
+
+    /// Convert to TAI, `None` if not covered by the leap second table.
+    pub const fn to_tai(&self, leap: &LeapSeconds) -> Option<Tai<NOM, DENOM>> {
+        let offset = match self.to_unix() {
+            Some((secs, _)) => match leap.offset_at_utc(secs) {
+                Some(offset) => offset,
+                None => return None,
+            },
+            None => return None,
+        };
+
+        if let Some(instant) = shift(self.instant, offset as u64, true) {
+            Some(Tai { instant })
+        } else {
+            None
+        }
+    }
+
+    /// Convert to GPS time, `None` if not covered by the leap second table or before the GPS
+    /// epoch.
+    pub const fn to_gps(&self, leap: &LeapSeconds) -> Option<Gps<NOM, DENOM>> {
+        if let Some(tai) = self.to_tai(leap) {
+            tai.to_gps()
+        } else {
+            None
+        }
+    }
+}
+
+impl<const NOM: u64, const DENOM: u64> GpsWeekTime<NOM, DENOM> {
+    /// Create from a week number and time of week, `None` if the time of week is not below one
+    /// week.
+    pub const fn try_new(week: u32, time_of_week: Duration<u64, NOM, DENOM>) -> Option<Self> {
+        let secs = to_secs::<NOM, DENOM>(time_of_week.ticks());
+
+        if secs >= SECS_PER_WEEK as u128 {
+            None
+        } else {
+            Some(GpsWeekTime {
+                week,
+                tow: time_of_week,
+            })
+        }
+    }
+
+    /// Create from a week number and time of week.
+    ///
+    /// Panics if the time of week is not below one week.
+    pub const fn new(week: u32, time_of_week: Duration<u64, NOM, DENOM>) -> Self {
+        if let Some(week_time) = Self::try_new(week, time_of_week) {
+            week_time
+        } else {
+            panic!("Time of week out of range!");
+        }
+    }
+
+    /// The week number since the GPS epoch.
+    #[inline]
+    pub const fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// The time since the start of the week, Sunday 00:00:00 GPS time.
+    #[inline]
+    pub const fn time_of_week(&self) -> Duration<u64, NOM, DENOM> {
+        self.tow
+    }
+}
@@ -0,0 +1,412 @@
+//! NTP and PTP (IEEE 1588) timestamp formats.
+//!
+//! Conversions from `Duration`s round to the nearest unit of the format and conversions back
+//! round to the nearest tick, so a round trip through a format with a finer resolution than the
+//! `Duration` is lossless (e.g. nanoseconds through NTP timestamps).
+
+use crate::helpers;
+use crate::{Duration, Instant};
+
+/// Whole seconds and the remainder in units of `1 / scale` seconds of a duration, rounded to the
+/// nearest unit.
+const fn split<const NOM: u64, const DENOM: u64>(
+    duration: Duration<u64, NOM, DENOM>,
+    scale: u128,
+) -> (u128, u128) {
+    let num = duration.ticks as u128 * NOM as u128;
+    let secs = num / DENOM as u128;
+    let rem = num % DENOM as u128;
+
+    let sub = (rem * scale + DENOM as u128 / 2) / DENOM as u128;
+    if sub == scale {
+        (secs + 1, 0)
+    } else {
+        (secs, sub)
+    }
+}
+
+/// Duration of whole seconds and a remainder in units of `1 / scale` seconds, rounded to the
+/// nearest tick. `None` if it does not fit.
+const fn join<const NOM: u64, const DENOM: u64>(
+    secs: u128,
+    sub: u128,
+    scale: u128,
+) -> Option<Duration<u64, NOM, DENOM>> {
+    let whole = match secs.checked_mul(DENOM as u128) {
+        Some(whole) => whole,
+        None => return None,
+    };
+
+    let den = NOM as u128 * scale;
+    let ticks =
+        whole / NOM as u128 + ((whole % NOM as u128) * scale + sub * DENOM as u128 + den / 2) / den;
+
+    if ticks > u64::MAX as u128 {
+        None
+    } else {
+        Some(Duration::<u64, NOM, DENOM>::from_ticks(ticks as u64))
+    }
+}
+
+/// NTP timestamp format, 32 bit seconds and 32 bit fraction since the start of an NTP era.
+///
+/// Era 0 starts at 1900-01-01 00:00:00 UTC, era 1 in 2036. Durations and instants are taken
+/// relative to the start of era 0, and the era is dropped when converting to a timestamp.
+///
+/// ```
+/// # use fugit::*;
+/// // 2024-01-01 00:00:00.5 UTC as nanoseconds since the UNIX epoch
+/// let unix = NanosDurationU64::nanos(1_704_067_200_500_000_000);
+/// let ntp = NtpTimestamp::UNIX_EPOCH.wrapping_add_duration(unix);
+///
+/// assert_eq!(ntp.seconds(), 3_913_056_000);
+/// assert_eq!(ntp.fraction(), 1 << 31);
+/// assert_eq!(ntp.to_bytes(), [0xe9, 0x3c, 0x7f, 0x00, 0x80, 0x00, 0x00, 0x00]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NtpTimestamp {
+    seconds: u32,
+    fraction: u32,
+}
+
+/// NTP short format, 16 bit seconds and 16 bit fraction, as used for root delay and dispersion.
+///
+/// ```
+/// # use fugit::*;
+/// let delay = NtpShort::from_duration(MicrosDurationU64::micros(1_500_000)).unwrap();
+///
+/// assert_eq!(delay.to_bits(), 0x0001_8000);
+/// assert_eq!(delay.to_duration::<1, 1_000_000>(), MicrosDurationU64::micros(1_500_000));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NtpShort {
+    bits: u32,
+}
+
+/// IEEE 1588 PTP timestamp, 48 bit seconds and 32 bit nanoseconds since the PTP epoch
+/// (1970-01-01 00:00:00 TAI).
+///
+/// ```
+/// # use fugit::*;
+/// let ptp = PtpTimestamp::from_duration(NanosDurationU64::nanos(1_700_000_000_123_456_789)).unwrap();
+///
+/// assert_eq!(ptp.seconds(), 1_700_000_000);
+/// assert_eq!(ptp.nanoseconds(), 123_456_789);
+/// assert_eq!(PtpTimestamp::from_bytes(ptp.to_bytes()), Some(ptp));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PtpTimestamp {
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl NtpTimestamp {
+    /// The UNIX epoch, 1970-01-01 00:00:00 UTC.
+    pub const UNIX_EPOCH: NtpTimestamp = NtpTimestamp::from_parts(2_208_988_800, 0);
+
+    /// Create a timestamp from seconds and fraction.
+    #[inline]
+    pub const fn from_parts(seconds: u32, fraction: u32) -> Self {
+        NtpTimestamp { seconds, fraction }
+    }
+
+    /// Create a timestamp from its 64 bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        NtpTimestamp::from_parts((bits >> 32) as u32, bits as u32)
+    }
+
+    /// Create a timestamp from its big endian wire format.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        NtpTimestamp::from_bits(u64::from_be_bytes(bytes))
+    }
+
+    /// Seconds since the start of the era.
+    #[inline]
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Fraction of a second, in units of `2^-32` seconds.
+    #[inline]
+    pub const fn fraction(&self) -> u32 {
+        self.fraction
+    }
+
+    /// The 64 bit representation.
+    #[inline]
+    pub const fn to_bits(&self) -> u64 {
+        (self.seconds as u64) << 32 | self.fraction as u64
+    }
+
+    /// The big endian wire format.
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Create a timestamp from a duration since the start of era 0, rounded to the nearest
+    /// fraction. The era is dropped.
+    pub const fn from_duration<const NOM: u64, const DENOM: u64>(
+        duration: Duration<u64, NOM, DENOM>,
+    ) -> Self {
+        let (secs, fraction) = split(duration, 1 << 32);
+
+        NtpTimestamp::from_parts(secs as u32, fraction as u32)
+    }
+
+    /// Create a timestamp from an instant, where the instant's tick zero is the start of era 0.
+    #[inline]
+    pub const fn from_instant<const NOM: u64, const DENOM: u64>(
+        instant: Instant<u64, NOM, DENOM>,
+    ) -> Self {
+        Self::from_duration(instant.duration_since_epoch())
+    }
+
+    /// Duration since the start of era 0, given the timestamp's `era`. Rounded to the nearest
+    /// tick, `None` if it does not fit.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// let ntp = NtpTimestamp::from_parts(1, 1 << 30);
+    ///
+    /// assert_eq!(ntp.to_duration(0), Some(MillisDurationU64::millis(1_250)));
+    /// assert_eq!(ntp.to_duration(1), Some(MillisDurationU64::millis(4_294_967_297_250)));
+    /// ```
+    pub const fn to_duration<const NOM: u64, const DENOM: u64>(
+        &self,
+        era: u32,
+    ) -> Option<Duration<u64, NOM, DENOM>> {
+        join(
+            (era as u128) << 32 | self.seconds as u128,
+            self.fraction as u128,
+            1 << 32,
+        )
+    }
+
+    /// Duration since the start of era 0, using the era which places the timestamp closest to
+    /// `pivot` (i.e. within 68 years). Rounded to the nearest tick, `None` if it does not fit.
+    ///
+    /// ```
+    /// # use fugit::*;
+    /// // A timestamp shortly after the era rollover in 2036
+    /// let ntp = NtpTimestamp::from_parts(10, 0);
+    /// let pivot = SecsDurationU64::secs(u32::MAX as u64 - 10);
+    ///
+    /// assert_eq!(ntp.to_duration_near(pivot), Some(SecsDurationU64::secs(u32::MAX as u64 + 11)));
+    /// ```
+    pub const fn to_duration_near<const NOM: u64, const DENOM: u64>(
+        &self,
+        pivot: Duration<u64, NOM, DENOM>,
+    ) -> Option<Duration<u64, NOM, DENOM>> {
+        let (pivot, _) = split(pivot, 1);
+        let era = (pivot >> 32) as u32;
+
+        // Difference of the seconds within the era, as a signed 32 bit value
+        let diff = self.seconds.wrapping_sub(pivot as u32) as i32;
+        let own = pivot as u32 as i64 + diff as i64;
+
+        let era = if own < 0 {
+            match era.checked_sub(1) {
+                Some(era) => era,
+                None => return None,
+            }
+        } else if own > u32::MAX as i64 {
+            match era.checked_add(1) {
+                Some(era) => era,
+                None => return None,
+            }
+        } else {
+            era
+        };
+
+        self.to_duration(era)
+    }
+
+    /// Instant of the timestamp given its `era`, where the instant's tick zero is the start of
+    /// era 0.
+    pub const fn to_instant<const NOM: u64, const DENOM: u64>(
+        &self,
+        era: u32,
+    ) -> Option<Instant<u64, NOM, DENOM>> {
+        if let Some(d) = self.to_duration::<NOM, DENOM>(era) {
+            Some(Instant::<u64, NOM, DENOM>::from_ticks(d.ticks))
+        } else {
+            None
+        }
+    }
+
+    /// Add a duration, rounded to the nearest fraction and wrapping around at the end of the
+    /// era.
+    pub const fn wrapping_add_duration<const NOM: u64, const DENOM: u64>(
+        self,
+        duration: Duration<u64, NOM, DENOM>,
+    ) -> Self {
+        let other = Self::from_duration(duration);
+
+        Self::from_bits(self.to_bits().wrapping_add(other.to_bits()))
+    }
+}
+
+impl NtpShort {
+    /// Create a short timestamp from its 32 bit representation.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        NtpShort { bits }
+    }
+
+    /// Create a short timestamp from its big endian wire format.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        NtpShort::from_bits(u32::from_be_bytes(bytes))
+    }
+
+    /// The 32 bit representation.
+    #[inline]
+    pub const fn to_bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The big endian wire format.
+    #[inline]
+    pub const fn to_bytes(&self) -> [u8; 4] {
+        self.bits.to_be_bytes()
+    }
+
+    /// Seconds part.
+    #[inline]
+    pub const fn seconds(&self) -> u16 {
+        (self.bits >> 16) as u16
+    }
+
+    /// Fraction of a second, in units of `2^-16` seconds.
+    #[inline]
+    pub const fn fraction(&self) -> u16 {
+        self.bits as u16
+    }
+
+    /// Create a short timestamp from a duration, rounded to the nearest fraction. `None` if it
+    /// is 65536 seconds or longer.
+    pub const fn from_duration<const NOM: u64, const DENOM: u64>(
+        duration: Duration<u64, NOM, DENOM>,
+    ) -> Option<Self> {
+        let (secs, fraction) = split(duration, 1 << 16);
+
+        if secs > u16::MAX as u128 {
+            None
+        } else {
+            Some(NtpShort::from_bits((secs as u32) << 16 | fraction as u32))
+        }
+    }
+
+    /// The duration, rounded to the nearest tick. `DENOM` must fit in 32 bits.
+    ///
+    /// ```compile_fail
+    /// # use fugit::*;
+    /// // Fails as 65535 s does not fit `u64` femtoseconds
+    /// let d = NtpShort::from_bits(u32::MAX).to_duration::<1, 1_000_000_000_000_000>();
+    /// ```
+    pub const fn to_duration<const NOM: u64, const DENOM: u64>(&self) -> Duration<u64, NOM, DENOM> {
+        helpers::fits_u32::<DENOM>();
+
+        if let Some(d) = join(self.seconds() as u128, self.fraction() as u128, 1 << 16) {
+            d
+        } else {
+            // 65536 s fits in 64 bits of any base with a 32 bit denominator
+            unreachable!()
+        }
+    }
+}
+
+impl PtpTimestamp {
+    /// Create a timestamp from seconds and nanoseconds, `None` if the seconds do not fit in 48
+    /// bits or the nanoseconds are not below one second.
+    #[inline]
+    pub const fn from_parts(seconds: u64, nanoseconds: u32) -> Option<Self> {
+        if seconds >> 48 != 0 || nanoseconds >= 1_000_000_000 {
+            None
+        } else {
+            Some(PtpTimestamp {
+                seconds,
+                nanoseconds,
+            })
+        }
+    }
+
+    /// Create a timestamp from its big endian wire format, 6 bytes of seconds followed by 4
+    /// bytes of nanoseconds. `None` if the nanoseconds are not below one second.
+    pub const fn from_bytes(bytes: [u8; 10]) -> Option<Self> {
+        let seconds = u64::from_be_bytes([
+            0, 0, bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5],
+        ]);
+        let nanoseconds = u32::from_be_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]);
+
+        Self::from_parts(seconds, nanoseconds)
+    }
+
+    /// Seconds since the PTP epoch.
+    #[inline]
+    pub const fn seconds(&self) -> u64 {
+        self.seconds
+    }
+
+    /// Nanoseconds within the second.
+    #[inline]
+    pub const fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// The big endian wire format, 6 bytes of seconds followed by 4 bytes of nanoseconds.
+    pub const fn to_bytes(&self) -> [u8; 10] {
+        let s = self.seconds.to_be_bytes();
+        let n = self.nanoseconds.to_be_bytes();
+
+        [s[2], s[3], s[4], s[5], s[6], s[7], n[0], n[1], n[2], n[3]]
+    }
+
+    /// Create a timestamp from a duration since the PTP epoch, rounded to the nearest
+    /// nanosecond. `None` if the seconds do not fit in 48 bits.
+    pub const fn from_duration<const NOM: u64, const DENOM: u64>(
+        duration: Duration<u64, NOM, DENOM>,
+    ) -> Option<Self> {
+        let (secs, nanoseconds) = split(duration, 1_000_000_000);
+
+        if secs > u64::MAX as u128 {
+            None
+        } else {
+            Self::from_parts(secs as u64, nanoseconds as u32)
+        }
+    }
+
+    /// Create a timestamp from an instant, where the instant's tick zero is the PTP epoch.
+    #[inline]
+    pub const fn from_instant<const NOM: u64, const DENOM: u64>(
+        instant: Instant<u64, NOM, DENOM>,
+    ) -> Option<Self> {
+        Self::from_duration(instant.duration_since_epoch())
+    }
+
+    /// Duration since the PTP epoch, rounded to the nearest tick. `None` if it does not fit.
+    pub const fn to_duration<const NOM: u64, const DENOM: u64>(
+        &self,
+    ) -> Option<Duration<u64, NOM, DENOM>> {
+        join(
+            self.seconds as u128,
+            self.nanoseconds as u128,
+            1_000_000_000,
+        )
+    }
+
+    /// Instant of the timestamp, where the instant's tick zero is the PTP epoch. `None` if it
+    /// does not fit.
+    pub const fn to_instant<const NOM: u64, const DENOM: u64>(
+        &self,
+    ) -> Option<Instant<u64, NOM, DENOM>> {
+        if let Some(d) = self.to_duration::<NOM, DENOM>() {
+            Some(Instant::<u64, NOM, DENOM>::from_ticks(d.ticks))
+        } else {
+            None
+        }
+    }
+}